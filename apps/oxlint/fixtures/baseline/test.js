@@ -0,0 +1,9 @@
+let x = 1;
+debugger;
+debugger;
+
+function foo(a, b) {
+    return a;
+}
+
+debugger;
@@ -0,0 +1,10 @@
+debugger; // eslint-disable-line no-debugger
+
+// eslint-disable-next-line no-debugger
+console.log("foo");
+
+/* oxlint-disable-next-line no-debugger, some-unknown-rule */
+console.log("bar");
+
+// eslint-disable-next-line
+console.log("baz");
@@ -0,0 +1,172 @@
+use std::{cell::RefCell, collections::BTreeMap, fs, io, path::Path, rc::Rc};
+
+use cow_utils::CowUtils;
+use oxc_diagnostics::{DiagnosticFilter, Error};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+use crate::output_formatter::hash_message_and_source;
+
+/// Existing diagnostics recorded in a baseline file, so that only new diagnostics are reported.
+///
+/// Diagnostics are grouped by file path and rule. Each diagnostic is identified by a hash of its
+/// message and the source text it points to, which stays the same when code above it is moved.
+/// The number of diagnostics with the same hash is recorded, so that adding another copy of
+/// an existing violation is still reported.
+///
+/// ```json
+/// {
+///   "src/index.ts": {
+///     "typescript-eslint(no-explicit-any)": {
+///       "ad3e6ecf1ea3a6c2": 2
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    files: BTreeMap<String, BTreeMap<String, BTreeMap<String, usize>>>,
+}
+
+impl Baseline {
+    /// # Errors
+    ///
+    /// When the file cannot be read or is not a valid baseline.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read baseline file {}: {err}", path.display()))?;
+        serde_json::from_str(&text)
+            .map_err(|err| format!("Failed to parse baseline file {}: {err}", path.display()))
+    }
+
+    /// # Errors
+    ///
+    /// When the file cannot be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("Failed to serialize");
+        fs::write(path, json + "\n")
+    }
+
+    /// Get the recorded count of a diagnostic, which is `None` for diagnostics that can't be
+    /// recorded because they do not belong to a rule, like parse errors.
+    fn entry(&mut self, path: &Path, diagnostic: &Error) -> Option<&mut usize> {
+        let rule = diagnostic.code()?.to_string();
+        let hash = Self::hash(diagnostic);
+        Some(
+            self.files
+                .entry(Self::file_key(path))
+                .or_default()
+                .entry(rule)
+                .or_default()
+                .entry(hash)
+                .or_default(),
+        )
+    }
+
+    /// Replace the recorded diagnostics of `linted_paths` with the ones in `recorded`, and keep
+    /// the diagnostics of files which were not linted.
+    pub fn update<'p>(&mut self, recorded: Self, linted_paths: impl IntoIterator<Item = &'p Path>) {
+        for path in linted_paths {
+            self.files.remove(&Self::file_key(path));
+        }
+        self.files.extend(recorded.files);
+    }
+
+    fn file_key(path: &Path) -> String {
+        path.to_string_lossy().cow_replace('\\', "/").into_owned()
+    }
+
+    fn hash(diagnostic: &Error) -> String {
+        let mut hasher = Sha1::new();
+        hash_message_and_source(&mut hasher, diagnostic);
+        let hash = format!("{:x}", hasher.finalize());
+        hash[..16].to_string()
+    }
+}
+
+/// A [`DiagnosticFilter`] which only reports diagnostics that are not in a [`Baseline`].
+///
+/// When updating, all diagnostics are recorded into the baseline instead of being reported,
+/// see [`Baseline::update`].
+pub struct BaselineFilter {
+    baseline: Rc<RefCell<Baseline>>,
+    update: bool,
+}
+
+impl BaselineFilter {
+    pub fn new(baseline: Rc<RefCell<Baseline>>, update: bool) -> Self {
+        Self { baseline, update }
+    }
+}
+
+impl DiagnosticFilter for BaselineFilter {
+    fn filter(&mut self, path: &Path, diagnostics: Vec<Error>) -> Vec<Error> {
+        let mut baseline = self.baseline.borrow_mut();
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let Some(count) = baseline.entry(path, diagnostic) else {
+                    return true;
+                };
+                if self.update {
+                    *count += 1;
+                    false
+                } else if *count > 0 {
+                    *count -= 1;
+                    false
+                } else {
+                    true
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, path::Path, rc::Rc};
+
+    use oxc_diagnostics::{DiagnosticFilter, Error, NamedSource, OxcDiagnostic};
+    use oxc_span::Span;
+
+    use super::{Baseline, BaselineFilter};
+
+    fn diagnostics(source_text: &str, spans: &[Span]) -> Vec<Error> {
+        spans
+            .iter()
+            .map(|span| {
+                OxcDiagnostic::warn("`debugger` statement is not allowed")
+                    .with_error_code("eslint", "no-debugger")
+                    .with_label(*span)
+                    .with_source_code(NamedSource::new("test.js", source_text.to_string()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn baseline() {
+        let baseline = Rc::new(RefCell::new(Baseline::default()));
+        let path = Path::new("test.js");
+
+        let source_text = "debugger;\ndebugger;";
+        let spans = [Span::new(0, 9), Span::new(10, 19)];
+        let mut filter = BaselineFilter::new(Rc::clone(&baseline), true);
+        assert!(filter.filter(path, diagnostics(source_text, &spans)).is_empty());
+
+        let json = serde_json::to_string(&*baseline.borrow()).unwrap();
+        assert_eq!(json, r#"{"test.js":{"eslint(no-debugger)":{"2ffeb41dbb44d806":2}}}"#);
+        let baseline: Baseline = serde_json::from_str(&json).unwrap();
+        let mut filter = BaselineFilter::new(Rc::new(RefCell::new(baseline)), false);
+
+        // Moved diagnostics are still recognized, only the third one is new
+        let source_text = "foo();\ndebugger;\ndebugger;\ndebugger;";
+        let spans = [Span::new(7, 16), Span::new(17, 26), Span::new(27, 36)];
+        let reported = filter.filter(path, diagnostics(source_text, &spans));
+        assert_eq!(reported.len(), 1);
+
+        // Diagnostics without a rule are always reported
+        let error = OxcDiagnostic::error("Unexpected token").with_label(Span::new(0, 1));
+        assert_eq!(filter.filter(path, vec![error.into()]).len(), 1);
+    }
+}
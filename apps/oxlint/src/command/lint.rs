@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use bpaf::Bpaf;
+use bpaf::{Bpaf, Parser, construct, long};
 use oxc_linter::{AllowWarnDeny, FixKind, LintPlugins};
 
 use crate::output_formatter::OutputFormat;
@@ -35,6 +35,18 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
+    #[bpaf(external)]
+    pub baseline_options: BaselineOptions,
+
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub changed_options: ChangedOptions,
+
+    #[bpaf(external)]
+    pub stdin_options: StdinOptions,
+
     /// list all the rules that are currently registered
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
@@ -141,13 +153,19 @@ pub struct FixOptions {
     /// Apply dangerous fixes and suggestions.
     #[bpaf(switch, hide_usage)]
     pub fix_dangerously: bool,
+
+    /// Print the fixes as a unified diff instead of writing them to the files.
+    /// Applies the same fixes as `--fix`, unless other fix options are used.
+    /// Can only be used with the `default` and `stylish` formats.
+    #[bpaf(switch, hide_usage)]
+    pub fix_dry_run: bool,
 }
 
 impl FixOptions {
     pub fn fix_kind(&self) -> FixKind {
         let mut kind = FixKind::None;
 
-        if self.fix {
+        if self.fix || (self.fix_dry_run && !self.fix_suggestions && !self.fix_dangerously) {
             kind.set(FixKind::SafeFix, true);
         }
 
@@ -166,7 +184,7 @@ impl FixOptions {
     }
 
     pub fn is_enabled(&self) -> bool {
-        self.fix || self.fix_suggestions || self.fix_dangerously
+        self.fix || self.fix_suggestions || self.fix_dangerously || self.fix_dry_run
     }
 }
 
@@ -185,16 +203,93 @@ pub struct WarningOptions {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     #[bpaf(argument("INT"), hide_usage)]
     pub max_warnings: Option<usize>,
+
+    #[bpaf(external, hide_usage)]
+    pub report_unused_disable_directives: Option<AllowWarnDeny>,
+}
+
+/// `--report-unused-disable-directives` or `--report-unused-disable-directives=SEVERITY`
+fn report_unused_disable_directives() -> impl Parser<Option<AllowWarnDeny>> {
+    const HELP: &str = "Report directive comments like `// eslint-disable-line` when no errors would have been reported on that line anyway.\n\
+        The severity can be set with `--report-unused-disable-directives=warn`, defaults to `error`";
+    let with_severity = long("report-unused-disable-directives")
+        .help(HELP)
+        .argument::<String>("SEVERITY")
+        .adjacent()
+        .parse(|severity| AllowWarnDeny::try_from(severity.as_str()).map_err(|e| e.to_string()));
+    let without_severity =
+        long("report-unused-disable-directives").help(HELP).req_flag(AllowWarnDeny::Deny).hide();
+    construct!([with_severity, without_severity]).optional()
 }
 
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
-    /// Use a specific output format (default, json, unix, checkstyle, github, stylish)
+    /// Use a specific output format (default, json, unix, checkstyle, github, stylish, junit, sarif, gitlab)
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
 }
 
+/// Baseline
+#[derive(Debug, Clone, Bpaf)]
+pub struct BaselineOptions {
+    /// Only report diagnostics which are not recorded in this baseline file,
+    /// so that existing violations do not fail the lint.
+    /// Paths in the baseline are relative to the current working directory.
+    #[bpaf(argument("./oxlint-baseline.json"), hide_usage)]
+    pub baseline: Option<PathBuf>,
+
+    /// Record the current diagnostics of the linted files in the `--baseline` file instead of
+    /// reporting them. Entries of other files in the baseline are kept.
+    #[bpaf(switch, hide_usage)]
+    pub update_baseline: bool,
+}
+
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint files which changed since the last run with `--cache`,
+    /// and report the stored results of unchanged files
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path of the cache file used by `--cache`
+    #[bpaf(argument("./.oxlintcache"), fallback(PathBuf::from(".oxlintcache")), hide_usage)]
+    pub cache_location: PathBuf,
+}
+
+/// Changed Files
+#[derive(Debug, Clone, Bpaf)]
+pub struct ChangedOptions {
+    /// Only lint files which changed compared to the merge base of this git ref and `HEAD`,
+    /// including uncommitted changes and untracked files
+    #[bpaf(argument("REF"), hide_usage)]
+    pub changed_since: Option<String>,
+
+    /// Only lint files with staged changes
+    #[bpaf(switch, hide_usage)]
+    pub staged: bool,
+
+    /// Also lint files which import the changed files, directly or indirectly.
+    /// Requires the import plugin. All files are still parsed to find the importing files.
+    #[bpaf(switch, hide_usage)]
+    pub include_dependents: bool,
+}
+
+/// Standard Input
+#[derive(Debug, Clone, Bpaf)]
+pub struct StdinOptions {
+    /// Lint source text read from stdin instead of files.
+    /// With `--fix`, the fixed source text is printed instead of diagnostics.
+    #[bpaf(switch, hide_usage)]
+    pub stdin: bool,
+
+    /// Path of the source text read with `--stdin`, which determines its language and
+    /// configuration. The file does not need to exist.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
+}
+
 /// Enable Plugins
 #[expect(clippy::struct_field_names)]
 #[derive(Debug, Default, Clone, Bpaf)]
@@ -393,6 +488,8 @@ mod plugins {
 
 #[cfg(test)]
 mod warning_options {
+    use oxc_linter::AllowWarnDeny;
+
     use super::{WarningOptions, lint_command};
 
     fn get_warning_options(arg: &str) -> WarningOptions {
@@ -418,13 +515,25 @@ mod warning_options {
         let options = get_warning_options("--max-warnings 10 .");
         assert_eq!(options.max_warnings, Some(10));
     }
+
+    #[test]
+    fn report_unused_disable_directives() {
+        let options = get_warning_options(".");
+        assert_eq!(options.report_unused_disable_directives, None);
+
+        let options = get_warning_options("--report-unused-disable-directives .");
+        assert_eq!(options.report_unused_disable_directives, Some(AllowWarnDeny::Deny));
+
+        let options = get_warning_options("--report-unused-disable-directives=warn .");
+        assert_eq!(options.report_unused_disable_directives, Some(AllowWarnDeny::Warn));
+    }
 }
 
 #[cfg(test)]
 mod lint_options {
     use std::{fs::File, path::PathBuf};
 
-    use oxc_linter::AllowWarnDeny;
+    use oxc_linter::{AllowWarnDeny, FixKind};
 
     use super::{LintCommand, OutputFormat, lint_command};
 
@@ -481,6 +590,16 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_dry_run() {
+        let options = get_lint_options("--fix-dry-run test.js");
+        assert!(options.fix_options.fix_dry_run);
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix);
+
+        let options = get_lint_options("--fix-dry-run --fix-suggestions test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Suggestion);
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -525,4 +644,39 @@ mod lint_options {
         let options = get_lint_options(".");
         assert!(!options.disable_nested_config);
     }
+
+    #[test]
+    fn changed() {
+        let options = get_lint_options(".");
+        assert_eq!(options.changed_options.changed_since, None);
+        assert!(!options.changed_options.staged);
+        assert!(!options.changed_options.include_dependents);
+
+        let options = get_lint_options("--changed-since main --staged --include-dependents .");
+        assert_eq!(options.changed_options.changed_since.as_deref(), Some("main"));
+        assert!(options.changed_options.staged);
+        assert!(options.changed_options.include_dependents);
+    }
+
+    #[test]
+    fn stdin() {
+        let options = get_lint_options(".");
+        assert!(!options.stdin_options.stdin);
+        assert_eq!(options.stdin_options.stdin_filename, None);
+
+        let options = get_lint_options("--stdin --stdin-filename src/foo.tsx");
+        assert!(options.stdin_options.stdin);
+        assert_eq!(options.stdin_options.stdin_filename, Some(PathBuf::from("src/foo.tsx")));
+    }
+
+    #[test]
+    fn cache() {
+        let options = get_lint_options(".");
+        assert!(!options.cache_options.cache);
+        assert_eq!(options.cache_options.cache_location, PathBuf::from(".oxlintcache"));
+
+        let options = get_lint_options("--cache --cache-location tmp/cache .");
+        assert!(options.cache_options.cache);
+        assert_eq!(options.cache_options.cache_location, PathBuf::from("tmp/cache"));
+    }
 }
@@ -3,7 +3,9 @@ mod lint;
 
 use std::path::PathBuf;
 
-use bpaf::Bpaf;
+use bpaf::{Bpaf, Parser, construct, long};
+
+use crate::timing::TimingFormat;
 
 pub use self::{
     ignore::IgnoreOptions,
@@ -28,8 +30,28 @@ pub struct MiscOptions {
 
     /// This option outputs the configuration to be used.
     /// When present, no linting is performed and only config-related options are valid.
+    /// With a single file path, the configuration resolved for that file is printed, with the
+    /// config file, override or option each entry comes from. The path must be a file.
     #[bpaf(switch, hide_usage)]
     pub print_config: bool,
+
+    #[bpaf(external, hide_usage)]
+    pub timing: Option<TimingFormat>,
+
+    /// Keep running and lint files again when they change.
+    /// With the import plugin, files importing the changed files are linted again too.
+    /// The configuration is loaded again when a config file changes.
+    #[bpaf(switch, hide_usage)]
+    pub watch: bool,
+}
+
+/// `--timing` or `--timing=FORMAT`
+fn timing() -> impl Parser<Option<TimingFormat>> {
+    const HELP: &str = "Print the time spent in each rule and in parsing, semantic analysis and module resolution to stderr.\n\
+        The format can be set with `--timing=json`, defaults to `table` which shows the 10 slowest rules";
+    let with_format = long("timing").help(HELP).argument::<TimingFormat>("FORMAT").adjacent();
+    let without_format = long("timing").help(HELP).req_flag(TimingFormat::Table).hide();
+    construct!([with_format, without_format]).optional()
 }
 
 #[expect(clippy::ptr_arg)]
@@ -46,6 +68,7 @@ const PATHS_ERROR_MESSAGE: &str = "PATH must not contain \"..\"";
 #[cfg(test)]
 mod misc_options {
     use super::{MiscOptions, lint::lint_command};
+    use crate::timing::TimingFormat;
 
     fn get_misc_options(arg: &str) -> MiscOptions {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -56,6 +79,23 @@ mod misc_options {
     fn default() {
         let options = get_misc_options(".");
         assert!(options.threads.is_none());
+        assert!(options.timing.is_none());
+        assert!(!options.watch);
+    }
+
+    #[test]
+    fn timing() {
+        let options = get_misc_options("--timing .");
+        assert_eq!(options.timing, Some(TimingFormat::Table));
+
+        let options = get_misc_options("--timing=json .");
+        assert_eq!(options.timing, Some(TimingFormat::Json));
+    }
+
+    #[test]
+    fn watch() {
+        let options = get_misc_options("--watch .");
+        assert!(options.watch);
     }
 
     #[test]
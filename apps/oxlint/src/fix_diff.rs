@@ -0,0 +1,40 @@
+use std::path::Path;
+
+use cow_utils::CowUtils;
+use oxc_linter::FixedFile;
+use similar::TextDiff;
+
+/// Lines of unchanged source text shown around changes, like `git diff`
+const CONTEXT_LINES: usize = 3;
+
+/// Format the fixes of a file as a unified diff, with the path relative to `cwd`.
+pub fn format_fix_diff(cwd: &Path, file: &FixedFile) -> String {
+    let path = file.path.strip_prefix(cwd).unwrap_or(&file.path);
+    let path = path.to_string_lossy();
+    let path = path.cow_replace('\\', "/");
+    TextDiff::from_lines(&file.source_text, &file.fixed_source_text)
+        .unified_diff()
+        .context_radius(CONTEXT_LINES)
+        .header(&format!("a/{path}"), &format!("b/{path}"))
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use oxc_linter::FixedFile;
+
+    use super::format_fix_diff;
+
+    #[test]
+    fn diff() {
+        let file = FixedFile {
+            path: PathBuf::from("/project/src/a.js"),
+            source_text: "debugger;\nlet a = 1;\n".to_string(),
+            fixed_source_text: "let a = 1;\n".to_string(),
+        };
+        let expected = "--- a/src/a.js\n+++ b/src/a.js\n@@ -1,2 +1 @@\n-debugger;\n let a = 1;\n";
+        assert_eq!(format_fix_diff(Path::new("/project"), &file), expected);
+    }
+}
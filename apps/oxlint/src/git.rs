@@ -0,0 +1,125 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Get the files changed in the git repository containing `cwd`, as absolute paths.
+///
+/// With `since`, these are the files which differ from the merge base of that ref and `HEAD`,
+/// including uncommitted changes, like `git diff <since>...` for committed changes. Changes made
+/// on `since` after the current branch was created from it are not included.
+/// With `staged`, only staged changes are considered. Untracked files are included unless
+/// `staged` is set, and deleted files are never included.
+///
+/// Only files inside `cwd` are returned.
+///
+/// # Errors
+///
+/// When git fails, e.g. because `cwd` is not inside a git repository or `since` is not a valid ref.
+pub fn changed_files(
+    cwd: &Path,
+    since: Option<&str>,
+    staged: bool,
+) -> Result<Vec<PathBuf>, String> {
+    let mut diff_args =
+        vec!["diff", "--name-only", "--relative", "--no-renames", "--diff-filter=d", "-z"];
+    if staged {
+        diff_args.push("--cached");
+    }
+    if let Some(since) = since {
+        diff_args.extend(["--merge-base", "--end-of-options", since, "--"]);
+    }
+    let mut files = git(cwd, &diff_args)?;
+    if !staged {
+        files.extend(git(cwd, &["ls-files", "--others", "--exclude-standard", "-z"])?);
+    }
+    Ok(files.into_iter().map(|file| cwd.join(file)).collect())
+}
+
+/// Run git in `cwd` and split its NUL separated output.
+fn git(cwd: &Path, args: &[&str]) -> Result<Vec<String>, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .map_err(|err| format!("Failed to run git: {err}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to get changed files from git: {}", stderr.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|file| !file.is_empty())
+        .map(ToString::to_string)
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path, process::Command};
+
+    use super::changed_files;
+
+    fn git(cwd: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(["-c", "user.name=oxlint", "-c", "user.email=oxlint@example.com"])
+            .args(args)
+            .current_dir(cwd)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    #[test]
+    fn test_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let cwd = dir.path();
+        fs::write(cwd.join("committed.js"), "debugger;").unwrap();
+        fs::write(cwd.join("modified.js"), "debugger;").unwrap();
+        fs::write(cwd.join("deleted.js"), "debugger;").unwrap();
+        git(cwd, &["init", "--quiet"]);
+        git(cwd, &["add", "."]);
+        git(cwd, &["commit", "--quiet", "-m", "init"]);
+
+        // `base` gets another commit after the current branch was created from it
+        git(cwd, &["branch", "base"]);
+        fs::write(cwd.join("feature.js"), "debugger;").unwrap();
+        git(cwd, &["add", "feature.js"]);
+        git(cwd, &["commit", "--quiet", "-m", "feature"]);
+        git(cwd, &["checkout", "--quiet", "base"]);
+        fs::write(cwd.join("upstream.js"), "debugger;").unwrap();
+        git(cwd, &["add", "upstream.js"]);
+        git(cwd, &["commit", "--quiet", "-m", "upstream"]);
+        git(cwd, &["checkout", "--quiet", "-"]);
+
+        fs::write(cwd.join("modified.js"), "debugger; debugger;").unwrap();
+        fs::write(cwd.join("staged.js"), "debugger;").unwrap();
+        fs::write(cwd.join("untracked.js"), "debugger;").unwrap();
+        fs::remove_file(cwd.join("deleted.js")).unwrap();
+        git(cwd, &["add", "staged.js"]);
+
+        let names = |since, staged| {
+            let mut files = changed_files(cwd, since, staged).unwrap();
+            files.sort();
+            files
+                .iter()
+                .map(|file| file.strip_prefix(cwd).unwrap().to_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(Some("HEAD"), false), ["modified.js", "staged.js", "untracked.js"]);
+        assert_eq!(names(None, true), ["staged.js"]);
+        assert_eq!(names(Some("HEAD"), true), ["staged.js"]);
+        assert_eq!(
+            names(Some("base"), false),
+            ["feature.js", "modified.js", "staged.js", "untracked.js"]
+        );
+
+        let err = changed_files(cwd, Some("does-not-exist"), false).unwrap_err();
+        assert!(err.starts_with("Failed to get changed files from git:"));
+        // Refs are never read as options
+        let err = changed_files(cwd, Some("--output=out.txt"), false).unwrap_err();
+        assert!(err.starts_with("Failed to get changed files from git:"));
+        assert!(!cwd.join("out.txt").exists());
+    }
+}
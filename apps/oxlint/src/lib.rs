@@ -1,10 +1,16 @@
+mod baseline;
 mod command;
+mod fix_diff;
+mod git;
 mod lint;
 mod output_formatter;
+mod print_config;
 mod result;
 mod runner;
 mod tester;
+mod timing;
 mod walk;
+mod watch;
 
 pub mod cli {
 
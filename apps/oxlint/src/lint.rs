@@ -1,41 +1,72 @@
 use std::{
-    env, fs,
-    io::{ErrorKind, Write},
+    cell::RefCell,
+    env,
+    ffi::OsStr,
+    fs,
+    io::{self, ErrorKind, Write},
     path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+    thread,
     time::Instant,
 };
 
 use cow_utils::CowUtils;
 use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
+use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, OxcDiagnostic, Severity};
 use oxc_linter::{
-    AllowWarnDeny, ConfigStore, ConfigStoreBuilder, InvalidFilterKind, LintFilter, LintOptions,
-    LintService, LintServiceOptions, Linter, Oxlintrc, loader::LINT_PARTIAL_LOADER_EXT,
+    AllowWarnDeny, ConfigSource, ConfigStore, ConfigStoreBuilder, FixedFile, InvalidFilterKind,
+    LintCache, LintFilter, LintOptions, LintPlugins, LintService, LintServiceOptions, LintTimings,
+    Linter, Oxlintrc, loader::LINT_PARTIAL_LOADER_EXT,
 };
 use oxc_span::VALID_EXTENSIONS;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
 
 use crate::{
+    baseline::{Baseline, BaselineFilter},
     cli::{CliRunResult, LintCommand, MiscOptions, Runner, WarningOptions},
+    fix_diff::format_fix_diff,
+    git,
     output_formatter::{LintCommandInfo, OutputFormatter},
+    print_config::format_effective_config,
     walk::{Extensions, Walk},
+    watch::{Changes, Watcher},
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LintRunner {
     options: LintCommand,
     cwd: PathBuf,
+    /// Source text to use for `--stdin` instead of reading stdin
+    stdin: Option<String>,
 }
 
 impl Runner for LintRunner {
     type Options = LintCommand;
 
     fn new(options: Self::Options) -> Self {
-        Self { options, cwd: env::current_dir().expect("Failed to get current working directory") }
+        Self {
+            options,
+            cwd: env::current_dir().expect("Failed to get current working directory"),
+            stdin: None,
+        }
     }
 
     fn run(self, stdout: &mut dyn Write) -> CliRunResult {
+        if self.options.misc_options.watch {
+            return self.watch(stdout);
+        }
+        self.lint(stdout, None)
+    }
+}
+
+impl LintRunner {
+    const DEFAULT_OXLINTRC: &'static str = ".oxlintrc.json";
+
+    /// Lint the paths, and with `watcher`, lint changed paths again whenever files change until a
+    /// config file changes.
+    fn lint(self, stdout: &mut dyn Write, mut watcher: Option<&mut Watcher>) -> CliRunResult {
         let format_str = self.options.output_options.format;
         let output_formatter = OutputFormatter::new(format_str);
 
@@ -56,18 +87,117 @@ impl Runner for LintRunner {
             fix_options,
             enable_plugins,
             misc_options,
+            baseline_options,
+            cache_options,
+            changed_options,
+            stdin_options,
             disable_nested_config,
             ..
         } = self.options;
 
+        // The diffs are not mixed into machine readable output, so there would be nothing to show
+        if fix_options.fix_dry_run && !output_formatter.print_fix_diffs() {
+            stdout
+                .write_all(
+                    b"`--fix-dry-run` can only be used with the default and stylish formats.\n",
+                )
+                .or_else(Self::check_for_writer_error)
+                .unwrap();
+            stdout.flush().unwrap();
+
+            return CliRunResult::InvalidOptionConfig;
+        }
+
+        let stdin_source_text = match (stdin_options.stdin, &stdin_options.stdin_filename) {
+            (false, None) => None,
+            (true, Some(_)) => match self.stdin.map_or_else(|| io::read_to_string(io::stdin()), Ok)
+            {
+                Ok(source_text) => Some(source_text),
+                Err(err) => {
+                    stdout
+                        .write_all(format!("Failed to read stdin: {err}\n").as_bytes())
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                    stdout.flush().unwrap();
+
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            },
+            (true, None) | (false, Some(_)) => {
+                stdout
+                    .write_all(b"`--stdin` and `--stdin-filename` must be used together.\n")
+                    .or_else(Self::check_for_writer_error)
+                    .unwrap();
+                stdout.flush().unwrap();
+
+                return CliRunResult::InvalidOptionConfig;
+            }
+        };
+        // With `--fix`, the output is the fixed source text only, so it can replace the input
+        let print_fixed_source_text =
+            stdin_source_text.is_some() && fix_options.is_enabled() && !fix_options.fix_dry_run;
+
+        let baseline_path = baseline_options.baseline.as_ref().map(|path| self.cwd.join(path));
+        let cache = (cache_options.cache && stdin_source_text.is_none()).then(|| {
+            Arc::new(LintCache::load(
+                self.cwd.join(&cache_options.cache_location),
+                env!("CARGO_PKG_VERSION"),
+            ))
+        });
+
+        let changed_files = if changed_options.changed_since.is_some() || changed_options.staged {
+            match git::changed_files(
+                &self.cwd,
+                changed_options.changed_since.as_deref(),
+                changed_options.staged,
+            ) {
+                Ok(files) => Some(files),
+                Err(err) => {
+                    stdout
+                        .write_all(format!("{err}\n").as_bytes())
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                    stdout.flush().unwrap();
+
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            }
+        } else {
+            None
+        };
+
         let use_nested_config = !disable_nested_config &&
             // If the `--config` option is explicitly passed, we should not search for nested config files
             // as the passed config file takes absolute precedence.
             basic_options.config.is_none();
 
-        let mut paths = paths;
+        let mut paths = stdin_options.stdin_filename.clone().map_or(paths, |path| vec![path]);
         let provided_path_count = paths.len();
-        let now = Instant::now();
+        // With a single file, `--print-config` prints the configuration resolved for that file
+        let print_config_path = match paths.as_slice() {
+            [path] if misc_options.print_config => {
+                let file = self.cwd.join(path);
+                // The path of stdin does not need to exist
+                if !file.is_file() && stdin_options.stdin_filename.is_none() {
+                    stdout
+                        .write_all(
+                            format!(
+                                "`--print-config` requires a file, but {:?} is not a file.\n",
+                                path.to_string_lossy().cow_replace('\\', "/")
+                            )
+                            .as_bytes(),
+                        )
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                    stdout.flush().unwrap();
+
+                    return CliRunResult::InvalidOptionConfig;
+                }
+                Some(file)
+            }
+            _ => None,
+        };
+        let mut now = Instant::now();
 
         let filter = match Self::get_filters(filter) {
             Ok(filter) => filter,
@@ -154,11 +284,22 @@ impl Runner for LintRunner {
             // If explicit paths were provided, but all have been
             // filtered, return early.
             if provided_path_count > 0 {
+                if let Some(source_text) = stdin_source_text.filter(|_| print_fixed_source_text) {
+                    stdout
+                        .write_all(source_text.as_bytes())
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                    stdout.flush().unwrap();
+
+                    return CliRunResult::LintNoFilesFound;
+                }
                 if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
                     number_of_files: 0,
                     number_of_rules: None,
                     threads_count: rayon::current_num_threads(),
                     start_time: now.elapsed(),
+                    fix_summary: None,
+                    fix_dry_run: false,
                 }) {
                     stdout.write_all(end.as_bytes()).or_else(Self::check_for_writer_error).unwrap();
                     stdout.flush().unwrap();
@@ -170,10 +311,42 @@ impl Runner for LintRunner {
             paths.push(self.cwd.clone());
         }
 
-        let walker = Walk::new(&paths, &ignore_options, override_builder);
-        let paths = walker.with_extensions(Extensions(extensions)).paths();
+        let walk_paths = || {
+            Walk::new(&paths, &ignore_options, override_builder.clone())
+                .with_extensions(Extensions(extensions.clone()))
+                .paths()
+        };
+        let mut paths = if stdin_source_text.is_some() {
+            // The path of stdin does not need to exist, so it is not walked
+            paths
+                .iter()
+                .map(|path| Arc::<OsStr>::from(self.cwd.join(path).into_os_string()))
+                .collect()
+        } else {
+            walk_paths()
+        };
+
+        // All paths which are linted again when they change
+        let mut watched_paths = watcher
+            .as_ref()
+            .map(|_| Self::normalized_paths(&self.cwd, paths.iter().cloned()))
+            .unwrap_or_default();
+
+        // Dependents of changed files are found by the lint service, which needs all paths for that
+        if let (Some(changed_files), false) = (&changed_files, changed_options.include_dependents) {
+            let changed_files = changed_files
+                .iter()
+                .map(|path| path.components().collect::<PathBuf>())
+                .collect::<FxHashSet<_>>();
+            paths.retain(|path| {
+                let path = self.cwd.join(Path::new(path)).components().collect::<PathBuf>();
+                changed_files.contains(&path)
+            });
+        }
 
-        let number_of_files = paths.len();
+        let mut number_of_files = paths.len();
+        // All paths are walked and parsed to find the dependents, but only some of them are linted
+        let count_linted_paths = changed_files.is_some() && changed_options.include_dependents;
 
         // TODO(perf): benchmark whether or not it is worth it to store the configurations on a
         // per-file or per-directory basis, to avoid calling `.parent()` on every path.
@@ -243,13 +416,16 @@ impl Runner for LintRunner {
             }
         }
 
+        let config_plugins = oxlintrc.plugins;
         enable_plugins.apply_overrides(&mut oxlintrc.plugins);
+        let command_line_plugins = oxlintrc.plugins.difference(config_plugins);
 
-        let oxlintrc_for_print = if misc_options.print_config || basic_options.init {
-            Some(oxlintrc.clone())
-        } else {
-            None
-        };
+        let oxlintrc_for_print =
+            if (misc_options.print_config && print_config_path.is_none()) || basic_options.init {
+                Some(oxlintrc.clone())
+            } else {
+                None
+            };
         let config_builder = match ConfigStoreBuilder::from_oxlintrc(false, oxlintrc) {
             Ok(builder) => builder,
             Err(e) => {
@@ -266,6 +442,16 @@ impl Runner for LintRunner {
             }
         }
         .with_filters(filter);
+        let config_builder = command_line_plugins.iter().fold(config_builder, |builder, plugin| {
+            // The jest plugin is also enabled by `--vitest-plugin`
+            let name = if plugin == LintPlugins::JEST && enable_plugins.jest_plugin.is_not_set() {
+                "vitest"
+            } else {
+                plugin.into()
+            };
+            builder
+                .with_plugins_source(plugin, ConfigSource::CommandLine(format!("--{name}-plugin")))
+        });
 
         if let Some(basic_config_file) = oxlintrc_for_print {
             let config_file = config_builder.resolve_final_config_file(basic_config_file);
@@ -315,7 +501,7 @@ impl Runner for LintRunner {
             }
         }
 
-        let mut options = LintServiceOptions::new(self.cwd, paths)
+        let mut options = LintServiceOptions::new(self.cwd.clone(), paths)
             .with_cross_module(config_builder.plugins().has_import());
 
         let lint_config = match config_builder.build() {
@@ -339,6 +525,28 @@ impl Runner for LintRunner {
                 .with_fix(fix_options.fix_kind())
         } else {
             Linter::new(LintOptions::default(), lint_config).with_fix(fix_options.fix_kind())
+        }
+        .with_report_fixes(output_formatter.report_fixes())
+        .with_report_unused_directives(
+            warning_options
+                .report_unused_disable_directives
+                .filter(|severity| severity.is_warn_deny())
+                .map(Severity::from),
+        );
+
+        if let Some(path) = print_config_path {
+            let config = format_effective_config(&self.cwd, &linter.effective_config(&path));
+            stdout.write_all(config.as_bytes()).or_else(Self::check_for_writer_error).unwrap();
+            stdout.write_all(b"\n").or_else(Self::check_for_writer_error).unwrap();
+            stdout.flush().unwrap();
+
+            return CliRunResult::PrintConfigResult;
+        }
+
+        let timings = misc_options.timing.map(|_| Arc::new(LintTimings::default()));
+        let linter = match &timings {
+            Some(timings) => linter.with_timings(Arc::clone(timings)),
+            None => linter,
         };
 
         let tsconfig = basic_options.tsconfig;
@@ -357,46 +565,344 @@ impl Runner for LintRunner {
             }
         }
 
-        let mut lint_service = LintService::new(linter, options);
-        let mut diagnostic_service =
-            Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options);
+        let baseline = match &baseline_path {
+            Some(path) if baseline_options.update_baseline && !path.exists() => {
+                Some(Baseline::default())
+            }
+            Some(path) => match Baseline::load(path) {
+                Ok(baseline) => Some(baseline),
+                Err(err) => {
+                    stdout
+                        .write_all(format!("{err}\n").as_bytes())
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                    stdout.flush().unwrap();
+
+                    return CliRunResult::InvalidOptionConfig;
+                }
+            },
+            None if baseline_options.update_baseline => {
+                stdout
+                    .write_all(
+                        b"`--update-baseline` requires a baseline file passed with `--baseline`.\n",
+                    )
+                    .or_else(Self::check_for_writer_error)
+                    .unwrap();
+                stdout.flush().unwrap();
+
+                return CliRunResult::InvalidOptionConfig;
+            }
+            None => None,
+        }
+        .map(|baseline| Rc::new(RefCell::new(baseline)));
 
+        if let Some(cache) = &cache {
+            options = options.with_cache(Arc::clone(cache));
+        }
+        if let Some(changed_files) = changed_files.filter(|_| changed_options.include_dependents) {
+            options = options.with_changed_paths(changed_files);
+        }
+        if let Some(source_text) = &stdin_source_text {
+            options = options.with_source_text(source_text.clone());
+        }
+        options = options.with_fix_dry_run(fix_options.fix_dry_run);
+
+        let mut lint_service = LintService::new(linter, options);
         let number_of_rules = lint_service.linter().number_of_rules();
 
-        // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
-        rayon::spawn({
-            let tx_error = diagnostic_service.sender().clone();
-            move || {
-                lint_service.run(&tx_error);
+        loop {
+            let mut diagnostic_service =
+                Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options);
+            // When updating, diagnostics of this run are recorded separately, so that only the
+            // entries of linted files are replaced
+            let recorded_baseline = Some(Rc::new(RefCell::new(Baseline::default())))
+                .filter(|_| baseline_options.update_baseline);
+            if let Some(baseline) = recorded_baseline.as_ref().or(baseline.as_ref()) {
+                diagnostic_service = diagnostic_service.with_filter(Box::new(BaselineFilter::new(
+                    Rc::clone(baseline),
+                    baseline_options.update_baseline,
+                )));
+            }
+
+            let fixed_source_text = if stdin_source_text.is_some() {
+                // There is only one source text to lint, so it's linted before printing diagnostics.
+                lint_service.run(diagnostic_service.sender());
+                lint_service.take_fixed_source_text()
+            } else {
+                None
+            };
+
+            // The output of the reporter is finished after the diffs of `--fix-dry-run`
+            let diagnostic_result = if print_fixed_source_text {
+                diagnostic_service.run(&mut io::sink())
+            } else if stdin_source_text.is_some() {
+                diagnostic_service.run_unfinished(stdout)
+            } else {
+                // Lint in another thread so diagnostics can be printed immediately from diagnostic_service.run.
+                let tx_error = diagnostic_service.sender().clone();
+                thread::scope(|scope| {
+                    scope.spawn(|| lint_service.run(&tx_error));
+                    diagnostic_service.run_unfinished(stdout)
+                })
+            };
+
+            if fix_options.fix_dry_run {
+                let fixed_files = match (&stdin_source_text, &fixed_source_text) {
+                    (Some(source_text), Some(fixed_source_text)) => vec![FixedFile {
+                        path: self.cwd.join(stdin_options.stdin_filename.as_ref().unwrap()),
+                        source_text: source_text.clone(),
+                        fixed_source_text: fixed_source_text.clone(),
+                    }],
+                    (Some(_), None) => vec![],
+                    (None, _) => lint_service.take_fixed_files(),
+                };
+                for file in &fixed_files {
+                    stdout
+                        .write_all(format_fix_diff(&self.cwd, file).as_bytes())
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                }
+                stdout.flush().unwrap();
+            }
+            if !print_fixed_source_text {
+                diagnostic_service.finish(stdout, &diagnostic_result);
+            }
+            let fix_summary = lint_service.take_fix_summary();
+            let linted_paths = lint_service.take_linted_paths();
+            if count_linted_paths {
+                number_of_files = linted_paths.len();
+            }
+
+            // Timings are printed to stderr, so that they do not mix with machine readable output
+            if let (Some(format), Some(timings)) = (misc_options.timing, &timings) {
+                let mut stderr = io::stderr().lock();
+                stderr
+                    .write_all(format.format(timings).as_bytes())
+                    .or_else(Self::check_for_writer_error)
+                    .unwrap();
+                stderr.flush().unwrap();
+            }
+
+            if let Some(source_text) =
+                stdin_source_text.as_ref().filter(|_| print_fixed_source_text)
+            {
+                stdout
+                    .write_all(fixed_source_text.as_ref().unwrap_or(source_text).as_bytes())
+                    .or_else(Self::check_for_writer_error)
+                    .unwrap();
+                stdout.flush().unwrap();
+            }
+
+            if let (Some(baseline), Some(path)) = (&baseline, &baseline_path) {
+                if let Some(recorded_baseline) = recorded_baseline {
+                    baseline.borrow_mut().update(
+                        recorded_baseline.take(),
+                        linted_paths.iter().map(|linted_path| {
+                            let linted_path = Path::new(linted_path);
+                            linted_path.strip_prefix(&self.cwd).unwrap_or(linted_path)
+                        }),
+                    );
+                    if let Err(err) = baseline.borrow().save(path) {
+                        stdout
+                            .write_all(
+                                format!(
+                                    "Failed to write baseline file {}: {err}\n",
+                                    path.display()
+                                )
+                                .as_bytes(),
+                            )
+                            .or_else(Self::check_for_writer_error)
+                            .unwrap();
+                        stdout.flush().unwrap();
+
+                        return CliRunResult::InvalidOptionConfig;
+                    }
+                }
+            }
+
+            if let Some(cache) = &cache {
+                if let Err(err) = cache.save() {
+                    stdout
+                        .write_all(
+                            format!(
+                                "Failed to write cache file {}: {err}\n",
+                                cache_options.cache_location.display()
+                            )
+                            .as_bytes(),
+                        )
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                    stdout.flush().unwrap();
+                }
             }
-        });
 
-        let diagnostic_result = diagnostic_service.run(stdout);
+            // Nothing is printed after the fixed source text
+            if let Some(end) = output_formatter
+                .lint_command_info(&LintCommandInfo {
+                    number_of_files,
+                    number_of_rules,
+                    threads_count: rayon::current_num_threads(),
+                    start_time: now.elapsed(),
+                    fix_summary: Some(fix_summary).filter(|summary| summary.files > 0),
+                    fix_dry_run: fix_options.fix_dry_run,
+                })
+                .filter(|_| !print_fixed_source_text)
+            {
+                stdout.write_all(end.as_bytes()).or_else(Self::check_for_writer_error).unwrap();
+                stdout.flush().unwrap();
+            };
 
-        if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
-            number_of_files,
-            number_of_rules,
-            threads_count: rayon::current_num_threads(),
-            start_time: now.elapsed(),
-        }) {
-            stdout.write_all(end.as_bytes()).or_else(Self::check_for_writer_error).unwrap();
+            let result = if diagnostic_result.errors_count() > 0 {
+                CliRunResult::LintFoundErrors
+            } else if warning_options.deny_warnings && diagnostic_result.warnings_count() > 0 {
+                CliRunResult::LintNoWarningsAllowed
+            } else if diagnostic_result.max_warnings_exceeded() {
+                CliRunResult::LintMaxWarningsExceeded
+            } else {
+                CliRunResult::LintSucceeded
+            };
+
+            let Some(watcher) = watcher.as_deref_mut() else {
+                return result;
+            };
+            Self::print_watching(stdout);
+            let paths = loop {
+                let changed_paths = match watcher.wait() {
+                    Changes::Files(paths) => paths,
+                    // Linting starts from the beginning to load the configuration again
+                    Changes::Config => return result,
+                };
+                // Created files are only linted when walking finds them, e.g. they are not ignored
+                if changed_paths.iter().any(|path| {
+                    !watched_paths.contains_key(&Self::normalize_path(&self.cwd, path))
+                        && path.is_file()
+                }) {
+                    watched_paths = Self::normalized_paths(&self.cwd, walk_paths());
+                }
+                let dependents = lint_service.invalidate(&changed_paths);
+                let paths = changed_paths
+                    .into_iter()
+                    .chain(dependents.iter().map(PathBuf::from))
+                    .filter_map(|path| watched_paths.get(&Self::normalize_path(&self.cwd, &path)))
+                    .filter(|path| Path::new(path).is_file())
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if !paths.is_empty() {
+                    break paths;
+                }
+            };
+            now = Instant::now();
+            number_of_files = paths.len();
+            lint_service.set_paths(paths);
+        }
+    }
+
+    /// Lint, and lint again whenever files change. Linting starts from the beginning when a
+    /// config file changes, to load the configuration again.
+    fn watch(self, stdout: &mut dyn Write) -> CliRunResult {
+        if self.options.stdin_options.stdin {
+            stdout
+                .write_all(b"`--watch` can not be used with `--stdin`.\n")
+                .or_else(Self::check_for_writer_error)
+                .unwrap();
             stdout.flush().unwrap();
+
+            return CliRunResult::InvalidOptionConfig;
+        }
+
+        let config_files = self
+            .options
+            .basic_options
+            .config
+            .iter()
+            .map(|path| self.cwd.join(path))
+            .chain([self.cwd.join(&self.options.ignore_options.ignore_path)])
+            .collect::<Vec<_>>();
+        let mut roots =
+            self.options.paths.iter().map(|path| self.cwd.join(path)).collect::<Vec<_>>();
+        if roots.is_empty() {
+            roots.push(self.cwd.clone());
+        }
+        // Files written after linting, which would otherwise trigger linting again
+        let written_files = [
+            self.options
+                .cache_options
+                .cache
+                .then(|| self.cwd.join(&self.options.cache_options.cache_location)),
+            self.options.baseline_options.baseline.as_ref().map(|path| self.cwd.join(path)),
+        ];
+        let watcher = Watcher::new(config_files.clone())
+            .map(|watcher| watcher.with_ignored_files(written_files.into_iter().flatten()))
+            .and_then(|watcher| {
+                watcher.with_ignore_options(&self.options.ignore_options, &self.cwd)
+            })
+            .and_then(|mut watcher| {
+                for root in &roots {
+                    watcher.watch(root, true)?;
+                }
+                // Config files outside of the paths to lint, like `.oxlintrc.json` in the working directory
+                let mut directories = config_files
+                    .iter()
+                    .filter_map(|path| path.parent())
+                    .chain([self.cwd.as_path()])
+                    .filter(|directory| !roots.iter().any(|root| directory.starts_with(root)))
+                    .collect::<Vec<_>>();
+                directories.sort_unstable();
+                directories.dedup();
+                for directory in directories {
+                    watcher.watch(directory, false)?;
+                }
+                Ok(watcher)
+            });
+        let mut watcher = match watcher {
+            Ok(watcher) => watcher,
+            Err(err) => {
+                stdout
+                    .write_all(format!("{err}\n").as_bytes())
+                    .or_else(Self::check_for_writer_error)
+                    .unwrap();
+                stdout.flush().unwrap();
+
+                return CliRunResult::InvalidOptionConfig;
+            }
         };
 
-        if diagnostic_result.errors_count() > 0 {
-            CliRunResult::LintFoundErrors
-        } else if warning_options.deny_warnings && diagnostic_result.warnings_count() > 0 {
-            CliRunResult::LintNoWarningsAllowed
-        } else if diagnostic_result.max_warnings_exceeded() {
-            CliRunResult::LintMaxWarningsExceeded
-        } else {
-            CliRunResult::LintSucceeded
+        loop {
+            match self.clone().lint(stdout, Some(&mut watcher)) {
+                // Lint again when the configuration was fixed
+                CliRunResult::InvalidOptionConfig | CliRunResult::LintNoFilesFound => {
+                    Self::print_watching(stdout);
+                    watcher.wait_for_config();
+                }
+                // Linting only returns when a config file changed
+                CliRunResult::LintSucceeded
+                | CliRunResult::LintFoundErrors
+                | CliRunResult::LintMaxWarningsExceeded
+                | CliRunResult::LintNoWarningsAllowed => {}
+                result => return result,
+            }
         }
     }
-}
 
-impl LintRunner {
-    const DEFAULT_OXLINTRC: &'static str = ".oxlintrc.json";
+    fn print_watching(stdout: &mut dyn Write) {
+        stdout
+            .write_all(b"Watching for file changes...\n")
+            .or_else(Self::check_for_writer_error)
+            .unwrap();
+        stdout.flush().unwrap();
+    }
+
+    /// Paths by their normalized absolute path, to look up paths reported by the file watcher
+    fn normalized_paths(
+        cwd: &Path,
+        paths: impl IntoIterator<Item = Arc<OsStr>>,
+    ) -> FxHashMap<PathBuf, Arc<OsStr>> {
+        paths.into_iter().map(|path| (Self::normalize_path(cwd, Path::new(&path)), path)).collect()
+    }
+
+    fn normalize_path(cwd: &Path, path: &Path) -> PathBuf {
+        cwd.join(path).components().collect()
+    }
 
     #[must_use]
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -404,6 +910,13 @@ impl LintRunner {
         self
     }
 
+    /// Use `source_text` for `--stdin` instead of reading stdin.
+    #[must_use]
+    pub fn with_stdin(mut self, source_text: String) -> Self {
+        self.stdin = Some(source_text);
+        self
+    }
+
     fn get_diagnostic_service(
         reporter: &OutputFormatter,
         warning_options: &WarningOptions,
@@ -533,7 +1046,10 @@ impl LintRunner {
 
 #[cfg(test)]
 mod test {
-    use std::{fs, path::PathBuf};
+    use std::{fs, path::PathBuf, process::Command};
+
+    use cow_utils::CowUtils;
+    use serde_json::Value;
 
     use super::LintRunner;
     use crate::tester::Tester;
@@ -829,6 +1345,182 @@ mod test {
         fs::write(file, content_original).unwrap();
     }
 
+    #[test]
+    fn test_fix_dry_run() {
+        use std::fs;
+        let file = "fixtures/fix_passes/spread.js";
+        let content = fs::read_to_string(file).unwrap();
+
+        // Fixes of nested spreads overlap, so they are applied in two passes
+        let args_1 = &["-A", "all", "-W", "unicorn/no-useless-spread", "--fix-dry-run", file];
+        // Diffs can not be printed in machine readable formats
+        let args_2 = &[
+            "-A",
+            "all",
+            "-W",
+            "unicorn/no-useless-spread",
+            "--fix-dry-run",
+            "--format=unix",
+            file,
+        ];
+        Tester::new().test_and_snapshot_multiple(&[args_1, args_2]);
+
+        // The file is not modified
+        assert_eq!(fs::read_to_string(file).unwrap(), content);
+    }
+
+    #[test]
+    fn test_fix_dry_run_oscillating() {
+        // `yoda` and `unicorn/explicit-length-check` undo each other's fixes
+        let args = &[
+            "-c",
+            "fixtures/fix_passes/oscillating.json",
+            "--fix-dry-run",
+            "fixtures/fix_passes/oscillating.js",
+        ];
+        Tester::new().test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_report_unused_directives() {
+        let args_1 = &["--report-unused-disable-directives", "test.js"];
+        let args_2 = &["--report-unused-disable-directives=warn", "test.js"];
+        Tester::new()
+            .with_cwd("fixtures/report_unused_directives".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let args = &["--baseline", "baseline.json", "test.js"];
+        Tester::new().with_cwd("fixtures/baseline".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_update_baseline() {
+        let dir = tempfile::tempdir().unwrap();
+        let baseline = dir.path().join("baseline.json");
+        // Entries of files which are not linted are kept
+        fs::write(
+            &baseline,
+            r#"{"other.js":{"eslint(no-debugger)":{"0123456789abcdef":1}},"test.js":{"eslint(no-debugger)":{"0123456789abcdef":1}}}"#,
+        )
+        .unwrap();
+        let args = &["--baseline", baseline.to_str().unwrap(), "--update-baseline", "test.js"];
+        Tester::new().with_cwd("fixtures/baseline".into()).test(args);
+
+        let baseline: Value = serde_json::from_str(&fs::read_to_string(baseline).unwrap()).unwrap();
+        assert_eq!(baseline["other.js"]["eslint(no-debugger)"]["0123456789abcdef"], 1);
+        assert_eq!(baseline["test.js"]["eslint(no-debugger)"].as_object().unwrap().len(), 1);
+        assert_eq!(baseline["test.js"]["eslint(no-debugger)"]["2ffeb41dbb44d806"], 3);
+        assert_eq!(baseline["test.js"]["eslint(no-unused-vars)"].as_object().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join(".oxlintcache");
+        let args = &["--cache", "--cache-location", cache.to_str().unwrap(), "test.js"];
+        let tester = Tester::new().with_cwd("fixtures/baseline".into());
+        tester.test(args);
+
+        let content = fs::read_to_string(&cache).unwrap();
+        let json: Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+        let files = json["files"].as_object().unwrap();
+        assert_eq!(files.len(), 1);
+        let (path, entry) = files.iter().next().unwrap();
+        assert!(path.ends_with("test.js"));
+        assert_eq!(entry["diagnostics"].as_array().unwrap().len(), 6);
+
+        // The file is unchanged, so the cached result is reused instead of linting it again
+        let content = content.cow_replace("`debugger` statement is not allowed", "cached debugger");
+        fs::write(&cache, content.as_ref()).unwrap();
+        let output = tester.test_output(args);
+        assert!(output.contains("cached debugger"), "{output}");
+        assert_eq!(fs::read_to_string(&cache).unwrap(), content);
+    }
+
+    #[test]
+    fn test_cache_unresolved_import() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.js"), "import { foo } from './b';\nfoo();\n").unwrap();
+        let cache = dir.path().join(".oxlintcache");
+        let args = &[
+            "--cache",
+            "--cache-location",
+            cache.to_str().unwrap(),
+            "--import-plugin",
+            "-D",
+            "import/named",
+            "a.js",
+        ];
+        let tester = Tester::new().with_cwd(dir.path().to_path_buf());
+        let output = tester.test_output(args);
+        assert!(output.contains("Found 0 warnings and 0 errors"), "{output}");
+
+        // The import can be resolved now, so the cached result is outdated
+        fs::write(dir.path().join("b.js"), "export const bar = 1;\n").unwrap();
+        let output = tester.test_output(args);
+        assert!(output.contains("eslint-plugin-import(named)"), "{output}");
+    }
+
+    #[test]
+    fn test_changed_files_with_dependents() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(["-c", "user.name=oxlint", "-c", "user.email=oxlint@oxc.rs"])
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {args:?}");
+        };
+        fs::write(dir.path().join("a.js"), "import { b } from './b';\nb();\ndebugger;\n").unwrap();
+        fs::write(dir.path().join("b.js"), "export function b() {}\n").unwrap();
+        fs::write(dir.path().join("c.js"), "debugger;\n").unwrap();
+        git(&["init", "-q"]);
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "init"]);
+        fs::write(dir.path().join("b.js"), "export function b() { debugger; }\n").unwrap();
+
+        let args = &["--import-plugin", "--changed-since", "HEAD", "--include-dependents"];
+        let output = Tester::new().with_cwd(dir.path().to_path_buf()).test_output(args);
+        // `c.js` is parsed but not linted
+        assert!(output.contains("Found 2 warnings and 0 errors"), "{output}");
+        assert!(output.contains("on 2 files"), "{output}");
+    }
+
+    #[test]
+    fn test_stdin() {
+        let args_1 = &["--stdin", "--stdin-filename", "virtual.ts"];
+        let args_2 = &["--stdin", "--stdin-filename", "src/virtual.jsx"];
+        Tester::new()
+            .with_cwd("fixtures/overrides".into())
+            .with_stdin("var x = 1;\nconsole.log(x);\n")
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
+    #[test]
+    fn test_stdin_fix() {
+        let args = &["--stdin", "--stdin-filename", "virtual.js", "-D", "no-debugger", "--fix"];
+        Tester::new().with_stdin("debugger;\nfoo();\n").test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_stdin_without_filename() {
+        let args = &["--stdin"];
+        Tester::new().with_stdin("debugger;\n").test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_watch_with_stdin() {
+        let args = &["--watch", "--stdin", "--stdin-filename", "test.js"];
+        Tester::new().with_stdin("debugger;\n").test_and_snapshot(args);
+    }
+
     #[test]
     fn test_print_config_ban_all_rules() {
         let args = &["-A", "all", "--print-config"];
@@ -849,6 +1541,29 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_print_config_file() {
+        let args = &[
+            "-c",
+            "fixtures/print_config/file/.oxlintrc.json",
+            "-A",
+            "correctness",
+            "-D",
+            "no-empty",
+            "--vitest-plugin",
+            "--print-config",
+            "fixtures/print_config/file/a.test.js",
+        ];
+        Tester::new().test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_print_config_invalid_file() {
+        let args_1 = &["--print-config", "fixtures/print_config/file/does-not-exist.js"];
+        let args_2 = &["--print-config", "fixtures/print_config/file"];
+        Tester::new().test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
     #[test]
     fn test_init_config() {
         assert!(!fs::exists(LintRunner::DEFAULT_OXLINTRC).unwrap());
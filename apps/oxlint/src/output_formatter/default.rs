@@ -27,19 +27,29 @@ impl InternalFormatter for DefaultOutputFormatter {
         let time = Self::get_execution_time(&lint_command_info.start_time);
         let s = if lint_command_info.number_of_files == 1 { "" } else { "s" };
 
+        let fixed = lint_command_info.fix_summary.map_or_else(String::new, |summary| {
+            let files = if summary.files == 1 { "file" } else { "files" };
+            let passes = if summary.passes == 1 { "pass" } else { "passes" };
+            let fixed = if lint_command_info.fix_dry_run { "Would fix" } else { "Fixed" };
+            format!("{fixed} {} {files} in {} {passes}.\n", summary.files, summary.passes)
+        });
         if let Some(number_of_rules) = lint_command_info.number_of_rules {
             Some(format!(
-                "Finished in {time} on {} file{s} with {} rules using {} threads.\n",
+                "{fixed}Finished in {time} on {} file{s} with {} rules using {} threads.\n",
                 lint_command_info.number_of_files, number_of_rules, lint_command_info.threads_count
             ))
         } else {
             Some(format!(
-                "Finished in {time} on {} file{s} using {} threads.\n",
+                "{fixed}Finished in {time} on {} file{s} using {} threads.\n",
                 lint_command_info.number_of_files, lint_command_info.threads_count
             ))
         }
     }
 
+    fn print_fix_diffs(&self) -> bool {
+        true
+    }
+
     #[cfg(not(test))]
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
         Box::new(GraphicalReporter::default())
@@ -160,6 +170,7 @@ mod test {
         default::{DefaultOutputFormatter, GraphicalReporter},
     };
     use oxc_diagnostics::reporter::{DiagnosticReporter, DiagnosticResult};
+    use oxc_linter::FixSummary;
 
     #[test]
     fn all_rules() {
@@ -177,6 +188,8 @@ mod test {
             number_of_rules: Some(10),
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            fix_summary: None,
+            fix_dry_run: false,
         });
 
         assert!(result.is_some());
@@ -186,6 +199,38 @@ mod test {
         );
     }
 
+    #[test]
+    fn lint_command_info_fix_summary() {
+        let formatter = DefaultOutputFormatter;
+        let result = formatter.lint_command_info(&LintCommandInfo {
+            number_of_files: 5,
+            number_of_rules: Some(10),
+            threads_count: 12,
+            start_time: Duration::new(1, 0),
+            fix_summary: Some(FixSummary { files: 2, passes: 3 }),
+            fix_dry_run: false,
+        });
+
+        assert_eq!(
+            result.unwrap(),
+            "Fixed 2 files in 3 passes.\nFinished in 1.0s on 5 files with 10 rules using 12 threads.\n"
+        );
+
+        let result = formatter.lint_command_info(&LintCommandInfo {
+            number_of_files: 5,
+            number_of_rules: Some(10),
+            threads_count: 12,
+            start_time: Duration::new(1, 0),
+            fix_summary: Some(FixSummary { files: 1, passes: 1 }),
+            fix_dry_run: true,
+        });
+
+        assert_eq!(
+            result.unwrap(),
+            "Would fix 1 file in 1 pass.\nFinished in 1.0s on 5 files with 10 rules using 12 threads.\n"
+        );
+    }
+
     #[test]
     fn lint_command_info_unknown_rules() {
         let formatter = DefaultOutputFormatter;
@@ -194,6 +239,8 @@ mod test {
             number_of_rules: None,
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            fix_summary: None,
+            fix_dry_run: false,
         });
 
         assert!(result.is_some());
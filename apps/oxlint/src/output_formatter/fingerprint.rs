@@ -0,0 +1,45 @@
+use oxc_diagnostics::{Error, reporter::Info};
+use rustc_hash::FxHashMap;
+use sha1::{Digest, Sha1};
+
+/// Computes a fingerprint for each diagnostic, which identifies it across runs.
+///
+/// The fingerprint hashes the rule, file name, message and the source text the diagnostic
+/// points to, but not its position, so it does not change when code above it is edited.
+/// Identical diagnostics in the same file are told apart by the order they occur in.
+pub fn fingerprints(diagnostics: &[Error]) -> Vec<String> {
+    let mut occurrences: FxHashMap<Vec<u8>, usize> = FxHashMap::default();
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let mut hasher = Sha1::new();
+            if let Some(code) = diagnostic.code() {
+                hasher.update(code.to_string());
+            }
+            hasher.update([0]);
+            hasher.update(Info::new(diagnostic).filename);
+            hasher.update([0]);
+            hash_message_and_source(&mut hasher, diagnostic);
+
+            let occurrence = occurrences.entry(hasher.clone().finalize().to_vec()).or_default();
+            hasher.update([0]);
+            hasher.update(occurrence.to_string());
+            *occurrence += 1;
+            format!("{:x}", hasher.finalize())
+        })
+        .collect()
+}
+
+/// Hash the message of a diagnostic and the source text of its first label, which identify it
+/// independently of its position.
+pub fn hash_message_and_source(hasher: &mut Sha1, diagnostic: &Error) {
+    hasher.update(diagnostic.to_string());
+    hasher.update([0]);
+    if let (Some(source), Some(label)) =
+        (diagnostic.source_code(), diagnostic.labels().and_then(|mut labels| labels.next()))
+    {
+        if let Ok(span_content) = source.read_span(label.inner(), 0, 0) {
+            hasher.update(span_content.data());
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use cow_utils::CowUtils;
+use oxc_diagnostics::{
+    Error, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
+};
+
+use super::{InternalFormatter, fingerprint::fingerprints};
+
+#[derive(Debug, Default)]
+pub struct GitlabOutputFormatter;
+
+impl InternalFormatter for GitlabOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(GitlabReporter::default())
+    }
+}
+
+/// Renders reports as a GitLab Code Quality report.
+///
+/// <https://docs.gitlab.com/ci/testing/code_quality/#code-quality-report-format>
+#[derive(Default)]
+struct GitlabReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for GitlabReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        Some(format_gitlab(&self.diagnostics))
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+#[derive(serde::Serialize)]
+struct GitlabIssue {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: &'static str,
+    location: GitlabLocation,
+}
+
+#[derive(serde::Serialize)]
+struct GitlabLocation {
+    path: String,
+    lines: GitlabLines,
+}
+
+#[derive(serde::Serialize)]
+struct GitlabLines {
+    begin: usize,
+    end: usize,
+}
+
+fn format_gitlab(diagnostics: &[Error]) -> String {
+    let issues = diagnostics
+        .iter()
+        .zip(fingerprints(diagnostics))
+        .map(|(diagnostic, fingerprint)| {
+            let Info { start, end, filename, .. } = Info::new(diagnostic);
+            GitlabIssue {
+                description: diagnostic.to_string(),
+                check_name: diagnostic
+                    .code()
+                    .map_or_else(|| "oxlint".to_string(), |code| code.to_string()),
+                fingerprint,
+                severity: if diagnostic.severity() == Some(Severity::Error) {
+                    "major"
+                } else {
+                    "minor"
+                },
+                location: GitlabLocation {
+                    path: filename.cow_replace('\\', "/").into_owned(),
+                    // GitLab requires line numbers to start at 1
+                    lines: GitlabLines { begin: start.line.max(1), end: end.line.max(1) },
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&issues).expect("Failed to serialize") + "\n"
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::GitlabReporter;
+
+    #[test]
+    fn reporter() {
+        let mut reporter = GitlabReporter::default();
+
+        let error = OxcDiagnostic::warn("error message")
+            .with_label(Span::new(0, 8))
+            .with_source_code(NamedSource::new("file://test.ts", "debugger;"));
+
+        let first_result = reporter.render_error(error);
+
+        // reporter keeps it in memory
+        assert!(first_result.is_none());
+
+        // report not gives us all diagnostics at ones
+        let second_result = reporter.finish(&DiagnosticResult::default());
+
+        assert!(second_result.is_some());
+        let issues: serde_json::Value = serde_json::from_str(&second_result.unwrap()).unwrap();
+        assert_eq!(issues[0]["description"], "error message");
+        assert_eq!(issues[0]["check_name"], "oxlint");
+        assert_eq!(issues[0]["severity"], "minor");
+        assert_eq!(issues[0]["location"]["path"], "file://test.ts");
+        assert_eq!(issues[0]["location"]["lines"]["begin"], 1);
+        assert_eq!(issues[0]["fingerprint"].as_str().unwrap().len(), 40);
+    }
+
+    #[test]
+    fn identical_diagnostics_have_distinct_fingerprints() {
+        let mut reporter = GitlabReporter::default();
+        let source = std::sync::Arc::new(NamedSource::new("test.ts", "debugger;\ndebugger;"));
+        for span in [Span::new(0, 9), Span::new(10, 19)] {
+            let error = OxcDiagnostic::warn("error message")
+                .with_label(span)
+                .with_source_code(std::sync::Arc::clone(&source));
+            reporter.render_error(error);
+        }
+
+        let result = reporter.finish(&DiagnosticResult::default()).unwrap();
+        let issues: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_ne!(issues[0]["fingerprint"], issues[1]["fingerprint"]);
+        assert_eq!(issues[1]["location"]["lines"]["begin"], 2);
+    }
+}
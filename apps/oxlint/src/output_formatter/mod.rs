@@ -1,8 +1,11 @@
 mod checkstyle;
 mod default;
+mod fingerprint;
 mod github;
+mod gitlab;
 mod json;
 mod junit;
+mod sarif;
 mod stylish;
 mod unix;
 mod xml_utils;
@@ -12,14 +15,19 @@ use std::time::Duration;
 
 use checkstyle::CheckStyleOutputFormatter;
 use github::GithubOutputFormatter;
+use gitlab::GitlabOutputFormatter;
 use junit::JUnitOutputFormatter;
+use sarif::SarifOutputFormatter;
 use stylish::StylishOutputFormatter;
 use unix::UnixOutputFormatter;
 
 use oxc_diagnostics::reporter::DiagnosticReporter;
+use oxc_linter::FixSummary;
 
 use crate::output_formatter::{default::DefaultOutputFormatter, json::JsonOutputFormatter};
 
+pub use fingerprint::hash_message_and_source;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum OutputFormat {
     Default,
@@ -31,6 +39,12 @@ pub enum OutputFormat {
     Checkstyle,
     Stylish,
     JUnit,
+    /// Static Analysis Results Interchange Format
+    /// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+    Sarif,
+    /// GitLab Code Quality report
+    /// <https://docs.gitlab.com/ci/testing/code_quality/#code-quality-report-format>
+    Gitlab,
 }
 
 impl FromStr for OutputFormat {
@@ -45,6 +59,8 @@ impl FromStr for OutputFormat {
             "github" => Ok(Self::Github),
             "stylish" => Ok(Self::Stylish),
             "junit" => Ok(Self::JUnit),
+            "sarif" => Ok(Self::Sarif),
+            "gitlab" => Ok(Self::Gitlab),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
@@ -62,6 +78,10 @@ pub struct LintCommandInfo {
     pub threads_count: usize,
     /// Some reporters want to output the duration it took to finished the task
     pub start_time: Duration,
+    /// The number of fixed files and the passes needed to fix them, if any file was fixed
+    pub fix_summary: Option<FixSummary>,
+    /// Whether the fixes were only shown with `--fix-dry-run`, instead of being written
+    pub fix_dry_run: bool,
 }
 
 /// An Interface for the different output formats.
@@ -77,6 +97,19 @@ trait InternalFormatter {
         None
     }
 
+    /// Whether the diagnostics passed to the reporter should carry their fixes,
+    /// see [`OxcDiagnostic::from_error`](oxc_diagnostics::OxcDiagnostic::from_error).
+    fn report_fixes(&self) -> bool {
+        false
+    }
+
+    /// Whether the diffs of `--fix-dry-run` are printed before the output of
+    /// [`DiagnosticReporter::finish`]. `--fix-dry-run` is rejected for machine readable formats,
+    /// which do not print them.
+    fn print_fix_diffs(&self) -> bool {
+        false
+    }
+
     /// oxlint words with [`DiagnosticService`](oxc_diagnostics::DiagnosticService),
     /// which uses a own reporter to output to stdout.
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter>;
@@ -100,6 +133,8 @@ impl OutputFormatter {
             OutputFormat::Default => Box::new(DefaultOutputFormatter),
             OutputFormat::Stylish => Box::<StylishOutputFormatter>::default(),
             OutputFormat::JUnit => Box::<JUnitOutputFormatter>::default(),
+            OutputFormat::Sarif => Box::<SarifOutputFormatter>::default(),
+            OutputFormat::Gitlab => Box::<GitlabOutputFormatter>::default(),
         }
     }
 
@@ -114,6 +149,18 @@ impl OutputFormatter {
         self.internal.lint_command_info(lint_command_info)
     }
 
+    /// Whether the linter should attach fixes to diagnostics.
+    /// See [`InternalFormatter::report_fixes`] for more details.
+    pub fn report_fixes(&self) -> bool {
+        self.internal.report_fixes()
+    }
+
+    /// Whether the diffs of `--fix-dry-run` are printed.
+    /// See [`InternalFormatter::print_fix_diffs`] for more details.
+    pub fn print_fix_diffs(&self) -> bool {
+        self.internal.print_fix_diffs()
+    }
+
     /// Returns the [`DiagnosticReporter`] which then will be used by [`DiagnosticService`](oxc_diagnostics::DiagnosticService)
     /// See [`InternalFormatter::get_diagnostic_reporter`] for more details.
     pub fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
@@ -172,4 +219,21 @@ mod test {
 
         Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
     }
+
+    /// disabled for windows
+    /// sarif will output the fix offsets which will be different for windows
+    #[cfg(all(test, not(target_os = "windows")))]
+    #[test]
+    fn test_output_formatter_diagnostic_sarif() {
+        let args = &["--format=sarif", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_output_formatter_diagnostic_gitlab() {
+        let args = &["--format=gitlab", "test.js"];
+
+        Tester::new().with_cwd(TEST_CWD.into()).test_and_snapshot(args);
+    }
 }
@@ -0,0 +1,338 @@
+use cow_utils::CowUtils;
+use oxc_diagnostics::{
+    Error, OxcDiagnostic, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
+};
+use oxc_linter::{RuleCategory, rules::RULES};
+use rustc_hash::FxHashMap;
+
+use super::{InternalFormatter, fingerprint::fingerprints};
+
+#[derive(Debug, Default)]
+pub struct SarifOutputFormatter;
+
+impl InternalFormatter for SarifOutputFormatter {
+    fn report_fixes(&self) -> bool {
+        true
+    }
+
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(SarifReporter::default())
+    }
+}
+
+/// Renders reports as a SARIF 2.1.0 log.
+///
+/// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+#[derive(Default)]
+struct SarifReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        Some(format_sarif(&self.diagnostics))
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+#[derive(serde::Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun; 1],
+}
+
+#[derive(serde::Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(serde::Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    default_configuration: SarifRuleConfiguration,
+}
+
+#[derive(serde::Serialize)]
+struct SarifRuleConfiguration {
+    enabled: bool,
+    level: &'static str,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    locations: [SarifLocation; 1],
+    partial_fingerprints: SarifFingerprints,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(serde::Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Clone, serde::Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    byte_offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    byte_length: Option<usize>,
+}
+
+#[derive(serde::Serialize)]
+struct SarifFingerprints {
+    #[serde(rename = "oxlint/v1")]
+    oxlint: String,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    artifact_changes: [SarifArtifactChange; 1],
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: [SarifReplacement; 1],
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    inserted_content: SarifMessage,
+}
+
+fn format_sarif(diagnostics: &[Error]) -> String {
+    let mut rules: Vec<SarifRule> = vec![];
+    let mut rule_indices: FxHashMap<String, usize> = FxHashMap::default();
+
+    let results = diagnostics
+        .iter()
+        .zip(fingerprints(diagnostics))
+        .map(|(diagnostic, fingerprint)| {
+            let Info { start, end, filename, .. } = Info::new(diagnostic);
+            let artifact_location =
+                SarifArtifactLocation { uri: filename.cow_replace('\\', "/").into_owned() };
+
+            let level = sarif_level(diagnostic);
+            let rule_id = diagnostic.code().map(|code| code.to_string());
+            let rule_index = rule_id.as_ref().map(|rule_id| {
+                let index = *rule_indices.entry(rule_id.clone()).or_insert_with(|| {
+                    rules.push(sarif_rule(rule_id.clone(), diagnostic));
+                    rules.len() - 1
+                });
+                // A rule may be configured as an error only in some overrides.
+                if level == "error" {
+                    rules[index].default_configuration.level = level;
+                }
+                index
+            });
+
+            let region = (start.line > 0).then_some(SarifRegion {
+                start_line: Some(start.line),
+                start_column: Some(start.column),
+                end_line: Some(end.line),
+                end_column: Some(end.column),
+                ..SarifRegion::default()
+            });
+
+            let fixes = OxcDiagnostic::from_error(diagnostic)
+                .and_then(|diagnostic| diagnostic.fix.as_ref())
+                .map(|fix| SarifFix {
+                    artifact_changes: [SarifArtifactChange {
+                        artifact_location: artifact_location.clone(),
+                        replacements: [SarifReplacement {
+                            deleted_region: SarifRegion {
+                                byte_offset: Some(fix.span.offset()),
+                                byte_length: Some(fix.span.len()),
+                                ..SarifRegion::default()
+                            },
+                            inserted_content: SarifMessage { text: fix.content.to_string() },
+                        }],
+                    }],
+                })
+                .into_iter()
+                .collect();
+
+            SarifResult {
+                rule_id,
+                rule_index,
+                level,
+                message: SarifMessage { text: diagnostic.to_string() },
+                locations: [SarifLocation {
+                    physical_location: SarifPhysicalLocation { artifact_location, region },
+                }],
+                partial_fingerprints: SarifFingerprints { oxlint: fingerprint },
+                fixes,
+            }
+        })
+        .collect();
+
+    let log = SarifLog {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: [SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver { name: "oxlint", information_uri: "https://oxc.rs", rules },
+            },
+            results,
+        }],
+    };
+    serde_json::to_string_pretty(&log).expect("Failed to serialize") + "\n"
+}
+
+/// SARIF level of the configured severity of a diagnostic.
+fn sarif_level(diagnostic: &Error) -> &'static str {
+    if diagnostic.severity() == Some(Severity::Error) { "error" } else { "warning" }
+}
+
+/// Rule metadata for a diagnostic's rule, as found in [`RULES`].
+/// Rules are enabled by default when they are in the correctness category,
+/// and their level is the configured severity of the rule.
+fn sarif_rule(id: String, diagnostic: &Error) -> SarifRule {
+    let rule = OxcDiagnostic::from_error(diagnostic).and_then(|diagnostic| {
+        RULES.iter().find(|rule| {
+            diagnostic.code.scope.as_deref() == Some(rule.plugin_prefix())
+                && diagnostic.code.number.as_deref() == Some(rule.name())
+        })
+    });
+    SarifRule {
+        id,
+        help_uri: rule.map(oxc_linter::rules::RuleEnum::documentation_url),
+        default_configuration: SarifRuleConfiguration {
+            enabled: rule.is_none_or(|rule| rule.category() == RuleCategory::Correctness),
+            level: sarif_level(diagnostic),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::SarifReporter;
+
+    #[test]
+    fn reporter() {
+        let mut reporter = SarifReporter::default();
+
+        let error = OxcDiagnostic::error("error message")
+            .with_error_code("eslint", "no-debugger")
+            .with_label(Span::new(0, 9))
+            .with_fix(Span::new(0, 9), "")
+            .with_source_code(NamedSource::new("file://test.ts", "debugger;"));
+
+        let first_result = reporter.render_error(error);
+
+        // reporter keeps it in memory
+        assert!(first_result.is_none());
+
+        // report not gives us all diagnostics at ones
+        let second_result = reporter.finish(&DiagnosticResult::default());
+
+        assert!(second_result.is_some());
+        let log: serde_json::Value = serde_json::from_str(&second_result.unwrap()).unwrap();
+        let run = &log["runs"][0];
+        assert_eq!(
+            run["tool"]["driver"]["rules"][0]["helpUri"],
+            "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"
+        );
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "eslint(no-debugger)");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["message"]["text"], "error message");
+        assert_eq!(result["locations"][0]["physicalLocation"]["region"]["startLine"], 1);
+        let replacement = &result["fixes"][0]["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["byteLength"], 9);
+        assert_eq!(replacement["insertedContent"]["text"], "");
+    }
+
+    #[test]
+    fn rule_level() {
+        let mut reporter = SarifReporter::default();
+        let diagnostic = |diagnostic: OxcDiagnostic, rule: &'static str| {
+            diagnostic
+                .with_error_code("eslint", rule)
+                .with_label(Span::new(0, 9))
+                .with_source_code(NamedSource::new("test.ts", "debugger;"))
+        };
+        reporter.render_error(diagnostic(OxcDiagnostic::warn("warning"), "no-debugger"));
+        reporter.render_error(diagnostic(OxcDiagnostic::error("error"), "no-debugger"));
+        reporter.render_error(diagnostic(OxcDiagnostic::warn("warning"), "no-console"));
+
+        let log = reporter.finish(&DiagnosticResult::default()).unwrap();
+        let log: serde_json::Value = serde_json::from_str(&log).unwrap();
+        let rules = &log["runs"][0]["tool"]["driver"]["rules"];
+        assert_eq!(rules[0]["id"], "eslint(no-debugger)");
+        assert_eq!(rules[0]["defaultConfiguration"]["level"], "error");
+        assert_eq!(rules[1]["id"], "eslint(no-console)");
+        assert_eq!(rules[1]["defaultConfiguration"]["level"], "warning");
+    }
+}
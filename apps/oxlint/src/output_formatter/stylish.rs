@@ -10,6 +10,10 @@ use crate::output_formatter::InternalFormatter;
 pub struct StylishOutputFormatter;
 
 impl InternalFormatter for StylishOutputFormatter {
+    fn print_fix_diffs(&self) -> bool {
+        true
+    }
+
     fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
         Box::new(StylishReporter::default())
     }
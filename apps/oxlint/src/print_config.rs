@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+
+use cow_utils::CowUtils;
+use oxc_linter::{ConfigSource, EffectiveConfig};
+use serde_json::{Map, Value, json};
+
+/// Format the effective configuration of a file as JSON, with paths relative to `cwd`.
+pub fn format_effective_config(cwd: &Path, config: &EffectiveConfig) -> String {
+    let source = |source: &ConfigSource| Value::String(format_source(cwd, source));
+
+    let plugins = config
+        .plugins
+        .iter()
+        .map(|(name, plugin_source)| ((*name).to_string(), source(plugin_source)))
+        .collect::<Map<_, _>>();
+    let rules = config
+        .rules
+        .iter()
+        .map(|rule| {
+            let name = if rule.plugin_name == "eslint" {
+                rule.rule_name.to_string()
+            } else {
+                format!("{}/{}", rule.plugin_name, rule.rule_name)
+            };
+            let value = json!({
+                "severity": rule.severity.as_str(),
+                "options": rule.options,
+                "source": source(&rule.source),
+            });
+            (name, value)
+        })
+        .collect::<Map<_, _>>();
+    let env = config
+        .env
+        .iter()
+        .map(|(name, env_source)| (name.clone(), source(env_source)))
+        .collect::<Map<_, _>>();
+    let settings = config
+        .settings
+        .iter()
+        .map(|(key, value, settings_source)| {
+            (key.clone(), json!({ "value": value, "source": source(settings_source) }))
+        })
+        .collect::<Map<_, _>>();
+    let globals = config
+        .globals
+        .iter()
+        .map(|(name, value, global_source)| {
+            (name.clone(), json!({ "value": value.as_str(), "source": source(global_source) }))
+        })
+        .collect::<Map<_, _>>();
+
+    let json = json!({
+        "configFile": config.path.as_deref().map(|path| relative_path(cwd, path)),
+        "plugins": plugins,
+        "rules": rules,
+        "env": env,
+        "globals": globals,
+        "settings": settings,
+    });
+    serde_json::to_string_pretty(&json).unwrap()
+}
+
+fn format_source(cwd: &Path, source: &ConfigSource) -> String {
+    match source {
+        ConfigSource::Default => "default".to_string(),
+        ConfigSource::File(path) => relative_path(cwd, path),
+        ConfigSource::Override { path, files } => {
+            let files = files.join(", ");
+            match path {
+                Some(path) => format!("{} (overrides: {files})", relative_path(cwd, path)),
+                None => format!("overrides: {files}"),
+            }
+        }
+        ConfigSource::CommandLine(option) => format!("command line: {option}"),
+    }
+}
+
+fn relative_path(cwd: &Path, path: &Path) -> String {
+    // Normalize paths of extended config files, like `./base.json`
+    let path = path.components().collect::<PathBuf>();
+    let path = path.strip_prefix(cwd).unwrap_or(&path);
+    path.to_string_lossy().cow_replace('\\', "/").into_owned()
+}
@@ -0,0 +1,172 @@
+use std::{str::FromStr, time::Duration};
+
+use oxc_linter::{LintTimings, PhaseTimings, RuleTiming};
+use serde_json::json;
+
+/// Number of the slowest rules shown in the table, like ESLint's `TIMING=1`
+const TABLE_RULES: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimingFormat {
+    Table,
+    Json,
+}
+
+impl FromStr for TimingFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("'{s}' is not a known timing format")),
+        }
+    }
+}
+
+impl TimingFormat {
+    pub fn format(self, timings: &LintTimings) -> String {
+        let rules = timings.rules();
+        let phases = timings.phases();
+        match self {
+            Self::Table => format_table(&rules, phases),
+            Self::Json => format_json(&rules, phases),
+        }
+    }
+}
+
+/// Milliseconds with microsecond precision
+#[expect(clippy::cast_precision_loss)]
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+fn rule_id(plugin: &str, name: &str) -> String {
+    format!("{plugin}({name})")
+}
+
+fn format_table(rules: &[(&str, &str, RuleTiming)], phases: PhaseTimings) -> String {
+    let total = rules.iter().map(|(_, _, timing)| timing.total()).sum::<Duration>();
+    let rows = rules
+        .iter()
+        .take(TABLE_RULES)
+        .map(|(plugin, name, timing)| {
+            let relative = if total.is_zero() {
+                0.0
+            } else {
+                timing.total().as_secs_f64() / total.as_secs_f64() * 100.0
+            };
+            (rule_id(plugin, name), milliseconds(timing.total()), format!("{relative:.1}%"))
+        })
+        .collect::<Vec<_>>();
+
+    let width = rows.iter().map(|(rule, _, _)| rule.len()).max().unwrap_or(0).max("Rule".len());
+    let mut output = format!("\n{:<width$} | Time (ms) | Relative\n", "Rule");
+    output.push_str(&format!("{:-<width$}-|-----------|---------\n", ""));
+    for (rule, time, relative) in rows {
+        output.push_str(&format!("{rule:<width$} | {time:>9.3} | {relative:>8}\n"));
+    }
+
+    let phase_rows = [
+        ("Parse", phases.parse),
+        ("Semantic", phases.semantic),
+        ("Module resolution", phases.module_resolution),
+        ("Rules", total),
+    ];
+    let width = "Module resolution".len();
+    output.push_str(&format!("\n{:<width$} | Time (ms)\n", "Phase"));
+    output.push_str(&format!("{:-<width$}-|----------\n", ""));
+    for (phase, time) in phase_rows {
+        output.push_str(&format!("{phase:<width$} | {:>9.3}\n", milliseconds(time)));
+    }
+    output
+}
+
+fn format_json(rules: &[(&str, &str, RuleTiming)], phases: PhaseTimings) -> String {
+    let rules = rules
+        .iter()
+        .map(|(plugin, name, timing)| {
+            json!({
+                "rule": rule_id(plugin, name),
+                "total": milliseconds(timing.total()),
+                "run": milliseconds(timing.run),
+                "runOnce": milliseconds(timing.run_once),
+                "runOnSymbol": milliseconds(timing.run_on_symbol),
+                "runOnJestNode": milliseconds(timing.run_on_jest_node),
+            })
+        })
+        .collect::<Vec<_>>();
+    let json = json!({
+        "rules": rules,
+        "phases": {
+            "parse": milliseconds(phases.parse),
+            "semantic": milliseconds(phases.semantic),
+            "moduleResolution": milliseconds(phases.module_resolution),
+        },
+    });
+    format!("{json}\n")
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use oxc_linter::{PhaseTimings, RuleTiming};
+
+    use super::{format_json, format_table};
+
+    fn timings() -> (Vec<(&'static str, &'static str, RuleTiming)>, PhaseTimings) {
+        let rules = vec![
+            (
+                "eslint",
+                "no-unused-vars",
+                RuleTiming {
+                    run: Duration::from_micros(2500),
+                    run_on_symbol: Duration::from_micros(500),
+                    ..RuleTiming::default()
+                },
+            ),
+            (
+                "eslint",
+                "no-debugger",
+                RuleTiming { run: Duration::from_micros(1000), ..RuleTiming::default() },
+            ),
+        ];
+        let phases = PhaseTimings {
+            parse: Duration::from_millis(5),
+            semantic: Duration::from_millis(7),
+            module_resolution: Duration::ZERO,
+        };
+        (rules, phases)
+    }
+
+    #[test]
+    fn table() {
+        let (rules, phases) = timings();
+        let expected = "
+Rule                   | Time (ms) | Relative
+-----------------------|-----------|---------
+eslint(no-unused-vars) |     3.000 |    75.0%
+eslint(no-debugger)    |     1.000 |    25.0%
+
+Phase             | Time (ms)
+------------------|----------
+Parse             |     5.000
+Semantic          |     7.000
+Module resolution |     0.000
+Rules             |     4.000
+";
+        assert_eq!(format_table(&rules, phases), expected);
+    }
+
+    #[test]
+    fn json() {
+        let (rules, phases) = timings();
+        let json: serde_json::Value = serde_json::from_str(&format_json(&rules, phases)).unwrap();
+        assert_eq!(json["rules"][0]["rule"], "eslint(no-unused-vars)");
+        assert_eq!(json["rules"][0]["total"], 3.0);
+        assert_eq!(json["rules"][0]["runOnSymbol"], 0.5);
+        assert_eq!(json["rules"][1]["rule"], "eslint(no-debugger)");
+        assert_eq!(json["phases"]["semantic"], 7.0);
+    }
+}
@@ -0,0 +1,282 @@
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Duration,
+};
+
+use ignore::overrides::{Override, OverrideBuilder};
+use notify::{
+    Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _,
+    event::{CreateKind, ModifyKind},
+};
+use rustc_hash::FxHashSet;
+
+use crate::cli::IgnoreOptions;
+
+/// Time to wait for more changes after a change, so that changes to many files at once, e.g. when
+/// switching branches, are linted together
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// File name of config files, which are also searched in nested directories
+const CONFIG_FILE_NAME: &str = ".oxlintrc.json";
+
+/// Directories which are never watched, because they can be large and are not linted
+const IGNORED_DIRECTORIES: [&str; 2] = ["node_modules", ".git"];
+
+/// Changes reported by [`Watcher::wait`]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Changes {
+    /// Files which were created, modified or removed
+    Files(Vec<PathBuf>),
+    /// A config file changed, so the configuration must be loaded again
+    Config,
+}
+
+/// Watches the file system for changes to lint again.
+pub struct Watcher {
+    inner: RecommendedWatcher,
+    rx: mpsc::Receiver<notify::Result<Event>>,
+    /// Config files other than `.oxlintrc.json`, such as the ignore file
+    config_files: FxHashSet<PathBuf>,
+    /// Files written by oxlint itself, such as the cache, whose changes are not linted
+    ignored_files: FxHashSet<PathBuf>,
+    ignore_options: Option<IgnoreOptions>,
+    /// Overrides for the ignore patterns passed with `--ignore-pattern`
+    overrides: Option<Override>,
+}
+
+impl Watcher {
+    /// # Errors
+    ///
+    /// When the file system can not be watched on this platform.
+    pub fn new(config_files: impl IntoIterator<Item = PathBuf>) -> Result<Self, String> {
+        let (tx, rx) = mpsc::channel();
+        let watcher = notify::recommended_watcher(tx)
+            .map_err(|err| format!("Failed to watch files: {err}"))?;
+        let config_files = config_files.into_iter().collect();
+        Ok(Self {
+            inner: watcher,
+            rx,
+            config_files,
+            ignored_files: FxHashSet::default(),
+            ignore_options: None,
+            overrides: None,
+        })
+    }
+
+    /// Ignore changes to `files`.
+    #[must_use]
+    pub fn with_ignored_files(mut self, files: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.ignored_files.extend(files);
+        self
+    }
+
+    /// Skip directories which are ignored by the ignore file and ignore patterns in `options`,
+    /// when watching recursively.
+    ///
+    /// # Errors
+    ///
+    /// When an ignore pattern is invalid.
+    pub fn with_ignore_options(
+        mut self,
+        options: &IgnoreOptions,
+        cwd: &Path,
+    ) -> Result<Self, String> {
+        if !options.no_ignore && !options.ignore_pattern.is_empty() {
+            let mut builder = OverrideBuilder::new(cwd);
+            for pattern in &options.ignore_pattern {
+                // Meaning of ignore pattern is reversed
+                // <https://docs.rs/ignore/latest/ignore/overrides/struct.OverrideBuilder.html#method.add>
+                builder.add(&format!("!{pattern}")).map_err(|err| err.to_string())?;
+            }
+            self.overrides = Some(builder.build().map_err(|err| err.to_string())?);
+        }
+        self.ignore_options = Some(options.clone());
+        Ok(self)
+    }
+
+    /// Watch `path`, and all paths inside of it which are not ignored when `recursive` is set.
+    ///
+    /// # Errors
+    ///
+    /// When `path` does not exist, or can not be watched.
+    pub fn watch(&mut self, path: &Path, recursive: bool) -> Result<(), String> {
+        if recursive && path.is_dir() {
+            self.watch_directories(path, path).map(|_| ())
+        } else {
+            self.watch_path(path)
+        }
+    }
+
+    fn watch_path(&mut self, path: &Path) -> Result<(), String> {
+        self.inner
+            .watch(path, RecursiveMode::NonRecursive)
+            .map_err(|err| format!("Failed to watch {}: {err}", path.display()))
+    }
+
+    /// Watch the directories inside of `root` which are not ignored, limited to `within`, and
+    /// return the files inside of them.
+    ///
+    /// Directories are walked from `root` instead of `within`, because ignore files only apply to
+    /// the paths inside of the walked directories, and not to the walked directories themselves.
+    fn watch_directories(&mut self, root: &Path, within: &Path) -> Result<Vec<PathBuf>, String> {
+        let mut builder = ignore::WalkBuilder::new(root);
+        if let Some(options) = &self.ignore_options {
+            if !options.no_ignore {
+                builder.add_custom_ignore_filename(&options.ignore_path);
+                if let Some(overrides) = &self.overrides {
+                    builder.overrides(overrides.clone());
+                }
+            }
+            builder.follow_links(options.symlinks);
+        }
+        let filtered_within = within.to_path_buf();
+        let walk = builder
+            .ignore(false)
+            .git_global(false)
+            .filter_entry(move |entry| {
+                entry.path().starts_with(&filtered_within)
+                    && !(entry.file_type().is_some_and(|file_type| file_type.is_dir())
+                        && IGNORED_DIRECTORIES
+                            .iter()
+                            .any(|name| entry.file_name() == OsStr::new(name)))
+            })
+            .build();
+
+        let mut files = vec![];
+        for entry in walk.flatten() {
+            if entry.depth() == 0 && root != within {
+                continue;
+            }
+            if entry.file_type().is_some_and(|file_type| file_type.is_dir()) {
+                self.watch_path(entry.path())?;
+            } else {
+                files.push(entry.into_path());
+            }
+        }
+        Ok(files)
+    }
+
+    /// Block until files change, and return the changes.
+    ///
+    /// # Panics
+    ///
+    /// When the watcher stopped, which only happens when its thread panicked.
+    pub fn wait(&mut self) -> Changes {
+        loop {
+            let mut files = FxHashSet::default();
+            let mut event = self.rx.recv().expect("The file watcher stopped");
+            // Collect all changes made shortly after each other
+            loop {
+                if let Ok(event) = event {
+                    if matches!(event.kind, EventKind::Create(CreateKind::Any | CreateKind::Folder))
+                    {
+                        for path in &event.paths {
+                            files.extend(self.watch_created_directory(path));
+                        }
+                    }
+                    if is_change(event.kind) {
+                        files.extend(event.paths);
+                    }
+                }
+                match self.rx.recv_timeout(DEBOUNCE) {
+                    Ok(next) => event = next,
+                    Err(_) => break,
+                }
+            }
+            files.retain(|file| !self.ignored_files.contains(file));
+            if files.iter().any(|file| self.is_config_file(file)) {
+                return Changes::Config;
+            }
+            if !files.is_empty() {
+                let mut files = files.into_iter().collect::<Vec<_>>();
+                files.sort_unstable();
+                return Changes::Files(files);
+            }
+        }
+    }
+
+    /// Block until a config file changes, ignoring changes to other files.
+    pub fn wait_for_config(&mut self) {
+        while self.wait() != Changes::Config {}
+    }
+
+    /// Watch `path` when it is a created directory which is not ignored, and return the files
+    /// which were created inside of it before it was watched.
+    fn watch_created_directory(&mut self, path: &Path) -> Vec<PathBuf> {
+        match path.parent() {
+            Some(parent) if path.is_dir() => {
+                self.watch_directories(parent, path).unwrap_or_default()
+            }
+            _ => vec![],
+        }
+    }
+
+    fn is_config_file(&self, path: &Path) -> bool {
+        path.file_name().is_some_and(|name| name == CONFIG_FILE_NAME)
+            || self.config_files.contains(path)
+    }
+}
+
+/// Whether the event changed the content of files, which excludes reading files and changing
+/// their permissions or timestamps.
+fn is_change(kind: EventKind) -> bool {
+    match kind {
+        EventKind::Create(_) | EventKind::Remove(_) => true,
+        EventKind::Modify(kind) => !matches!(kind, ModifyKind::Metadata(_)),
+        EventKind::Any | EventKind::Access(_) | EventKind::Other => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{Changes, Watcher};
+    use crate::cli::IgnoreOptions;
+
+    #[test]
+    fn wait() {
+        let dir = tempfile::tempdir().unwrap();
+        // Events are reported for canonical paths on some platforms, e.g. `/private/var` on macOS
+        let cwd = dir.path().canonicalize().unwrap();
+        fs::create_dir(cwd.join("nested")).unwrap();
+        fs::create_dir(cwd.join("node_modules")).unwrap();
+        fs::create_dir(cwd.join("ignored")).unwrap();
+        let ignore_options = IgnoreOptions {
+            ignore_path: ".eslintignore".into(),
+            ignore_pattern: vec!["ignored".to_string()],
+            no_ignore: false,
+            symlinks: false,
+        };
+        let mut watcher = Watcher::new([cwd.join(".eslintignore")])
+            .unwrap()
+            .with_ignored_files([cwd.join(".oxlintcache")])
+            .with_ignore_options(&ignore_options, &cwd)
+            .unwrap();
+        watcher.watch(&cwd, true).unwrap();
+
+        fs::write(cwd.join(".oxlintcache"), "{}").unwrap();
+        fs::write(cwd.join("node_modules/c.js"), "debugger;").unwrap();
+        fs::write(cwd.join("ignored/c.js"), "debugger;").unwrap();
+        fs::write(cwd.join("a.js"), "debugger;").unwrap();
+        fs::write(cwd.join("b.js"), "debugger;").unwrap();
+        assert_eq!(watcher.wait(), Changes::Files(vec![cwd.join("a.js"), cwd.join("b.js")]));
+
+        fs::create_dir(cwd.join("created")).unwrap();
+        fs::write(cwd.join("created/c.js"), "debugger;").unwrap();
+        assert_eq!(
+            watcher.wait(),
+            Changes::Files(vec![cwd.join("created"), cwd.join("created/c.js")])
+        );
+        fs::write(cwd.join("created/c.js"), "debugger;").unwrap();
+        assert_eq!(watcher.wait(), Changes::Files(vec![cwd.join("created/c.js")]));
+
+        fs::write(cwd.join("nested/.oxlintrc.json"), "{}").unwrap();
+        assert_eq!(watcher.wait(), Changes::Config);
+
+        fs::write(cwd.join(".eslintignore"), "a.js").unwrap();
+        assert_eq!(watcher.wait(), Changes::Config);
+    }
+}
@@ -271,7 +271,7 @@ pub trait CompilerInterface {
     }
 
     fn mangle(&self, program: &mut Program<'_>, options: MangleOptions) -> Scoping {
-        Mangler::new().with_options(options).build(program)
+        Mangler::new().with_options(options).build(program).scoping
     }
 
     fn codegen(
@@ -11,6 +11,7 @@ use oxc_ast_macros::ast;
 use oxc_estree::ESTree;
 use oxc_span::{Atom, ContentEq, GetSpan, GetSpanMut, SourceType, Span};
 use oxc_syntax::{
+    module_record::ImportPhase,
     operator::{
         AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
     },
@@ -1071,6 +1072,7 @@ pub enum Statement<'a> {
     TryStatement(Box<'a, TryStatement<'a>>) = 15,
     WhileStatement(Box<'a, WhileStatement<'a>>) = 16,
     WithStatement(Box<'a, WithStatement<'a>>) = 17,
+    ErrorStatement(Box<'a, ErrorStatement>) = 18,
     // `Declaration` variants added here by `inherit_variants!` macro
     @inherit Declaration
     // `ModuleDeclaration` variants added here by `inherit_variants!` macro
@@ -1206,6 +1208,16 @@ pub struct EmptyStatement {
     pub span: Span,
 }
 
+/// Source text which could not be parsed
+///
+/// Only produced by the parser when error recovery is enabled.
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, GetSpan, GetSpanMut, ContentEq, ESTree)]
+pub struct ErrorStatement {
+    pub span: Span,
+}
+
 /// Expression Statement
 #[ast(visit)]
 #[derive(Debug)]
@@ -2339,19 +2351,6 @@ pub struct ImportDeclaration<'a> {
     pub import_kind: ImportOrExportKind,
 }
 
-/// Import Phase
-///
-/// <https://github.com/tc39/proposal-defer-import-eval>
-/// <https://github.com/tc39/proposal-source-phase-imports>
-/// <https://github.com/estree/estree/blob/2b48e56efc223ea477a45b5e034039934c5791fa/stage3/source-phase-imports.md>
-#[ast]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[generate_derive(CloneIn, ContentEq, ESTree)]
-pub enum ImportPhase {
-    Source = 0,
-    Defer = 1,
-}
-
 #[ast(visit)]
 #[derive(Debug)]
 #[generate_derive(CloneIn, GetSpan, GetSpanMut, GetAddress, ContentEq, ESTree)]
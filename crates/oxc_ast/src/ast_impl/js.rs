@@ -1892,18 +1892,3 @@ impl<'a> ModuleExportName<'a> {
         }
     }
 }
-
-impl ImportPhase {
-    /// Returns the syntax associated with this [`ImportPhase`].
-    ///
-    /// ## Example
-    ///
-    /// - [`Source`][`ImportPhase::Source`] => `"source"`
-    /// - [`Defer`][`ImportPhase::Defer`] => `"defer"`
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Self::Source => "source",
-            Self::Defer => "defer",
-        }
-    }
-}
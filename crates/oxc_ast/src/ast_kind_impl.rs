@@ -9,7 +9,7 @@ impl<'a> AstKind<'a> {
     pub fn is_statement(self) -> bool {
         self.is_iteration_statement()
             || matches!(self, Self::BlockStatement(_) | Self::BreakStatement(_) | Self::ContinueStatement(_)
-                    | Self::DebuggerStatement(_) | Self::EmptyStatement(_) | Self::ExpressionStatement(_)
+                    | Self::DebuggerStatement(_) | Self::EmptyStatement(_) | Self::ErrorStatement(_) | Self::ExpressionStatement(_)
                     | Self::LabeledStatement(_) | Self::ReturnStatement(_) | Self::SwitchStatement(_)
                     | Self::ThrowStatement(_) | Self::TryStatement(_) | Self::WithStatement(_)
                     | Self::IfStatement(_) | Self::VariableDeclaration(_) | Self::ExportDefaultDeclaration(_))
@@ -202,6 +202,7 @@ impl AstKind<'_> {
             Self::DebuggerStatement(_) => "DebuggerStatement".into(),
             Self::DoWhileStatement(_) => "DoWhileStatement".into(),
             Self::EmptyStatement(_) => "EmptyStatement".into(),
+            Self::ErrorStatement(_) => "ErrorStatement".into(),
             Self::ExpressionStatement(_) => "ExpressionStatement".into(),
             Self::ForInStatement(_) => "ForInStatement".into(),
             Self::ForOfStatement(_) => "ForOfStatement".into(),
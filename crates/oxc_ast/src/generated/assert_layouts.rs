@@ -337,6 +337,10 @@ const _: () = {
     assert!(align_of::<EmptyStatement>() == 8);
     assert!(offset_of!(EmptyStatement, span) == 0);
 
+    assert!(size_of::<ErrorStatement>() == 8);
+    assert!(align_of::<ErrorStatement>() == 8);
+    assert!(offset_of!(ErrorStatement, span) == 0);
+
     assert!(size_of::<ExpressionStatement>() == 24);
     assert!(align_of::<ExpressionStatement>() == 8);
     assert!(offset_of!(ExpressionStatement, span) == 0);
@@ -675,9 +679,6 @@ const _: () = {
     assert!(offset_of!(ImportDeclaration, with_clause) == 88);
     assert!(offset_of!(ImportDeclaration, import_kind) == 96);
 
-    assert!(size_of::<ImportPhase>() == 1);
-    assert!(align_of::<ImportPhase>() == 1);
-
     assert!(size_of::<ImportDeclarationSpecifier>() == 16);
     assert!(align_of::<ImportDeclarationSpecifier>() == 8);
 
@@ -1742,6 +1743,10 @@ const _: () = {
     assert!(align_of::<EmptyStatement>() == 4);
     assert!(offset_of!(EmptyStatement, span) == 0);
 
+    assert!(size_of::<ErrorStatement>() == 8);
+    assert!(align_of::<ErrorStatement>() == 4);
+    assert!(offset_of!(ErrorStatement, span) == 0);
+
     assert!(size_of::<ExpressionStatement>() == 16);
     assert!(align_of::<ExpressionStatement>() == 4);
     assert!(offset_of!(ExpressionStatement, span) == 0);
@@ -2080,9 +2085,6 @@ const _: () = {
     assert!(offset_of!(ImportDeclaration, with_clause) == 52);
     assert!(offset_of!(ImportDeclaration, import_kind) == 56);
 
-    assert!(size_of::<ImportPhase>() == 1);
-    assert!(align_of::<ImportPhase>() == 1);
-
     assert!(size_of::<ImportDeclarationSpecifier>() == 8);
     assert!(align_of::<ImportDeclarationSpecifier>() == 4);
 
@@ -3765,6 +3765,17 @@ impl<'a> AstBuilder<'a> {
         Statement::WithStatement(self.alloc_with_statement(span, object, body))
     }
 
+    /// Build a [`Statement::ErrorStatement`].
+    ///
+    /// This node contains an [`ErrorStatement`] that will be stored in the memory arena.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn statement_error(self, span: Span) -> Statement<'a> {
+        Statement::ErrorStatement(self.alloc_error_statement(span))
+    }
+
     /// Build a [`Directive`].
     ///
     /// If you want the built node to be allocated in the memory arena, use [`AstBuilder::alloc_directive`] instead.
@@ -4514,6 +4525,28 @@ impl<'a> AstBuilder<'a> {
         Box::new_in(self.empty_statement(span), self.allocator)
     }
 
+    /// Build an [`ErrorStatement`].
+    ///
+    /// If you want the built node to be allocated in the memory arena, use [`AstBuilder::alloc_error_statement`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn error_statement(self, span: Span) -> ErrorStatement {
+        ErrorStatement { span }
+    }
+
+    /// Build an [`ErrorStatement`], and store it in the memory arena.
+    ///
+    /// Returns a [`Box`] containing the newly-allocated node. If you want a stack-allocated node, use [`AstBuilder::error_statement`] instead.
+    ///
+    /// ## Parameters
+    /// * `span`: The [`Span`] covering this node
+    #[inline]
+    pub fn alloc_error_statement(self, span: Span) -> Box<'a, ErrorStatement> {
+        Box::new_in(self.error_statement(span), self.allocator)
+    }
+
     /// Build an [`ExpressionStatement`].
     ///
     /// If you want the built node to be allocated in the memory arena, use [`AstBuilder::alloc_expression_statement`] instead.
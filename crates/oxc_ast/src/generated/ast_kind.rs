@@ -57,128 +57,129 @@ pub enum AstType {
     VariableDeclaration = 41,
     VariableDeclarator = 42,
     EmptyStatement = 43,
-    ExpressionStatement = 44,
-    IfStatement = 45,
-    DoWhileStatement = 46,
-    WhileStatement = 47,
-    ForStatement = 48,
-    ForStatementInit = 49,
-    ForInStatement = 50,
-    ForOfStatement = 51,
-    ContinueStatement = 52,
-    BreakStatement = 53,
-    ReturnStatement = 54,
-    WithStatement = 55,
-    SwitchStatement = 56,
-    SwitchCase = 57,
-    LabeledStatement = 58,
-    ThrowStatement = 59,
-    TryStatement = 60,
-    CatchClause = 61,
-    CatchParameter = 62,
-    DebuggerStatement = 63,
-    AssignmentPattern = 64,
-    ObjectPattern = 65,
-    ArrayPattern = 66,
-    BindingRestElement = 67,
-    Function = 68,
-    FormalParameters = 69,
-    FormalParameter = 70,
-    FunctionBody = 71,
-    ArrowFunctionExpression = 72,
-    YieldExpression = 73,
-    Class = 74,
-    ClassBody = 75,
-    MethodDefinition = 76,
-    PropertyDefinition = 77,
-    PrivateIdentifier = 78,
-    StaticBlock = 79,
-    ModuleDeclaration = 80,
-    ImportExpression = 81,
-    ImportDeclaration = 82,
-    ImportSpecifier = 83,
-    ImportDefaultSpecifier = 84,
-    ImportNamespaceSpecifier = 85,
-    ExportNamedDeclaration = 86,
-    ExportDefaultDeclaration = 87,
-    ExportAllDeclaration = 88,
-    ExportSpecifier = 89,
-    V8IntrinsicExpression = 90,
-    BooleanLiteral = 91,
-    NullLiteral = 92,
-    NumericLiteral = 93,
-    StringLiteral = 94,
-    BigIntLiteral = 95,
-    RegExpLiteral = 96,
-    JSXElement = 97,
-    JSXOpeningElement = 98,
-    JSXClosingElement = 99,
-    JSXFragment = 100,
-    JSXElementName = 101,
-    JSXNamespacedName = 102,
-    JSXMemberExpression = 103,
-    JSXMemberExpressionObject = 104,
-    JSXExpressionContainer = 105,
-    JSXAttributeItem = 106,
-    JSXSpreadAttribute = 107,
-    JSXIdentifier = 108,
-    JSXText = 109,
-    TSThisParameter = 110,
-    TSEnumDeclaration = 111,
-    TSEnumMember = 112,
-    TSTypeAnnotation = 113,
-    TSLiteralType = 114,
-    TSConditionalType = 115,
-    TSUnionType = 116,
-    TSIntersectionType = 117,
-    TSParenthesizedType = 118,
-    TSIndexedAccessType = 119,
-    TSNamedTupleMember = 120,
-    TSAnyKeyword = 121,
-    TSStringKeyword = 122,
-    TSBooleanKeyword = 123,
-    TSNumberKeyword = 124,
-    TSNeverKeyword = 125,
-    TSIntrinsicKeyword = 126,
-    TSUnknownKeyword = 127,
-    TSNullKeyword = 128,
-    TSUndefinedKeyword = 129,
-    TSVoidKeyword = 130,
-    TSSymbolKeyword = 131,
-    TSThisType = 132,
-    TSObjectKeyword = 133,
-    TSBigIntKeyword = 134,
-    TSTypeReference = 135,
-    TSTypeName = 136,
-    TSQualifiedName = 137,
-    TSTypeParameterInstantiation = 138,
-    TSTypeParameter = 139,
-    TSTypeParameterDeclaration = 140,
-    TSTypeAliasDeclaration = 141,
-    TSClassImplements = 142,
-    TSInterfaceDeclaration = 143,
-    TSPropertySignature = 144,
-    TSMethodSignature = 145,
-    TSConstructSignatureDeclaration = 146,
-    TSInterfaceHeritage = 147,
-    TSModuleDeclaration = 148,
-    TSModuleBlock = 149,
-    TSTypeLiteral = 150,
-    TSInferType = 151,
-    TSTypeQuery = 152,
-    TSImportType = 153,
-    TSMappedType = 154,
-    TSTemplateLiteralType = 155,
-    TSAsExpression = 156,
-    TSSatisfiesExpression = 157,
-    TSTypeAssertion = 158,
-    TSImportEqualsDeclaration = 159,
-    TSModuleReference = 160,
-    TSExternalModuleReference = 161,
-    TSNonNullExpression = 162,
-    Decorator = 163,
-    TSExportAssignment = 164,
-    TSInstantiationExpression = 165,
+    ErrorStatement = 44,
+    ExpressionStatement = 45,
+    IfStatement = 46,
+    DoWhileStatement = 47,
+    WhileStatement = 48,
+    ForStatement = 49,
+    ForStatementInit = 50,
+    ForInStatement = 51,
+    ForOfStatement = 52,
+    ContinueStatement = 53,
+    BreakStatement = 54,
+    ReturnStatement = 55,
+    WithStatement = 56,
+    SwitchStatement = 57,
+    SwitchCase = 58,
+    LabeledStatement = 59,
+    ThrowStatement = 60,
+    TryStatement = 61,
+    CatchClause = 62,
+    CatchParameter = 63,
+    DebuggerStatement = 64,
+    AssignmentPattern = 65,
+    ObjectPattern = 66,
+    ArrayPattern = 67,
+    BindingRestElement = 68,
+    Function = 69,
+    FormalParameters = 70,
+    FormalParameter = 71,
+    FunctionBody = 72,
+    ArrowFunctionExpression = 73,
+    YieldExpression = 74,
+    Class = 75,
+    ClassBody = 76,
+    MethodDefinition = 77,
+    PropertyDefinition = 78,
+    PrivateIdentifier = 79,
+    StaticBlock = 80,
+    ModuleDeclaration = 81,
+    ImportExpression = 82,
+    ImportDeclaration = 83,
+    ImportSpecifier = 84,
+    ImportDefaultSpecifier = 85,
+    ImportNamespaceSpecifier = 86,
+    ExportNamedDeclaration = 87,
+    ExportDefaultDeclaration = 88,
+    ExportAllDeclaration = 89,
+    ExportSpecifier = 90,
+    V8IntrinsicExpression = 91,
+    BooleanLiteral = 92,
+    NullLiteral = 93,
+    NumericLiteral = 94,
+    StringLiteral = 95,
+    BigIntLiteral = 96,
+    RegExpLiteral = 97,
+    JSXElement = 98,
+    JSXOpeningElement = 99,
+    JSXClosingElement = 100,
+    JSXFragment = 101,
+    JSXElementName = 102,
+    JSXNamespacedName = 103,
+    JSXMemberExpression = 104,
+    JSXMemberExpressionObject = 105,
+    JSXExpressionContainer = 106,
+    JSXAttributeItem = 107,
+    JSXSpreadAttribute = 108,
+    JSXIdentifier = 109,
+    JSXText = 110,
+    TSThisParameter = 111,
+    TSEnumDeclaration = 112,
+    TSEnumMember = 113,
+    TSTypeAnnotation = 114,
+    TSLiteralType = 115,
+    TSConditionalType = 116,
+    TSUnionType = 117,
+    TSIntersectionType = 118,
+    TSParenthesizedType = 119,
+    TSIndexedAccessType = 120,
+    TSNamedTupleMember = 121,
+    TSAnyKeyword = 122,
+    TSStringKeyword = 123,
+    TSBooleanKeyword = 124,
+    TSNumberKeyword = 125,
+    TSNeverKeyword = 126,
+    TSIntrinsicKeyword = 127,
+    TSUnknownKeyword = 128,
+    TSNullKeyword = 129,
+    TSUndefinedKeyword = 130,
+    TSVoidKeyword = 131,
+    TSSymbolKeyword = 132,
+    TSThisType = 133,
+    TSObjectKeyword = 134,
+    TSBigIntKeyword = 135,
+    TSTypeReference = 136,
+    TSTypeName = 137,
+    TSQualifiedName = 138,
+    TSTypeParameterInstantiation = 139,
+    TSTypeParameter = 140,
+    TSTypeParameterDeclaration = 141,
+    TSTypeAliasDeclaration = 142,
+    TSClassImplements = 143,
+    TSInterfaceDeclaration = 144,
+    TSPropertySignature = 145,
+    TSMethodSignature = 146,
+    TSConstructSignatureDeclaration = 147,
+    TSInterfaceHeritage = 148,
+    TSModuleDeclaration = 149,
+    TSModuleBlock = 150,
+    TSTypeLiteral = 151,
+    TSInferType = 152,
+    TSTypeQuery = 153,
+    TSImportType = 154,
+    TSMappedType = 155,
+    TSTemplateLiteralType = 156,
+    TSAsExpression = 157,
+    TSSatisfiesExpression = 158,
+    TSTypeAssertion = 159,
+    TSImportEqualsDeclaration = 160,
+    TSModuleReference = 161,
+    TSExternalModuleReference = 162,
+    TSNonNullExpression = 163,
+    Decorator = 164,
+    TSExportAssignment = 165,
+    TSInstantiationExpression = 166,
 }
 
 /// Untyped AST Node Kind
@@ -233,6 +234,7 @@ pub enum AstKind<'a> {
     VariableDeclaration(&'a VariableDeclaration<'a>) = AstType::VariableDeclaration as u8,
     VariableDeclarator(&'a VariableDeclarator<'a>) = AstType::VariableDeclarator as u8,
     EmptyStatement(&'a EmptyStatement) = AstType::EmptyStatement as u8,
+    ErrorStatement(&'a ErrorStatement) = AstType::ErrorStatement as u8,
     ExpressionStatement(&'a ExpressionStatement<'a>) = AstType::ExpressionStatement as u8,
     IfStatement(&'a IfStatement<'a>) = AstType::IfStatement as u8,
     DoWhileStatement(&'a DoWhileStatement<'a>) = AstType::DoWhileStatement as u8,
@@ -426,6 +428,7 @@ impl GetSpan for AstKind<'_> {
             Self::VariableDeclaration(it) => it.span(),
             Self::VariableDeclarator(it) => it.span(),
             Self::EmptyStatement(it) => it.span(),
+            Self::ErrorStatement(it) => it.span(),
             Self::ExpressionStatement(it) => it.span(),
             Self::IfStatement(it) => it.span(),
             Self::DoWhileStatement(it) => it.span(),
@@ -773,6 +776,11 @@ impl<'a> AstKind<'a> {
         if let Self::EmptyStatement(v) = self { Some(v) } else { None }
     }
 
+    #[inline]
+    pub fn as_error_statement(self) -> Option<&'a ErrorStatement> {
+        if let Self::ErrorStatement(v) = self { Some(v) } else { None }
+    }
+
     #[inline]
     pub fn as_expression_statement(self) -> Option<&'a ExpressionStatement<'a>> {
         if let Self::ExpressionStatement(v) = self { Some(v) } else { None }
@@ -1187,6 +1187,7 @@ impl<'new_alloc> CloneIn<'new_alloc> for Statement<'_> {
             Self::TryStatement(it) => Statement::TryStatement(CloneIn::clone_in(it, allocator)),
             Self::WhileStatement(it) => Statement::WhileStatement(CloneIn::clone_in(it, allocator)),
             Self::WithStatement(it) => Statement::WithStatement(CloneIn::clone_in(it, allocator)),
+            Self::ErrorStatement(it) => Statement::ErrorStatement(CloneIn::clone_in(it, allocator)),
             Self::VariableDeclaration(it) => {
                 Statement::VariableDeclaration(CloneIn::clone_in(it, allocator))
             }
@@ -1342,6 +1343,13 @@ impl<'alloc> CloneIn<'alloc> for EmptyStatement {
     }
 }
 
+impl<'alloc> CloneIn<'alloc> for ErrorStatement {
+    type Cloned = ErrorStatement;
+    fn clone_in(&self, allocator: &'alloc Allocator) -> Self::Cloned {
+        ErrorStatement { span: CloneIn::clone_in(&self.span, allocator) }
+    }
+}
+
 impl<'new_alloc> CloneIn<'new_alloc> for ExpressionStatement<'_> {
     type Cloned = ExpressionStatement<'new_alloc>;
     fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
@@ -2162,16 +2170,6 @@ impl<'new_alloc> CloneIn<'new_alloc> for ImportDeclaration<'_> {
     }
 }
 
-impl<'alloc> CloneIn<'alloc> for ImportPhase {
-    type Cloned = ImportPhase;
-    fn clone_in(&self, _: &'alloc Allocator) -> Self::Cloned {
-        match self {
-            Self::Source => ImportPhase::Source,
-            Self::Defer => ImportPhase::Defer,
-        }
-    }
-}
-
 impl<'new_alloc> CloneIn<'new_alloc> for ImportDeclarationSpecifier<'_> {
     type Cloned = ImportDeclarationSpecifier<'new_alloc>;
     fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
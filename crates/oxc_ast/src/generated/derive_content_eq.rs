@@ -683,6 +683,7 @@ impl ContentEq for Statement<'_> {
             (Self::TryStatement(a), Self::TryStatement(b)) => a.content_eq(b),
             (Self::WhileStatement(a), Self::WhileStatement(b)) => a.content_eq(b),
             (Self::WithStatement(a), Self::WithStatement(b)) => a.content_eq(b),
+            (Self::ErrorStatement(a), Self::ErrorStatement(b)) => a.content_eq(b),
             (Self::VariableDeclaration(a), Self::VariableDeclaration(b)) => a.content_eq(b),
             (Self::FunctionDeclaration(a), Self::FunctionDeclaration(b)) => a.content_eq(b),
             (Self::ClassDeclaration(a), Self::ClassDeclaration(b)) => a.content_eq(b),
@@ -774,6 +775,12 @@ impl ContentEq for EmptyStatement {
     }
 }
 
+impl ContentEq for ErrorStatement {
+    fn content_eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
 impl ContentEq for ExpressionStatement<'_> {
     fn content_eq(&self, other: &Self) -> bool {
         ContentEq::content_eq(&self.expression, &other.expression)
@@ -1275,12 +1282,6 @@ impl ContentEq for ImportDeclaration<'_> {
     }
 }
 
-impl ContentEq for ImportPhase {
-    fn content_eq(&self, other: &Self) -> bool {
-        self == other
-    }
-}
-
 impl ContentEq for ImportDeclarationSpecifier<'_> {
     fn content_eq(&self, other: &Self) -> bool {
         match (self, other) {
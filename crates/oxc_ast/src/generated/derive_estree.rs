@@ -829,6 +829,7 @@ impl ESTree for Statement<'_> {
             Self::TryStatement(it) => it.serialize(serializer),
             Self::WhileStatement(it) => it.serialize(serializer),
             Self::WithStatement(it) => it.serialize(serializer),
+            Self::ErrorStatement(it) => it.serialize(serializer),
             Self::VariableDeclaration(it) => it.serialize(serializer),
             Self::FunctionDeclaration(it) => it.serialize(serializer),
             Self::ClassDeclaration(it) => it.serialize(serializer),
@@ -944,6 +945,16 @@ impl ESTree for EmptyStatement {
     }
 }
 
+impl ESTree for ErrorStatement {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &JsonSafeString("ErrorStatement"));
+        state.serialize_field("start", &self.span.start);
+        state.serialize_field("end", &self.span.end);
+        state.end();
+    }
+}
+
 impl ESTree for ExpressionStatement<'_> {
     fn serialize<S: Serializer>(&self, serializer: S) {
         let mut state = serializer.serialize_struct();
@@ -1655,15 +1666,6 @@ impl ESTree for ImportDeclaration<'_> {
     }
 }
 
-impl ESTree for ImportPhase {
-    fn serialize<S: Serializer>(&self, serializer: S) {
-        match self {
-            Self::Source => JsonSafeString("source").serialize(serializer),
-            Self::Defer => JsonSafeString("defer").serialize(serializer),
-        }
-    }
-}
-
 impl ESTree for ImportDeclarationSpecifier<'_> {
     fn serialize<S: Serializer>(&self, serializer: S) {
         match self {
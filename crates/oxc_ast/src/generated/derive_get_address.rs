@@ -306,6 +306,7 @@ impl GetAddress for Statement<'_> {
             Self::TryStatement(it) => GetAddress::address(it),
             Self::WhileStatement(it) => GetAddress::address(it),
             Self::WithStatement(it) => GetAddress::address(it),
+            Self::ErrorStatement(it) => GetAddress::address(it),
             Self::VariableDeclaration(it) => GetAddress::address(it),
             Self::FunctionDeclaration(it) => GetAddress::address(it),
             Self::ClassDeclaration(it) => GetAddress::address(it),
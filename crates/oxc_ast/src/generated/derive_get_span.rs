@@ -601,6 +601,7 @@ impl GetSpan for Statement<'_> {
             Self::TryStatement(it) => GetSpan::span(&**it),
             Self::WhileStatement(it) => GetSpan::span(&**it),
             Self::WithStatement(it) => GetSpan::span(&**it),
+            Self::ErrorStatement(it) => GetSpan::span(&**it),
             Self::VariableDeclaration(it) => GetSpan::span(&**it),
             Self::FunctionDeclaration(it) => GetSpan::span(&**it),
             Self::ClassDeclaration(it) => GetSpan::span(&**it),
@@ -676,6 +677,13 @@ impl GetSpan for EmptyStatement {
     }
 }
 
+impl GetSpan for ErrorStatement {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 impl GetSpan for ExpressionStatement<'_> {
     #[inline]
     fn span(&self) -> Span {
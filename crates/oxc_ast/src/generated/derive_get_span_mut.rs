@@ -601,6 +601,7 @@ impl GetSpanMut for Statement<'_> {
             Self::TryStatement(it) => GetSpanMut::span_mut(&mut **it),
             Self::WhileStatement(it) => GetSpanMut::span_mut(&mut **it),
             Self::WithStatement(it) => GetSpanMut::span_mut(&mut **it),
+            Self::ErrorStatement(it) => GetSpanMut::span_mut(&mut **it),
             Self::VariableDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::FunctionDeclaration(it) => GetSpanMut::span_mut(&mut **it),
             Self::ClassDeclaration(it) => GetSpanMut::span_mut(&mut **it),
@@ -676,6 +677,13 @@ impl GetSpanMut for EmptyStatement {
     }
 }
 
+impl GetSpanMut for ErrorStatement {
+    #[inline]
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
 impl GetSpanMut for ExpressionStatement<'_> {
     #[inline]
     fn span_mut(&mut self) -> &mut Span {
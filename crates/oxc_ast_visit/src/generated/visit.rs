@@ -335,6 +335,11 @@ pub trait Visit<'a>: Sized {
         walk_empty_statement(self, it);
     }
 
+    #[inline]
+    fn visit_error_statement(&mut self, it: &ErrorStatement) {
+        walk_error_statement(self, it);
+    }
+
     #[inline]
     fn visit_expression_statement(&mut self, it: &ExpressionStatement<'a>) {
         walk_expression_statement(self, it);
@@ -2028,6 +2033,7 @@ pub mod walk {
             Statement::TryStatement(it) => visitor.visit_try_statement(it),
             Statement::WhileStatement(it) => visitor.visit_while_statement(it),
             Statement::WithStatement(it) => visitor.visit_with_statement(it),
+            Statement::ErrorStatement(it) => visitor.visit_error_statement(it),
             match_declaration!(Statement) => visitor.visit_declaration(it.to_declaration()),
             match_module_declaration!(Statement) => {
                 visitor.visit_module_declaration(it.to_module_declaration())
@@ -2117,6 +2123,14 @@ pub mod walk {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_error_statement<'a, V: Visit<'a>>(visitor: &mut V, it: &ErrorStatement) {
+        let kind = AstKind::ErrorStatement(visitor.alloc(it));
+        visitor.enter_node(kind);
+        visitor.visit_span(&it.span);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_expression_statement<'a, V: Visit<'a>>(
         visitor: &mut V,
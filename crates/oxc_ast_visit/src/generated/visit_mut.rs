@@ -327,6 +327,11 @@ pub trait VisitMut<'a>: Sized {
         walk_empty_statement(self, it);
     }
 
+    #[inline]
+    fn visit_error_statement(&mut self, it: &mut ErrorStatement) {
+        walk_error_statement(self, it);
+    }
+
     #[inline]
     fn visit_expression_statement(&mut self, it: &mut ExpressionStatement<'a>) {
         walk_expression_statement(self, it);
@@ -2065,6 +2070,7 @@ pub mod walk_mut {
             Statement::TryStatement(it) => visitor.visit_try_statement(it),
             Statement::WhileStatement(it) => visitor.visit_while_statement(it),
             Statement::WithStatement(it) => visitor.visit_with_statement(it),
+            Statement::ErrorStatement(it) => visitor.visit_error_statement(it),
             match_declaration!(Statement) => visitor.visit_declaration(it.to_declaration_mut()),
             match_module_declaration!(Statement) => {
                 visitor.visit_module_declaration(it.to_module_declaration_mut())
@@ -2154,6 +2160,14 @@ pub mod walk_mut {
         visitor.leave_node(kind);
     }
 
+    #[inline]
+    pub fn walk_error_statement<'a, V: VisitMut<'a>>(visitor: &mut V, it: &mut ErrorStatement) {
+        let kind = AstType::ErrorStatement;
+        visitor.enter_node(kind);
+        visitor.visit_span(&mut it.span);
+        visitor.leave_node(kind);
+    }
+
     #[inline]
     pub fn walk_expression_statement<'a, V: VisitMut<'a>>(
         visitor: &mut V,
@@ -4,6 +4,7 @@
 use std::ops::Not;
 
 use oxc_ast::ast::{BinaryExpression, Expression, LogicalExpression};
+use oxc_span::GetSpan;
 use oxc_syntax::{
     operator::{BinaryOperator, LogicalOperator},
     precedence::{GetPrecedence, Precedence},
@@ -117,6 +118,8 @@ impl<'a> BinaryExpressionVisitor<'a> {
 
             let left = v.e.left();
             let left_binary = match left {
+                // Unchanged operands are copied from the source text by `gen_expr`.
+                _ if p.is_unchanged_for_reprint(left.span()) => None,
                 Expression::BinaryExpression(e) => Some(Binaryish::Binary(e)),
                 Expression::LogicalExpression(e) => Some(Binaryish::Logical(e)),
                 _ => None,
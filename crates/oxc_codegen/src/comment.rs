@@ -4,7 +4,7 @@ use rustc_hash::FxHashMap;
 use oxc_ast::{Comment, CommentKind, ast::Argument};
 use oxc_syntax::identifier::is_line_terminator;
 
-use crate::{Codegen, LegalComment};
+use crate::{Codegen, IndentChar, LegalComment};
 
 pub type CommentsMap = FxHashMap</* attached_to */ u32, Vec<Comment>>;
 
@@ -124,6 +124,9 @@ impl Codegen<'_> {
                         match b {
                             b'\n' => self.print_indent(),
                             b'\t' => { /* noop */ }
+                            b' ' if self.options.indent_char == IndentChar::Space
+                                && self.is_after_indent() =>
+                            { /* noop */ }
                             _ => {
                                 self.print_hard_newline();
                                 self.print_indent();
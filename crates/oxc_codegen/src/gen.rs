@@ -45,6 +45,9 @@ impl Gen for Program<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.is_jsx = self.source_type.is_jsx();
 
+        if p.reprint.is_some() && p.reprint_program(self) {
+            return;
+        }
         if let Some(hashbang) = &self.hashbang {
             hashbang.print(p, ctx);
         }
@@ -56,6 +59,7 @@ impl Gen for Program<'_> {
             p.print_semicolon_if_needed();
         }
         // Print trailing statement comments.
+        p.print_reprint_leading_comments(self.span.end);
         p.print_statement_comments(self.span.end);
     }
 }
@@ -96,7 +100,7 @@ impl Gen for Directive<'_> {
             }
         }
         p.print_ascii_byte(quote);
-        p.print_str(directive);
+        p.print_raw_string(directive);
         p.print_ascii_byte(quote);
         p.print_ascii_byte(b';');
         p.print_soft_newline();
@@ -105,6 +109,10 @@ impl Gen for Directive<'_> {
 
 impl Gen for Statement<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
+        p.print_newline_if_past_line_limit();
+        if p.reprint.is_some() && p.reprint_statement(self) {
+            return;
+        }
         match self {
             Self::BlockStatement(stmt) => {
                 p.print_statement_comments(stmt.span.start);
@@ -178,6 +186,10 @@ impl Gen for Statement<'_> {
                 p.print_statement_comments(stmt.span.start);
                 stmt.print(p, ctx);
             }
+            Self::ErrorStatement(stmt) => {
+                p.print_statement_comments(stmt.span.start);
+                stmt.print(p, ctx);
+            }
             Self::ImportDeclaration(decl) => {
                 p.print_statement_comments(decl.span.start);
                 decl.print(p, ctx);
@@ -265,6 +277,9 @@ impl Gen for Statement<'_> {
                 p.print_semicolon_after_statement();
             }
         }
+        if p.reprint.is_some() {
+            p.print_reprint_statement_end(self.span());
+        }
     }
 }
 
@@ -528,6 +543,19 @@ impl Gen for EmptyStatement {
     }
 }
 
+impl Gen for ErrorStatement {
+    fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        let text = self.span.source_text(p.source_text).trim_end();
+        if text.is_empty() {
+            return;
+        }
+        p.add_source_mapping(self.span);
+        p.print_indent();
+        p.print_str(text);
+        p.print_soft_newline();
+    }
+}
+
 impl Gen for ContinueStatement<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.add_source_mapping(self.span);
@@ -990,7 +1018,7 @@ impl Gen for ImportAttribute<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         match &self.key {
             ImportAttributeKey::Identifier(identifier) => {
-                p.print_str(identifier.name.as_str());
+                p.print_identifier(identifier.name.as_str());
             }
             ImportAttributeKey::StringLiteral(literal) => {
                 p.print_string_literal(literal, false);
@@ -1172,6 +1200,9 @@ impl Gen for ExportDefaultDeclarationKind<'_> {
 
 impl GenExpr for Expression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        if p.reprint.is_some() && p.reprint_expression(self, precedence, ctx) {
+            return;
+        }
         match self {
             Self::BooleanLiteral(lit) => lit.print(p, ctx),
             Self::NullLiteral(lit) => lit.print(p, ctx),
@@ -1241,7 +1272,7 @@ impl Gen for IdentifierReference<'_> {
         let name = p.get_identifier_reference_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_identifier(name);
     }
 }
 
@@ -1249,7 +1280,7 @@ impl Gen for IdentifierName<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping(self.span);
-        p.print_str(self.name.as_str());
+        p.print_identifier(self.name.as_str());
     }
 }
 
@@ -1258,7 +1289,7 @@ impl Gen for BindingIdentifier<'_> {
         let name = p.get_binding_identifier_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_identifier(name);
     }
 }
 
@@ -1266,7 +1297,7 @@ impl Gen for LabelIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, &self.name);
-        p.print_str(self.name.as_str());
+        p.print_identifier(self.name.as_str());
     }
 }
 
@@ -1355,7 +1386,8 @@ impl Gen for RegExpLiteral<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'/');
-        p.print_str(pattern_text.as_ref());
+        let unicode = self.regex.flags.intersects(RegExpFlags::U | RegExpFlags::V);
+        p.print_regex_pattern(pattern_text.as_ref(), unicode);
         p.print_ascii_byte(b'/');
         p.print_str(self.regex.flags.to_inline_string().as_str());
         p.prev_reg_exp_end = p.code().len();
@@ -1513,12 +1545,15 @@ impl Gen for ArrayExpression<'_> {
         for (i, item) in self.elements.iter().enumerate() {
             if i != 0 {
                 p.print_comma();
+                if is_multi_line {
+                    p.print_newline_if_past_line_limit();
+                } else {
+                    p.print_list_separator();
+                }
             }
             if is_multi_line {
                 p.print_soft_newline();
                 p.print_indent();
-            } else if i != 0 {
-                p.print_soft_space();
             }
             item.print(p, ctx);
             if i == self.elements.len() - 1 && matches!(item, ArrayExpressionElement::Elision(_)) {
@@ -1550,6 +1585,7 @@ impl GenExpr for ObjectExpression<'_> {
             for (i, item) in self.properties.iter().enumerate() {
                 if i != 0 {
                     p.print_comma();
+                    p.print_newline_if_past_line_limit();
                 }
                 if is_multi_line {
                     p.print_soft_newline();
@@ -2007,10 +2043,10 @@ impl Gen for AssignmentTargetPropertyIdentifier<'_> {
             self.binding.print(p, ctx);
         } else {
             // `({x: a} = y);`
-            p.print_str(self.binding.name.as_str());
+            p.print_identifier(self.binding.name.as_str());
             p.print_colon();
             p.print_soft_space();
-            p.print_str(ident_name);
+            p.print_identifier(ident_name);
         }
         if let Some(expr) = &self.init {
             p.print_soft_space();
@@ -2134,22 +2170,31 @@ impl GenExpr for ImportExpression<'_> {
 
 impl Gen for TemplateLiteral<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.print_ascii_byte(b'`');
-        let mut expressions = self.expressions.iter();
+        print_template_literal(self, p, false);
+    }
+}
 
-        for quasi in &self.quasis {
-            p.add_source_mapping(quasi.span);
-            p.print_str(quasi.value.raw.as_str());
+/// The raw strings of tagged templates are observable, so they are never escaped.
+fn print_template_literal(template: &TemplateLiteral<'_>, p: &mut Codegen, is_tagged: bool) {
+    p.print_ascii_byte(b'`');
+    let mut expressions = template.expressions.iter();
 
-            if let Some(expr) = expressions.next() {
-                p.print_str("${");
-                p.print_expression(expr);
-                p.print_ascii_byte(b'}');
-            }
+    for quasi in &template.quasis {
+        p.add_source_mapping(quasi.span);
+        if is_tagged {
+            p.print_str(quasi.value.raw.as_str());
+        } else {
+            p.print_raw_string(quasi.value.raw.as_str());
         }
 
-        p.print_ascii_byte(b'`');
+        if let Some(expr) = expressions.next() {
+            p.print_str("${");
+            p.print_expression(expr);
+            p.print_ascii_byte(b'}');
+        }
     }
+
+    p.print_ascii_byte(b'`');
 }
 
 impl Gen for TaggedTemplateExpression<'_> {
@@ -2159,7 +2204,7 @@ impl Gen for TaggedTemplateExpression<'_> {
         if let Some(type_parameters) = &self.type_arguments {
             type_parameters.print(p, ctx);
         }
-        self.quasi.print(p, ctx);
+        print_template_literal(&self.quasi, p, true);
     }
 }
 
@@ -2817,7 +2862,7 @@ impl Gen for PrivateIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping_for_name(self.span, &self.name);
         p.print_ascii_byte(b'#');
-        p.print_str(self.name.as_str());
+        p.print_identifier(self.name.as_str());
     }
 }
 
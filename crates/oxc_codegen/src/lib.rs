@@ -10,9 +10,10 @@ mod context;
 mod r#gen;
 mod operator;
 mod options;
+mod reprint;
 mod sourcemap_builder;
 
-use std::borrow::Cow;
+use std::{borrow::Cow, iter};
 
 use oxc_ast::ast::{
     Argument, BindingIdentifier, BlockStatement, Comment, Expression, IdentifierReference, Program,
@@ -29,12 +30,12 @@ use oxc_syntax::{
 
 use crate::{
     binary_expr_visitor::BinaryExpressionVisitor, comment::CommentsMap, operator::Operator,
-    sourcemap_builder::SourcemapBuilder,
+    reprint::Reprint, sourcemap_builder::SourcemapBuilder,
 };
 pub use crate::{
     context::Context,
     r#gen::{Gen, GenExpr},
-    options::{CodegenOptions, LegalComment},
+    options::{CodegenOptions, IndentChar, LegalComment},
 };
 
 /// Code generator without whitespace removal.
@@ -87,6 +88,8 @@ pub struct Codegen<'a> {
     // states
     prev_op_end: usize,
     prev_reg_exp_end: usize,
+    /// End of the last identifier ending with a `\u{...}` escape, see [CodegenOptions::ascii_only].
+    prev_escaped_identifier_end: usize,
     need_space_before_dot: usize,
     print_next_indent_as_space: bool,
     binary_expr_stack: Stack<BinaryExpressionVisitor<'a>>,
@@ -110,6 +113,12 @@ pub struct Codegen<'a> {
     quote: u8,
     /// Fast path for if print comments
     print_comments: bool,
+    /// Fast path for [CodegenOptions::max_line_len], `None` if not minifying.
+    max_line_len: Option<usize>,
+    /// Start of the current output line, see [Codegen::print_newline_if_past_line_limit].
+    line_start: usize,
+    /// Output up to this position has been searched for line breaks.
+    line_scan_end: usize,
 
     // Builders
     comments: CommentsMap,
@@ -117,6 +126,9 @@ pub struct Codegen<'a> {
     legal_comments: Vec<Comment>,
 
     sourcemap_builder: Option<SourcemapBuilder>,
+
+    /// Unchanged nodes and comments for [CodegenOptions::reprint].
+    reprint: Option<Reprint>,
 }
 
 impl Default for Codegen<'_> {
@@ -157,6 +169,7 @@ impl<'a> Codegen<'a> {
             binary_expr_stack: Stack::with_capacity(12),
             prev_op_end: 0,
             prev_reg_exp_end: 0,
+            prev_escaped_identifier_end: 0,
             prev_op: None,
             start_of_stmt: 0,
             start_of_arrow_expr: 0,
@@ -165,9 +178,13 @@ impl<'a> Codegen<'a> {
             indent: 0,
             quote: b'"',
             print_comments,
+            max_line_len: None,
+            line_start: 0,
+            line_scan_end: 0,
             comments: CommentsMap::default(),
             legal_comments: vec![],
             sourcemap_builder: None,
+            reprint: None,
         }
     }
 
@@ -176,6 +193,7 @@ impl<'a> Codegen<'a> {
     pub fn with_options(mut self, options: CodegenOptions) -> Self {
         self.quote = if options.single_quote { b'\'' } else { b'"' };
         self.print_comments = options.print_comments();
+        self.max_line_len = options.max_line_len();
         self.options = options;
         self
     }
@@ -207,10 +225,26 @@ impl<'a> Codegen<'a> {
         if let Some(path) = &self.options.source_map_path {
             self.sourcemap_builder = Some(SourcemapBuilder::new(path, program.source_text));
         }
+        if self.options.reprint() {
+            if let Some((indent_char, indent_width)) = reprint::detect_indentation(program) {
+                self.options.indent_char = indent_char;
+                self.options.indent_width = indent_width;
+            }
+            self.reprint = Some(Reprint::new(program, self.options.comments));
+            // Trailing comments are not attached to a node, they are copied after their statement.
+            self.comments.retain(|_, comments| {
+                comments.retain(|comment| comment.is_leading());
+                !comments.is_empty()
+            });
+        }
         program.print(&mut self, Context::default());
         self.try_print_eof_legal_comments();
+        let debug_id = (self.options.source_map_debug_id && self.sourcemap_builder.is_some())
+            .then(|| self.print_debug_id_comment());
         let code = self.code.into_string();
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let map = self
+            .sourcemap_builder
+            .map(|builder| builder.into_sourcemap_with_options(&self.options, debug_id.as_deref()));
         CodegenReturn { code, map, legal_comments: self.legal_comments }
     }
 
@@ -260,6 +294,18 @@ impl<'a> Codegen<'a> {
         self.code().len()
     }
 
+    /// Print the `//# debugId=` comment for [CodegenOptions::source_map_debug_id].
+    fn print_debug_id_comment(&mut self) -> String {
+        let debug_id = sourcemap_builder::debug_id(self.code.as_bytes());
+        if !matches!(self.last_byte(), None | Some(b'\n')) {
+            self.print_hard_newline();
+        }
+        self.print_str("//# debugId=");
+        self.print_str(&debug_id);
+        self.print_hard_newline();
+        debug_id
+    }
+
     #[inline]
     fn print_soft_space(&mut self) {
         if !self.options.minify {
@@ -284,6 +330,58 @@ impl<'a> Codegen<'a> {
         self.print_ascii_byte(b'\n');
     }
 
+    /// Print a line break if the current line has reached [CodegenOptions::max_line_len].
+    ///
+    /// Must only be called where a line break cannot change the meaning of the program.
+    #[inline]
+    fn print_newline_if_past_line_limit(&mut self) {
+        let Some(max_line_len) = self.max_line_len else { return };
+        if self.is_past_line_limit(max_line_len) {
+            self.print_line_break();
+        }
+    }
+
+    /// Print the space after a comma in a list, or a line break followed by one more level of
+    /// indentation if the current line has reached [CodegenOptions::print_width].
+    ///
+    /// Must only be called where a line break cannot change the meaning of the program.
+    fn print_list_separator(&mut self) {
+        if let Some(print_width) = self.options.print_width() {
+            if self.is_past_line_limit(print_width) {
+                self.print_line_break();
+                self.print_indent_level(self.indent + 1);
+                return;
+            }
+        }
+        self.print_soft_space();
+        self.print_newline_if_past_line_limit();
+    }
+
+    #[inline]
+    fn is_past_line_limit(&mut self, limit: usize) -> bool {
+        if self.code.len() - self.line_start < limit {
+            return false;
+        }
+        self.update_line_start();
+        self.code.len() - self.line_start >= limit
+    }
+
+    fn print_line_break(&mut self) {
+        self.print_hard_newline();
+        self.line_start = self.code.len();
+        self.line_scan_end = self.line_start;
+    }
+
+    /// Strings, template literals and comments may contain line breaks,
+    /// so search the output printed since the last check for the start of the current line.
+    fn update_line_start(&mut self) {
+        let bytes = &self.code.as_bytes()[self.line_scan_end..];
+        if let Some(index) = bytes.iter().rposition(|&b| b == b'\n') {
+            self.line_start = self.line_scan_end + index + 1;
+        }
+        self.line_scan_end = self.code.len();
+    }
+
     #[inline]
     fn print_semicolon(&mut self) {
         self.print_ascii_byte(b';');
@@ -298,7 +396,9 @@ impl<'a> Codegen<'a> {
     fn print_space_before_identifier(&mut self) {
         let Some(byte) = self.last_byte() else { return };
 
-        if self.prev_reg_exp_end != self.code.len() {
+        if self.prev_reg_exp_end != self.code.len()
+            && self.prev_escaped_identifier_end != self.code.len()
+        {
             let is_identifier = if byte.is_ascii() {
                 // Fast path for ASCII (very common case)
                 is_identifier_part_ascii(byte as char)
@@ -358,7 +458,29 @@ impl<'a> Codegen<'a> {
             self.print_next_indent_as_space = false;
             return;
         }
-        self.code.print_indent(self.indent as usize);
+        if let Some(indent) = self.reprint.as_mut().and_then(Reprint::take_indent) {
+            let source_text = self.source_text;
+            self.print_str(indent.source_text(source_text));
+            return;
+        }
+        self.print_indent_level(self.indent);
+    }
+
+    #[inline]
+    fn print_indent_level(&mut self, level: u32) {
+        let n = level as usize * self.options.indent_width;
+        match self.options.indent_char {
+            IndentChar::Tab => self.code.print_indent(n),
+            IndentChar::Space => self.code.print_ascii_bytes(iter::repeat_n(b' ', n)),
+        }
+    }
+
+    /// Whether the current output line only contains indentation.
+    fn is_after_indent(&self) -> bool {
+        let indent_char = self.options.indent_char.as_byte();
+        let bytes = self.code.as_bytes();
+        bytes.last() == Some(&indent_char)
+            && bytes.iter().rev().take_while(|&&b| b != b'\n').all(|&b| b == indent_char)
     }
 
     #[inline]
@@ -471,7 +593,7 @@ impl<'a> Codegen<'a> {
         for (index, item) in items.iter().enumerate() {
             if index != 0 {
                 self.print_comma();
-                self.print_soft_space();
+                self.print_list_separator();
             }
             item.print(self, ctx);
         }
@@ -496,7 +618,7 @@ impl<'a> Codegen<'a> {
         for (index, item) in items.iter().enumerate() {
             if index != 0 {
                 self.print_comma();
-                self.print_soft_space();
+                self.print_list_separator();
             }
             item.print_expr(self, precedence, ctx);
         }
@@ -677,11 +799,107 @@ impl<'a> Codegen<'a> {
                     }
                     self.print_ascii_byte(b'$');
                 }
+                _ if self.options.ascii_only && !c.is_ascii() => {
+                    self.print_unicode_escape(c, false);
+                }
                 _ => self.print_str(c.encode_utf8([0; 4].as_mut())),
             }
         }
     }
 
+    /// Print an identifier name, escaping non-ASCII characters if [CodegenOptions::ascii_only] is set.
+    fn print_identifier(&mut self, name: &str) {
+        if !self.options.ascii_only || name.is_ascii() {
+            self.print_str(name);
+            return;
+        }
+        for c in name.chars() {
+            if c.is_ascii() {
+                self.print_ascii_byte(c as u8);
+            } else {
+                // Surrogate pairs are not allowed in identifiers.
+                self.print_unicode_escape(c, true);
+            }
+        }
+        if name.chars().next_back().is_some_and(|c| !c.is_ascii() && c > '\u{FFFF}') {
+            self.prev_escaped_identifier_end = self.code.len();
+        }
+    }
+
+    /// Print the raw text of a template literal or directive,
+    /// escaping non-ASCII characters if [CodegenOptions::ascii_only] is set.
+    fn print_raw_string(&mut self, raw: &str) {
+        if !self.options.ascii_only || raw.is_ascii() {
+            self.print_str(raw);
+            return;
+        }
+        self.print_source_text_ascii_only(raw, true);
+    }
+
+    /// Print the pattern of a regular expression,
+    /// escaping non-ASCII characters if [CodegenOptions::ascii_only] is set.
+    fn print_regex_pattern(&mut self, pattern: &str, unicode: bool) {
+        if !self.options.ascii_only || pattern.is_ascii() {
+            self.print_str(pattern);
+            return;
+        }
+        // Without the `u` or `v` flag, a surrogate pair matches the same code units.
+        self.print_source_text_ascii_only(pattern, unicode);
+    }
+
+    /// Print source text with all non-ASCII characters escaped.
+    ///
+    /// A `\` before a non-ASCII character is removed, because `\é` is the character itself,
+    /// and a line continuation `\<LS>` is removed entirely.
+    fn print_source_text_ascii_only(&mut self, text: &str, braces: bool) {
+        let mut escaped = false;
+        for c in text.chars() {
+            if c.is_ascii() {
+                if escaped {
+                    self.print_ascii_byte(b'\\');
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                    continue;
+                }
+                self.print_ascii_byte(c as u8);
+                continue;
+            }
+            let is_line_continuation = escaped && matches!(c, LS | PS);
+            escaped = false;
+            if !is_line_continuation {
+                self.print_unicode_escape(c, braces);
+            }
+        }
+        if escaped {
+            self.print_ascii_byte(b'\\');
+        }
+    }
+
+    /// Print `c` as `\uXXXX`. Characters outside the Basic Multilingual Plane are printed
+    /// as `\u{XXXXX}` if `braces` is true, or as a surrogate pair otherwise.
+    fn print_unicode_escape(&mut self, c: char, braces: bool) {
+        let code_point = c as u32;
+        if code_point > 0xFFFF && braces {
+            self.print_str("\\u{");
+            self.print_hex(code_point, 1);
+            self.print_ascii_byte(b'}');
+            return;
+        }
+        for unit in c.encode_utf16(&mut [0; 2]) {
+            self.print_str("\\u");
+            self.print_hex(u32::from(*unit), 4);
+        }
+    }
+
+    fn print_hex(&mut self, value: u32, min_digits: u32) {
+        const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+        let digits = (32 - value.leading_zeros()).div_ceil(4).max(min_digits);
+        for i in (0..digits).rev() {
+            self.print_ascii_byte(HEX_DIGITS[((value >> (i * 4)) & 0xF) as usize]);
+        }
+    }
+
     // `get_minified_number` from terser
     // https://github.com/terser/terser/blob/c5315c3fd6321d6b2e076af35a70ef532f498505/lib/output.js#L2418
     #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
@@ -35,6 +35,25 @@ impl LegalComment {
     }
 }
 
+/// Indentation character, see [CodegenOptions::indent_char].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum IndentChar {
+    /// Indent with tabs (default).
+    #[default]
+    Tab,
+    /// Indent with spaces.
+    Space,
+}
+
+impl IndentChar {
+    pub(crate) fn as_byte(self) -> u8 {
+        match self {
+            Self::Tab => b'\t',
+            Self::Space => b' ',
+        }
+    }
+}
+
 /// Codegen Options.
 #[derive(Debug, Clone)]
 pub struct CodegenOptions {
@@ -75,6 +94,89 @@ pub struct CodegenOptions {
     /// By default, the source map path is the same as the input source code
     /// (with a `.map` extension).
     pub source_map_path: Option<PathBuf>,
+
+    /// Include the original source code in `sourcesContent` of the source map.
+    ///
+    /// Default is `true`.
+    pub source_map_sources_content: bool,
+
+    /// Set `sourceRoot` of the source map.
+    ///
+    /// Default is `None`.
+    pub source_map_source_root: Option<String>,
+
+    /// Add a `debugId` to the source map and a `//# debugId=` comment to the end of the generated code,
+    /// see the [Debug ID proposal](https://github.com/tc39/source-map/blob/main/proposals/debug-id.md).
+    ///
+    /// The debug ID is a UUID derived from the generated code, so the same output always has the same ID.
+    ///
+    /// Default is `false`.
+    pub source_map_debug_id: bool,
+
+    /// Add the source to `x_google_ignoreList` of the source map if [CodegenOptions::source_map_path]
+    /// contains any of these strings, e.g. `node_modules`.
+    ///
+    /// Default is empty.
+    pub source_map_ignore_list: Vec<String>,
+
+    /// Character used for indentation.
+    ///
+    /// Only takes into effect when `minify` is false.
+    ///
+    /// Default is [IndentChar::Tab].
+    pub indent_char: IndentChar,
+
+    /// Number of [CodegenOptions::indent_char] printed per indentation level.
+    ///
+    /// Default is `1`.
+    pub indent_width: usize,
+
+    /// Insert a line break at the next safe position once a line reaches this many bytes,
+    /// similar to esbuild's `--line-limit`.
+    ///
+    /// Lines are only broken between statements and after commas.
+    /// Only takes into effect when `minify` is true.
+    ///
+    /// Default is `None`.
+    pub max_line_len: Option<usize>,
+
+    /// Preferred maximum line width of the output, similar to Prettier's `printWidth`.
+    ///
+    /// This is a hint: once a line reaches this many bytes, a line break is inserted after the
+    /// next comma in a list of arguments, array elements or object properties,
+    /// and the rest of the list is indented by one more level. Lines are never broken elsewhere.
+    /// Only takes into effect when `minify` is false.
+    ///
+    /// Default is `None`.
+    pub print_width: Option<usize>,
+
+    /// Escape all non-ASCII characters in strings, template literals, identifiers and
+    /// regular expressions, similar to esbuild's `--charset=ascii`.
+    ///
+    /// Tagged templates, JSX and comments are printed as is, because escaping them changes their meaning.
+    ///
+    /// Default is `false`.
+    pub ascii_only: bool,
+
+    /// Preserve the formatting of the source text, similar to [recast](https://github.com/benjamn/recast).
+    ///
+    /// Statements and expressions which did not change since parsing are copied verbatim
+    /// from the source text together with their comments, only changed nodes are printed.
+    /// A node has changed if its span is [oxc_span::SPAN], if its name, literal value, operator or
+    /// declaration kind no longer matches its source text, if it was moved out of its original
+    /// parent, or if it contains a changed node. Other in-place mutations are not detected, set the
+    /// span of a node to [oxc_span::SPAN] after them, e.g. after removing an element from a list or
+    /// making a function `async`.
+    ///
+    /// An unchanged program is printed exactly as its source text. Printed statements keep the
+    /// indentation of their original line, and the indentation of new lines is detected from the
+    /// source text in place of [CodegenOptions::indent_char] and [CodegenOptions::indent_width].
+    ///
+    /// Identifiers renamed by [Codegen::with_scoping](crate::Codegen::with_scoping) are only
+    /// applied to printed nodes. Only takes into effect when `minify` is false.
+    ///
+    /// Default is `false`.
+    pub reprint: bool,
 }
 
 impl Default for CodegenOptions {
@@ -86,6 +188,16 @@ impl Default for CodegenOptions {
             annotation_comments: false,
             legal_comments: LegalComment::default(),
             source_map_path: None,
+            source_map_sources_content: true,
+            source_map_source_root: None,
+            source_map_debug_id: false,
+            source_map_ignore_list: vec![],
+            indent_char: IndentChar::default(),
+            indent_width: 1,
+            max_line_len: None,
+            print_width: None,
+            ascii_only: false,
+            reprint: false,
         }
     }
 }
@@ -95,6 +207,18 @@ impl CodegenOptions {
         !self.minify && (self.comments || self.legal_comments.is_inline())
     }
 
+    pub(crate) fn max_line_len(&self) -> Option<usize> {
+        if self.minify { self.max_line_len } else { None }
+    }
+
+    pub(crate) fn print_width(&self) -> Option<usize> {
+        if self.minify { None } else { self.print_width }
+    }
+
+    pub(crate) fn reprint(&self) -> bool {
+        !self.minify && self.reprint
+    }
+
     pub(crate) fn print_annotation_comments(&self) -> bool {
         !self.minify && (self.comments || self.annotation_comments)
     }
@@ -0,0 +1,691 @@
+//! Formatting-preserving printing, see [CodegenOptions::reprint].
+//!
+//! Nodes which did not change since parsing are copied verbatim from the source text,
+//! only changed subtrees are printed by the code generator. Changes are detected by comparing
+//! nodes with their source text, in-place mutations of other properties of a node must also
+//! replace its span.
+//!
+//! [CodegenOptions::reprint]: crate::CodegenOptions::reprint
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{
+    AstKind, Comment,
+    ast::{Declaration, ExportDefaultDeclarationKind, Expression, Program, Statement},
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::precedence::{GetPrecedence, Precedence};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{Codegen, Context, IndentChar};
+
+pub struct Reprint {
+    /// Spans of nodes which changed themselves or contain a changed node.
+    changed: FxHashSet<Span>,
+    /// Span of the next statement in the same statement list, keyed by statement span.
+    /// `None` for the last statement of a list.
+    next_statements: FxHashMap<Span, Option<Span>>,
+    /// All comments of the program, sorted by position.
+    comments: Vec<Comment>,
+    /// Whether comments are printed, see [crate::CodegenOptions::comments].
+    print_comments: bool,
+    /// Indices into `comments`, grouped by the position they are attached to.
+    attached: FxHashMap<u32, Vec<usize>>,
+    /// Comments which have already been printed.
+    printed: FxHashSet<usize>,
+    /// Start of the source line after the last line break copied from the source text,
+    /// and the length of the output after copying it.
+    line_start: Option<(u32, usize)>,
+    /// Original indentation to print for the next statement, instead of the indentation level.
+    indent: Option<Span>,
+}
+
+impl Reprint {
+    pub fn new(program: &Program<'_>, print_comments: bool) -> Self {
+        let mut finder = ChangedNodeFinder {
+            source_text: program.source_text,
+            stack: vec![],
+            changed: FxHashSet::default(),
+            next_statements: FxHashMap::default(),
+        };
+        finder.visit_program(program);
+
+        let comments = program.comments.to_vec();
+        let mut attached = FxHashMap::<u32, Vec<usize>>::default();
+        for (index, comment) in comments.iter().enumerate().filter(|(_, c)| c.is_leading()) {
+            attached.entry(comment.attached_to).or_default().push(index);
+        }
+
+        Self {
+            changed: finder.changed,
+            next_statements: finder.next_statements,
+            comments,
+            print_comments,
+            attached,
+            printed: FxHashSet::default(),
+            line_start: None,
+            indent: None,
+        }
+    }
+
+    fn is_unchanged(&self, span: Span, source_text: &str) -> bool {
+        is_in_source(span, source_text) && !self.changed.contains(&span)
+    }
+
+    /// Comments attached to `start` which have not been printed yet.
+    fn take_leading_comments(&mut self, start: u32) -> Vec<Comment> {
+        let Some(indices) = self.attached.remove(&start) else { return vec![] };
+        let comments = indices
+            .into_iter()
+            .filter(|&index| self.printed.insert(index))
+            .map(|index| self.comments[index])
+            .collect();
+        if self.print_comments { comments } else { vec![] }
+    }
+
+    fn take_comment_at(&mut self, start: u32) -> Option<Comment> {
+        if !self.print_comments {
+            return None;
+        }
+        let index =
+            self.comments.binary_search_by_key(&start, |comment| comment.span.start).ok()?;
+        self.printed.insert(index).then(|| self.comments[index])
+    }
+
+    pub fn take_indent(&mut self) -> Option<Span> {
+        self.indent.take()
+    }
+
+    /// Whether the semicolon omitted after the statement at `span` in the source text can be
+    /// left out, because the statement is followed by the end of its list or by the unchanged
+    /// statement which followed it in the source text.
+    fn may_omit_semicolon(&self, span: Span, source_text: &str) -> bool {
+        match self.next_statements.get(&span) {
+            Some(None) => true,
+            Some(Some(next)) => {
+                next.start >= span.end
+                    && self.is_unchanged(*next, source_text)
+                    && self.is_trivia(source_text, span.end, next.start)
+            }
+            None => false,
+        }
+    }
+
+    /// Whether the source text from `start` to `end` only contains whitespace and comments
+    /// which can be copied to the output.
+    fn is_trivia(&self, source_text: &str, start: u32, end: u32) -> bool {
+        let bytes = source_text.as_bytes();
+        let mut index = self.comments.partition_point(|comment| comment.span.start < start);
+        let mut pos = start;
+        while pos < end {
+            if let Some(comment) = self.comments.get(index).filter(|c| c.span.start == pos) {
+                if !self.print_comments {
+                    return false;
+                }
+                pos = comment.span.end;
+                index += 1;
+            } else if bytes[pos as usize].is_ascii_whitespace() {
+                pos += 1;
+            } else {
+                return false;
+            }
+        }
+        pos == end
+    }
+}
+
+/// Detect the indentation of the source text from the most common increase in
+/// indentation between consecutive lines.
+pub fn detect_indentation(program: &Program<'_>) -> Option<(IndentChar, usize)> {
+    let mut counts = FxHashMap::<&str, usize>::default();
+    let mut prev_indent = "";
+    for line in program.source_text.lines() {
+        let content = line.trim_start_matches([' ', '\t']);
+        if content.is_empty() {
+            continue;
+        }
+        let indent = &line[..line.len() - content.len()];
+        if let Some(increase) = indent.strip_prefix(prev_indent) {
+            if increase.bytes().all(|b| b == b' ') || increase.bytes().all(|b| b == b'\t') {
+                *counts.entry(increase).or_default() += 1;
+            }
+        }
+        prev_indent = indent;
+    }
+    let (increase, _) = counts
+        .into_iter()
+        .filter(|(increase, _)| !increase.is_empty())
+        .max_by_key(|&(increase, count)| (count, std::cmp::Reverse(increase.len()), increase))?;
+    let indent_char = if increase.starts_with('\t') { IndentChar::Tab } else { IndentChar::Space };
+    Some((indent_char, increase.len()))
+}
+
+fn is_in_source(span: Span, source_text: &str) -> bool {
+    !span.is_unspanned() && span.start < span.end && span.end as usize <= source_text.len()
+}
+
+struct Frame {
+    span: Span,
+    /// End of the previous child.
+    last_end: u32,
+    /// Span of the previous child.
+    last_span: Span,
+    changed: bool,
+}
+
+/// Collects the spans of all nodes which are not safe to copy from the source text.
+///
+/// A node is changed if it has no span, if its name, literal value, operator or declaration kind
+/// no longer matches the source text, or if it contains a changed node. Nodes which are not within
+/// the span of their parent, or are not after their previous sibling, have been moved
+/// and mark their ancestors as changed.
+struct ChangedNodeFinder<'s> {
+    source_text: &'s str,
+    stack: Vec<Frame>,
+    changed: FxHashSet<Span>,
+    next_statements: FxHashMap<Span, Option<Span>>,
+}
+
+impl ChangedNodeFinder<'_> {
+    fn is_modified(&self, kind: AstKind<'_>, span: Span) -> bool {
+        if !is_in_source(span, self.source_text) {
+            return true;
+        }
+        let source = span.source_text(self.source_text);
+        match kind {
+            AstKind::IdentifierName(ident) => ident.name != source,
+            AstKind::IdentifierReference(ident) => ident.name != source,
+            AstKind::BindingIdentifier(ident) => ident.name != source,
+            AstKind::LabelIdentifier(ident) => ident.name != source,
+            AstKind::StringLiteral(lit) => lit.raw.is_none_or(|raw| raw != source),
+            AstKind::NumericLiteral(lit) => lit.raw.is_none_or(|raw| raw != source),
+            AstKind::BigIntLiteral(lit) => lit.raw != source,
+            AstKind::RegExpLiteral(lit) => lit.raw.is_none_or(|raw| raw != source),
+            AstKind::BooleanLiteral(lit) => source != if lit.value { "true" } else { "false" },
+            AstKind::TemplateLiteral(lit) => lit.quasis.iter().any(|quasi| {
+                self.source_text.get(quasi.span.start as usize..quasi.span.end as usize)
+                    != Some(quasi.value.raw.as_str())
+            }),
+            AstKind::BinaryExpression(expr) => !self.is_token_between(
+                expr.left.span().end,
+                expr.right.span().start,
+                expr.operator.as_str(),
+            ),
+            AstKind::LogicalExpression(expr) => !self.is_token_between(
+                expr.left.span().end,
+                expr.right.span().start,
+                expr.operator.as_str(),
+            ),
+            AstKind::AssignmentExpression(expr) => !self.is_token_between(
+                expr.left.span().end,
+                expr.right.span().start,
+                expr.operator.as_str(),
+            ),
+            AstKind::UnaryExpression(expr) => !self.is_token_between(
+                span.start,
+                expr.argument.span().start,
+                expr.operator.as_str(),
+            ),
+            AstKind::UpdateExpression(expr) => {
+                let argument = expr.argument.span();
+                let (start, end) = if expr.prefix {
+                    (span.start, argument.start)
+                } else {
+                    (argument.end, span.end)
+                };
+                !self.is_token_between(start, end, expr.operator.as_str())
+            }
+            AstKind::VariableDeclaration(decl) => !starts_with_keyword(source, decl.kind.as_str()),
+            _ => false,
+        }
+    }
+
+    /// Whether the source text from `start` to `end` is `token`, surrounded by whitespace,
+    /// comments and parentheses.
+    fn is_token_between(&self, start: u32, end: u32, token: &str) -> bool {
+        let Some(mut rest) = self.source_text.get(start as usize..end as usize) else {
+            return false;
+        };
+        let mut found = false;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || matches!(c, '(' | ')'));
+            if rest.is_empty() {
+                return found;
+            }
+            if let Some(comment) = rest.strip_prefix("//") {
+                rest = comment.split_once('\n').map_or("", |(_, rest)| rest);
+            } else if let Some(comment) = rest.strip_prefix("/*") {
+                rest = comment.split_once("*/").map_or("", |(_, rest)| rest);
+            } else if let Some(after) = rest.strip_prefix(token).filter(|_| !found) {
+                rest = after;
+                found = true;
+            } else {
+                return false;
+            }
+        }
+    }
+
+    fn is_moved(&self, span: Span) -> bool {
+        let Some(parent) = self.stack.last() else { return false };
+        if parent.span.is_unspanned() {
+            return false;
+        }
+        !parent.span.contains_inclusive(span)
+            || (span.start < parent.last_end && span != parent.last_span)
+    }
+
+    fn mark_ancestors(&mut self) {
+        for frame in self.stack.iter_mut().rev() {
+            if frame.changed {
+                break;
+            }
+            frame.changed = true;
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ChangedNodeFinder<'_> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let span = kind.span();
+        let modified = self.is_modified(kind, span);
+        if modified || self.is_moved(span) {
+            self.mark_ancestors();
+        }
+        let start = span.start;
+        self.stack.push(Frame {
+            span,
+            last_end: start,
+            last_span: Span::empty(start),
+            changed: false,
+        });
+        if modified {
+            self.mark_ancestors();
+        }
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        let frame = self.stack.pop().unwrap();
+        if frame.changed {
+            self.changed.insert(frame.span);
+        }
+        if let Some(parent) = self.stack.last_mut() {
+            parent.last_end = parent.last_end.max(frame.span.end);
+            parent.last_span = frame.span;
+        }
+    }
+
+    fn visit_statements(&mut self, stmts: &ArenaVec<'a, Statement<'a>>) {
+        let next_spans = stmts.iter().skip(1).map(|stmt| Some(stmt.span())).chain([None]);
+        for (stmt, next_span) in stmts.iter().zip(next_spans) {
+            self.next_statements.insert(stmt.span(), next_span);
+        }
+        walk::walk_statements(self, stmts);
+    }
+}
+
+/// Precedence of an expression copied from the source text, `None` if it should be printed.
+fn verbatim_precedence(expr: &Expression<'_>) -> Option<Precedence> {
+    match expr {
+        Expression::SequenceExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::ConditionalExpression(_)
+        | Expression::LogicalExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::UnaryExpression(_)
+        | Expression::UpdateExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::ComputedMemberExpression(_)
+        | Expression::StaticMemberExpression(_)
+        | Expression::PrivateFieldExpression(_) => Some(expr.precedence()),
+        Expression::ArrowFunctionExpression(_) => Some(Precedence::Assign),
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::Identifier(_)
+        | Expression::ThisExpression(_)
+        | Expression::ArrayExpression(_)
+        | Expression::ObjectExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ClassExpression(_)
+        | Expression::ParenthesizedExpression(_)
+        | Expression::TaggedTemplateExpression(_)
+        | Expression::MetaProperty(_)
+        | Expression::JSXElement(_)
+        | Expression::JSXFragment(_) => Some(Precedence::Member),
+        _ => None,
+    }
+}
+
+/// Whether a statement is terminated by a semicolon which may have been omitted in the source.
+fn needs_semicolon(stmt: &Statement<'_>) -> bool {
+    match stmt {
+        Statement::ExpressionStatement(_)
+        | Statement::VariableDeclaration(_)
+        | Statement::ReturnStatement(_)
+        | Statement::ThrowStatement(_)
+        | Statement::BreakStatement(_)
+        | Statement::ContinueStatement(_)
+        | Statement::DebuggerStatement(_)
+        | Statement::DoWhileStatement(_)
+        | Statement::ImportDeclaration(_)
+        | Statement::ExportAllDeclaration(_)
+        | Statement::TSExportAssignment(_)
+        | Statement::TSNamespaceExportDeclaration(_)
+        | Statement::TSImportEqualsDeclaration(_) => true,
+        Statement::ExportNamedDeclaration(decl) => decl
+            .declaration
+            .as_ref()
+            .is_none_or(|decl| matches!(decl, Declaration::VariableDeclaration(_))),
+        Statement::ExportDefaultDeclaration(decl) => !matches!(
+            decl.declaration,
+            ExportDefaultDeclarationKind::FunctionDeclaration(_)
+                | ExportDefaultDeclarationKind::ClassDeclaration(_)
+                | ExportDefaultDeclarationKind::TSInterfaceDeclaration(_)
+        ),
+        _ => false,
+    }
+}
+
+fn starts_with_keyword(text: &str, keyword: &str) -> bool {
+    text.strip_prefix(keyword).is_some_and(|rest| {
+        !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+    })
+}
+
+impl Codegen<'_> {
+    pub(crate) fn is_unchanged_for_reprint(&self, span: Span) -> bool {
+        self.reprint.as_ref().is_some_and(|reprint| reprint.is_unchanged(span, self.source_text))
+    }
+
+    /// Copy an unchanged program from the source text.
+    ///
+    /// Returns `false` if the program needs to be printed.
+    pub(crate) fn reprint_program(&mut self, program: &Program<'_>) -> bool {
+        if !self.is_unchanged_for_reprint(program.span) {
+            return false;
+        }
+        self.add_source_mapping(program.span);
+        self.print_str(program.source_text);
+        true
+    }
+
+    /// Copy an unchanged statement from the source text.
+    ///
+    /// Returns `false` if the statement needs to be printed, after printing its leading comments.
+    pub(crate) fn reprint_statement(&mut self, stmt: &Statement<'_>) -> bool {
+        let span = stmt.span();
+        let Some(reprint) = &mut self.reprint else { return false };
+        reprint.indent = None;
+        if !is_in_source(span, self.source_text) {
+            return false;
+        }
+        self.print_reprint_leading_comments(span.start);
+        if !self.is_unchanged_for_reprint(span) {
+            let indent = self.reprint_indent(span.start);
+            if let Some(reprint) = &mut self.reprint {
+                reprint.indent = indent;
+            }
+            return false;
+        }
+
+        self.print_reprint_indent(span.start);
+        self.add_source_mapping(span);
+        let text = span.source_text(self.source_text);
+        self.print_str(text);
+        if needs_semicolon(stmt)
+            && !text.ends_with(';')
+            && !self
+                .reprint
+                .as_ref()
+                .is_some_and(|reprint| reprint.may_omit_semicolon(span, self.source_text))
+        {
+            self.print_semicolon();
+        }
+        self.print_reprint_line_end(span.end);
+        if self.last_byte() != Some(b'\n') {
+            self.print_soft_newline();
+        }
+        true
+    }
+
+    /// Finish a printed statement with the comments after it on the same line.
+    pub(crate) fn print_reprint_statement_end(&mut self, span: Span) {
+        if let Some(reprint) = &mut self.reprint {
+            reprint.indent = None;
+        }
+        if is_in_source(span, self.source_text) {
+            self.print_reprint_line_end(span.end);
+        }
+    }
+
+    /// Copy an unchanged expression from the source text.
+    ///
+    /// Returns `false` if the expression needs to be printed.
+    pub(crate) fn reprint_expression(
+        &mut self,
+        expr: &Expression<'_>,
+        precedence: Precedence,
+        ctx: Context,
+    ) -> bool {
+        let span = expr.span();
+        // `in` and calls are wrapped in parentheses by their parent in these contexts.
+        if ctx.forbid_in() || ctx.forbid_call() || !self.is_unchanged_for_reprint(span) {
+            return false;
+        }
+        let Some(expr_precedence) = verbatim_precedence(expr) else { return false };
+
+        let text = span.source_text(self.source_text);
+        let code_len = self.code_len();
+        let wrap = (expr_precedence < Precedence::Member && precedence >= expr_precedence)
+            || (self.start_of_stmt == code_len
+                && (text.starts_with('{')
+                    || starts_with_keyword(text, "function")
+                    || starts_with_keyword(text, "class")
+                    || starts_with_keyword(text, "async")
+                    || starts_with_keyword(text, "let")))
+            || (self.start_of_arrow_expr == code_len && text.starts_with('{'))
+            || (self.start_of_default_export == code_len
+                && (starts_with_keyword(text, "function")
+                    || starts_with_keyword(text, "class")
+                    || starts_with_keyword(text, "async")));
+
+        self.wrap(wrap, |p| {
+            p.print_space_before_verbatim(text);
+            p.add_source_mapping(span);
+            p.print_str(text);
+        });
+        if matches!(expr, Expression::NumericLiteral(_)) && text.bytes().all(|b| b.is_ascii_digit())
+        {
+            self.need_space_before_dot = self.code_len();
+        }
+        true
+    }
+
+    /// Avoid merging the first token of `text` with the end of the output.
+    fn print_space_before_verbatim(&mut self, text: &str) {
+        let Some(first) = text.chars().next() else { return };
+        if first.is_alphanumeric() || matches!(first, '_' | '$' | '\\' | '#') || !first.is_ascii() {
+            self.print_space_before_identifier();
+            return;
+        }
+        if let Some(last) = self.last_byte() {
+            if matches!((last, first), (b'+', '+') | (b'-', '-') | (b'/', '/') | (b'<', '!')) {
+                self.print_hard_space();
+            }
+        }
+    }
+
+    /// Copy the comments attached to `start` from the source text,
+    /// keeping empty lines and the indentation of the first line.
+    ///
+    /// If the output ends with a line copied from the source text, everything up to `start` is copied.
+    pub(crate) fn print_reprint_leading_comments(&mut self, start: u32) {
+        let Some(reprint) = &mut self.reprint else { return };
+        let comments = reprint.take_leading_comments(start);
+        // Comments printed by [`Codegen::print_statement_comments`] are contained in these.
+        self.comments.remove(&start);
+
+        if let Some(line_start) = self.reprint_line_start(start) {
+            let source_text = self.source_text;
+            self.print_str(&source_text[line_start as usize..start as usize]);
+            return;
+        }
+
+        let first_start = comments.first().map_or(start, |comment| comment.span.start);
+        self.print_reprint_empty_line(first_start);
+        for comment in comments {
+            if comment.preceded_by_newline && !matches!(self.last_byte(), None | Some(b'\n')) {
+                self.print_hard_newline();
+            }
+            self.print_reprint_indent(comment.span.start);
+            self.print_str(comment.span.source_text(self.source_text));
+            if comment.is_line() || comment.followed_by_newline {
+                self.print_hard_newline();
+            } else {
+                self.print_hard_space();
+            }
+        }
+    }
+
+    /// Start of the source line the output continues, if everything from there to `start`
+    /// can be copied from the source text.
+    fn reprint_line_start(&self, start: u32) -> Option<u32> {
+        let reprint = self.reprint.as_ref()?;
+        let (line_start, code_len) = reprint.line_start?;
+        (code_len == self.code_len()
+            && line_start <= start
+            && reprint.is_trivia(self.source_text, line_start, start))
+        .then_some(line_start)
+    }
+
+    /// Copy the rest of the source line after `end` if it only contains whitespace and comments,
+    /// replacing the line break printed before.
+    fn print_reprint_line_end(&mut self, end: u32) {
+        let comments = self.take_reprint_trailing_comments(end);
+        let comments_end = comments.last().map_or(end, |comment| comment.span.end) as usize;
+        let bytes = self.source_text.as_bytes();
+        let mut pos = comments_end;
+        while matches!(bytes.get(pos), Some(b' ' | b'\t' | b'\r')) {
+            pos += 1;
+        }
+        let line_end = (bytes.get(pos) == Some(&b'\n')).then_some(pos + 1);
+        if comments.is_empty() && line_end.is_none() {
+            return;
+        }
+
+        let at_line_start = self.last_byte() == Some(b'\n');
+        let newline = at_line_start && self.pop_reprint_newline();
+        let source_text = self.source_text;
+        if at_line_start && !newline {
+            // The comments can't continue the line, print them on their own line instead.
+            if !comments.is_empty() {
+                self.print_str(source_text[end as usize..comments_end].trim_start());
+                self.print_hard_newline();
+            }
+        } else if let Some(line_end) = line_end {
+            self.print_str(&source_text[end as usize..line_end]);
+            #[expect(clippy::cast_possible_truncation)]
+            let line_start = (line_end as u32, self.code_len());
+            if let Some(reprint) = &mut self.reprint {
+                reprint.line_start = Some(line_start);
+            }
+        } else {
+            self.print_str(&source_text[end as usize..comments_end]);
+            if newline || comments.last().is_some_and(|comment| comment.is_line()) {
+                self.print_hard_newline();
+            }
+        }
+    }
+
+    /// Remove the line break at the end of the output, to continue the line it ends.
+    ///
+    /// The line break is kept if a source mapping was added after it, because the sourcemap
+    /// builder has already counted it.
+    fn pop_reprint_newline(&mut self) -> bool {
+        let len = self.code_len() - 1;
+        if self.sourcemap_builder.as_ref().is_some_and(|builder| builder.is_generated_after(len)) {
+            return false;
+        }
+        self.code.pop_ascii_byte().is_some()
+    }
+
+    fn take_reprint_trailing_comments(&mut self, end: u32) -> Vec<Comment> {
+        let Some(reprint) = &mut self.reprint else { return vec![] };
+        let mut comments = vec![];
+        let bytes = self.source_text.as_bytes();
+        let mut pos = end as usize;
+        loop {
+            while matches!(bytes.get(pos), Some(b' ' | b'\t')) {
+                pos += 1;
+            }
+            if bytes.get(pos) != Some(&b'/') || !matches!(bytes.get(pos + 1), Some(b'/' | b'*')) {
+                break;
+            }
+            #[expect(clippy::cast_possible_truncation)]
+            let Some(comment) = reprint.take_comment_at(pos as u32) else { break };
+            comments.push(comment);
+            if comment.is_line() || comment.followed_by_newline {
+                break;
+            }
+            pos = comment.span.end as usize;
+        }
+        comments
+    }
+
+    /// Print an empty line if there is one before `start` in the source text.
+    fn print_reprint_empty_line(&mut self, start: u32) {
+        if self.last_byte() != Some(b'\n') || self.code.as_bytes().ends_with(b"\n\n") {
+            return;
+        }
+        let before = &self.source_text[..start as usize];
+        let whitespace = before.len() - before.trim_end().len();
+        if before[before.len() - whitespace..].bytes().filter(|&b| b == b'\n').count() >= 2 {
+            self.print_hard_newline();
+        }
+    }
+
+    /// Print the original indentation of the line `start` is on if it starts a line of the output.
+    fn print_reprint_indent(&mut self, start: u32) {
+        if self.print_next_indent_as_space {
+            self.print_indent();
+            return;
+        }
+        if !matches!(self.last_byte(), None | Some(b'\n')) {
+            return;
+        }
+        match self.reprint_indent(start) {
+            Some(indent) => {
+                let source_text = self.source_text;
+                self.print_str(indent.source_text(source_text));
+            }
+            None => self.print_indent(),
+        }
+    }
+
+    /// Original indentation to print before `start`.
+    ///
+    /// Empty if the output already ends with indentation copied from the source text,
+    /// `None` if the line of `start` does not start with it.
+    fn reprint_indent(&self, start: u32) -> Option<Span> {
+        let before = &self.source_text[..start as usize];
+        let line_start = before.rfind(['\n', '\r']).map_or(0, |index| index + 1);
+        if !before[line_start..].bytes().all(|b| matches!(b, b' ' | b'\t')) {
+            return None;
+        }
+        let output_line = self.code.as_bytes().rsplit(|&b| b == b'\n').next().unwrap_or_default();
+        if !output_line.is_empty() {
+            return output_line
+                .iter()
+                .all(|b| matches!(b, b' ' | b'\t'))
+                .then(|| Span::empty(start));
+        }
+        #[expect(clippy::cast_possible_truncation)]
+        Some(Span::new(line_start as u32, start))
+    }
+}
@@ -1,9 +1,12 @@
-use std::{path::Path, sync::Arc};
+use std::{fmt::Write, path::Path, sync::Arc};
 
 use nonmax::NonMaxU32;
 use oxc_index::{Idx, IndexVec};
 use oxc_span::Span;
 use oxc_syntax::identifier::{LS, PS};
+use sha1::{Digest, Sha1};
+
+use crate::CodegenOptions;
 
 // Irregular line breaks - '\u{2028}' (LS) and '\u{2029}' (PS)
 const LS_OR_PS_FIRST: u8 = 0xE2;
@@ -14,6 +17,24 @@ const PS_THIRD: u8 = 0xA9;
 /// Number of lines to check with linear search when translating byte position to line index
 const LINE_SEARCH_LINEAR_ITERATIONS: usize = 16;
 
+/// Create a deterministic UUID for [CodegenOptions::source_map_debug_id] from the generated code.
+pub fn debug_id(code: &[u8]) -> String {
+    let hash = Sha1::digest(code);
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&hash[..16]);
+    // Set the version (4) and variant (RFC 4122) bits, so the ID is a valid UUID.
+    bytes[6] = (bytes[6] & 0x0F) | 0x40;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    let mut uuid = String::with_capacity(36);
+    for (i, byte) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            uuid.push('-');
+        }
+        write!(uuid, "{byte:02x}").unwrap();
+    }
+    uuid
+}
+
 /// Index into vec of `ColumnOffsets`
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct ColumnOffsetsId(NonMaxU32);
@@ -99,10 +120,55 @@ impl SourcemapBuilder {
         }
     }
 
+    /// Whether the generated position of a mapping was computed from output past `len` bytes.
+    pub fn is_generated_after(&self, len: usize) -> bool {
+        self.last_generated_update > len
+    }
+
     pub fn into_sourcemap(self) -> oxc_sourcemap::SourceMap {
         self.sourcemap_builder.into_sourcemap()
     }
 
+    /// Build the source map, applying the `source_map_*` options which are not handled while printing.
+    pub fn into_sourcemap_with_options(
+        self,
+        options: &CodegenOptions,
+        debug_id: Option<&str>,
+    ) -> oxc_sourcemap::SourceMap {
+        let source_id = self.source_id;
+        let mut sourcemap = self.into_sourcemap();
+        if !options.source_map_sources_content || options.source_map_source_root.is_some() {
+            // `oxc_sourcemap` has no setters for these.
+            let source_contents = options.source_map_sources_content.then(|| {
+                sourcemap.get_source_contents().map(|contents| contents.map(Arc::from).collect())
+            });
+            let mut rebuilt = oxc_sourcemap::SourceMap::new(
+                sourcemap.get_file().map(Arc::from),
+                sourcemap.get_names().map(Arc::from).collect(),
+                options.source_map_source_root.clone(),
+                sourcemap.get_sources().map(Arc::from).collect(),
+                source_contents.flatten(),
+                sourcemap.get_tokens().cloned().collect(),
+                None,
+            );
+            if let Some(ignore_list) = sourcemap.get_x_google_ignore_list() {
+                rebuilt.set_x_google_ignore_list(ignore_list.to_vec());
+            }
+            sourcemap = rebuilt;
+        }
+        if let Some(path) = &options.source_map_path {
+            let path = path.to_string_lossy();
+            if options.source_map_ignore_list.iter().any(|pattern| path.contains(pattern.as_str()))
+            {
+                sourcemap.set_x_google_ignore_list(vec![source_id]);
+            }
+        }
+        if let Some(debug_id) = debug_id {
+            sourcemap.set_debug_id(debug_id);
+        }
+        sourcemap
+    }
+
     pub fn add_source_mapping_for_name(&mut self, output: &[u8], span: Span, name: &str) {
         debug_assert!(
             (span.end as usize) <= self.original_source.len(),
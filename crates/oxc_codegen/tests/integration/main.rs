@@ -3,6 +3,7 @@ pub mod esbuild;
 pub mod jsdoc;
 pub mod legal_comments;
 pub mod pure_comments;
+pub mod reprint;
 pub mod tester;
 pub mod ts;
 pub mod unit;
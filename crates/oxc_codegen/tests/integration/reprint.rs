@@ -0,0 +1,203 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    AstBuilder, NONE,
+    ast::{Expression, Program, Statement, VariableDeclarationKind},
+};
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::{SPAN, SourceType};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{
+        AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
+    },
+};
+
+fn reprint<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    mutate: impl FnOnce(AstBuilder<'a>, &mut Program<'a>),
+) -> String {
+    let mut program = Parser::new(allocator, source_text, SourceType::mjs()).parse().program;
+    mutate(AstBuilder::new(allocator), &mut program);
+    let options = CodegenOptions { reprint: true, ..CodegenOptions::default() };
+    CodeGenerator::new().with_options(options).build(&program).code
+}
+
+fn expression_statement<'b, 'a>(
+    program: &'b mut Program<'a>,
+    index: usize,
+) -> &'b mut Expression<'a> {
+    let Statement::ExpressionStatement(stmt) = &mut program.body[index] else { unreachable!() };
+    &mut stmt.expression
+}
+
+#[test]
+fn unchanged() {
+    let source_text = "#!/usr/bin/env node\n// header\nconst  a = {b:1}\n\n\nfunction f( x ) {\n  return x  // done\n}\nf( a ) /* call */\n/* eof */\n";
+    let allocator = Allocator::default();
+    assert_eq!(reprint(&allocator, source_text, |_, _| {}), source_text);
+}
+
+#[test]
+fn unchanged_statements() {
+    let source_text = "// header\nconst  a = {b:1}\n\n\nfunction f( x ) {\n  return x  // done\n}\nf( a ) /* call */\n\n/* eof */\n";
+    let allocator = Allocator::default();
+    let code = reprint(&allocator, source_text, |ast, program| {
+        let Statement::VariableDeclaration(decl) = &mut program.body[0] else { unreachable!() };
+        decl.declarations[0].init = Some(ast.expression_identifier(SPAN, "b"));
+    });
+    assert_eq!(
+        code,
+        "// header\nconst a = b;\n\n\nfunction f( x ) {\n  return x  // done\n}\nf( a ) /* call */\n\n/* eof */\n"
+    );
+}
+
+#[test]
+fn renamed_identifier() {
+    let allocator = Allocator::default();
+    let code = reprint(&allocator, "let  a = [1,2];\nfoo( a,  b );\n", |_, program| {
+        let Expression::CallExpression(call) = expression_statement(program, 1) else {
+            unreachable!()
+        };
+        let Some(Expression::Identifier(ident)) = call.arguments[1].as_expression_mut() else {
+            unreachable!()
+        };
+        ident.name = "c".into();
+    });
+    assert_eq!(code, "let  a = [1,2];\nfoo(a, c);\n");
+}
+
+#[test]
+fn nested_statements() {
+    let allocator = Allocator::default();
+    let source_text =
+        "function f() {\n  // check\n  if (x)   {\n    y( 1 );\n  }\n\n  return  z;\n}\n";
+    let code = reprint(&allocator, source_text, |ast, program| {
+        let Statement::FunctionDeclaration(func) = &mut program.body[0] else { unreachable!() };
+        let body = func.body.as_mut().unwrap();
+        let Statement::ReturnStatement(ret) = &mut body.statements[1] else { unreachable!() };
+        ret.argument = Some(ast.expression_identifier(SPAN, "w"));
+    });
+    assert_eq!(
+        code,
+        "function f() {\n  // check\n  if (x)   {\n    y( 1 );\n  }\n\n  return w;\n}\n"
+    );
+}
+
+#[test]
+fn leading_comments() {
+    let allocator = Allocator::default();
+    let code = reprint(&allocator, "/* keep */\nfoo( 1 ); // one\nbar( 1 );\n", |ast, program| {
+        let Expression::CallExpression(call) = expression_statement(program, 0) else {
+            unreachable!()
+        };
+        call.arguments[0] =
+            ast.expression_numeric_literal(SPAN, 2.0, None, NumberBase::Decimal).into();
+    });
+    assert_eq!(code, "/* keep */\nfoo(2); // one\nbar( 1 );\n");
+}
+
+#[test]
+fn moved_expression() {
+    let allocator = Allocator::default();
+    let code = reprint(&allocator, "a = b  +  c;\nd;\n", |ast, program| {
+        let Expression::AssignmentExpression(assign) = expression_statement(program, 0) else {
+            unreachable!()
+        };
+        let sum = std::mem::replace(&mut assign.right, ast.expression_identifier(SPAN, "x"));
+        let product = ast.expression_binary(
+            SPAN,
+            sum,
+            BinaryOperator::Multiplication,
+            ast.expression_identifier(SPAN, "e"),
+        );
+        *expression_statement(program, 1) = product;
+    });
+    assert_eq!(code, "a = x;\n(b  +  c) * e;\n");
+}
+
+#[test]
+fn verbatim_at_start_of_statement() {
+    let allocator = Allocator::default();
+    let code = reprint(&allocator, "x = function() {};\ny;\n", |ast, program| {
+        let Expression::AssignmentExpression(assign) = expression_statement(program, 0) else {
+            unreachable!()
+        };
+        let func = std::mem::replace(&mut assign.right, ast.expression_identifier(SPAN, "z"));
+        *expression_statement(program, 1) = func;
+    });
+    assert_eq!(code, "x = z;\n(function() {});\n");
+}
+
+#[test]
+fn removed_and_added_statements() {
+    let allocator = Allocator::default();
+    let source_text = "if (a) {\n    foo()\n    bar()\n    baz()\n}\n";
+    let code = reprint(&allocator, source_text, |ast, program| {
+        let Statement::IfStatement(if_stmt) = &mut program.body[0] else { unreachable!() };
+        let Statement::BlockStatement(block) = &mut if_stmt.consequent else { unreachable!() };
+        block.span = SPAN;
+        block.body.remove(1);
+        let callee = ast.expression_identifier(SPAN, "qux");
+        let call = ast.expression_call(SPAN, callee, NONE, ast.vec(), false);
+        block.body.push(ast.statement_expression(SPAN, call));
+    });
+    assert_eq!(code, "if (a) {\n    foo();\n    baz();\n    qux();\n}\n");
+}
+
+#[test]
+fn in_place_mutations() {
+    let source_text = "let  a = 1n + /x/g - `t${b}` || !c;\nd  += e++;\nconst  f = true;\n";
+    let allocator = Allocator::default();
+    assert_eq!(reprint(&allocator, source_text, |_, _| {}), source_text);
+
+    let code = reprint(&allocator, source_text, |_, program| {
+        let Statement::VariableDeclaration(decl) = &mut program.body[0] else { unreachable!() };
+        let Some(Expression::LogicalExpression(logical)) = &mut decl.declarations[0].init else {
+            unreachable!()
+        };
+        logical.operator = LogicalOperator::And;
+        let Expression::BinaryExpression(binary) = &mut logical.left else { unreachable!() };
+        binary.operator = BinaryOperator::Addition;
+        let Expression::UnaryExpression(unary) = &mut logical.right else { unreachable!() };
+        unary.operator = UnaryOperator::UnaryNegation;
+    });
+    assert_eq!(code, "let a = 1n + /x/g + `t${b}` && -c;\nd  += e++;\nconst  f = true;\n");
+
+    let code = reprint(&allocator, source_text, |_, program| {
+        let Expression::AssignmentExpression(assign) = expression_statement(program, 1) else {
+            unreachable!()
+        };
+        assign.operator = AssignmentOperator::Subtraction;
+        let Expression::UpdateExpression(update) = &mut assign.right else { unreachable!() };
+        update.operator = UpdateOperator::Decrement;
+        let Statement::VariableDeclaration(decl) = &mut program.body[2] else { unreachable!() };
+        decl.kind = VariableDeclarationKind::Let;
+        let Some(Expression::BooleanLiteral(lit)) = &mut decl.declarations[0].init else {
+            unreachable!()
+        };
+        lit.value = false;
+    });
+    assert_eq!(code, "let  a = 1n + /x/g - `t${b}` || !c;\nd -= e--;\nlet f = false;\n");
+}
+
+#[test]
+fn sourcemap_with_trailing_comments() {
+    let source_text = "function f( x ) {\n  return x;\n} // f\nbar( 1 ); // bar\n";
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let Statement::FunctionDeclaration(func) = &mut program.body[0] else { unreachable!() };
+    func.id.as_mut().unwrap().name = "g".into();
+    let options = CodegenOptions {
+        reprint: true,
+        source_map_path: Some("test.js".into()),
+        ..CodegenOptions::default()
+    };
+    let ret = CodeGenerator::new().with_options(options).build(&program);
+    assert_eq!(ret.code, "function g(x) {\n  return x;\n} // f\nbar( 1 ); // bar\n");
+    let map = ret.map.unwrap();
+    let token = map.get_tokens().last().unwrap();
+    assert_eq!((token.get_dst_line(), token.get_dst_col()), (3, 0));
+    assert_eq!((token.get_src_line(), token.get_src_col()), (3, 0));
+}
@@ -1,4 +1,7 @@
-use oxc_codegen::CodegenOptions;
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions, IndentChar};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
 
 use crate::tester::{
     test, test_minify, test_minify_same, test_options, test_same, test_with_parse_options,
@@ -519,3 +522,158 @@ fn v8_intrinsics() {
         parse_opts,
     );
 }
+
+#[test]
+fn max_line_len() {
+    let options =
+        CodegenOptions { minify: true, max_line_len: Some(8), ..CodegenOptions::default() };
+    test_options(
+        "foo(1, 2, 3, 4, 5); bar(); baz()",
+        "foo(1,2,\n3,4,5);bar();\nbaz();",
+        options.clone(),
+    );
+    // Template literals and strings may contain line breaks, and are never broken.
+    test_options(
+        "`a\nb`; foo(1, 2); 'long string', 2",
+        "`a\nb`;foo(1,\n2);`long string`,\n2;",
+        options,
+    );
+    // Lines are not broken when not minifying.
+    test_options(
+        "foo(1, 2, 3, 4, 5);\n",
+        "foo(1, 2, 3, 4, 5);\n",
+        CodegenOptions { max_line_len: Some(8), ..CodegenOptions::default() },
+    );
+}
+
+#[test]
+fn max_line_len_sourcemap() {
+    let source_text = "var alpha = 1, beta = 2, gamma = 3; alpha(beta, gamma);";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let options = CodegenOptions {
+        minify: true,
+        max_line_len: Some(10),
+        source_map_path: Some("test.js".into()),
+        ..CodegenOptions::default()
+    };
+    let ret = CodeGenerator::new().with_options(options).build(&ret.program);
+    assert_eq!(ret.code, "var alpha=1,\nbeta=2,gamma=3;\nalpha(beta,\ngamma);");
+    let map = ret.map.unwrap();
+    let lines = ret.code.lines().collect::<Vec<_>>();
+    for token in map.get_source_view_tokens() {
+        let Some(name) = token.get_name() else { continue };
+        let line = lines[token.get_dst_line() as usize];
+        assert!(
+            line[token.get_dst_col() as usize..].starts_with(name),
+            "{name} not found in {line}"
+        );
+        let src_line = source_text.lines().nth(token.get_src_line() as usize).unwrap();
+        assert!(src_line[token.get_src_col() as usize..].starts_with(name));
+    }
+}
+
+#[test]
+fn ascii_only() {
+    let options = CodegenOptions { ascii_only: true, ..CodegenOptions::default() };
+    test_options("x = 'é😀';", "x = \"\\u00E9\\uD83D\\uDE00\";\n", options.clone());
+    test_options("`é${x}\\é\\\u{2028}`;", "`\\u00E9${x}\\u00E9`;\n", options.clone());
+    test_options("String.raw`é`;", "String.raw`é`;\n", options.clone());
+    test_options(
+        "let café, 𐊧; café(𐊧);",
+        "let caf\\u00E9, \\u{102A7};\ncaf\\u00E9(\\u{102A7});\n",
+        options.clone(),
+    );
+    test_options("class A { #é }", "class A {\n\t#\\u00E9;\n}\n", options.clone());
+    test_options(
+        "/é\\é😀/; /[😀]/u;",
+        "/\\u00E9\\u00E9\\uD83D\\uDE00/;\n/[\\u{1F600}]/u;\n",
+        options.clone(),
+    );
+    test_options("'use é';", "\"use \\u00E9\";\n", options);
+    test_options(
+        "𐊧 in x",
+        "\\u{102A7} in x;",
+        CodegenOptions { minify: true, ascii_only: true, ..CodegenOptions::default() },
+    );
+}
+
+#[test]
+fn indent() {
+    let source_text = "function foo() {\n  if (x) {\n    /*! c */\n    bar();\n  }\n}";
+    let options = CodegenOptions {
+        indent_char: IndentChar::Space,
+        indent_width: 2,
+        ..CodegenOptions::default()
+    };
+    test_options(
+        source_text,
+        "function foo() {\n  if (x) {\n    /*! c */\n    bar();\n  }\n}\n",
+        options,
+    );
+    test_options(
+        source_text,
+        "function foo() {\n\t\tif (x) {\n\t\t\t\t/*! c */\n\t\t\t\tbar();\n\t\t}\n}\n",
+        CodegenOptions { indent_width: 2, ..CodegenOptions::default() },
+    );
+}
+
+#[test]
+fn print_width() {
+    let options = CodegenOptions { print_width: Some(20), ..CodegenOptions::default() };
+    test_options(
+        "function foo() { bar(alpha, beta, gamma, delta); }",
+        "function foo() {\n\tbar(alpha, beta, gamma,\n\t\tdelta);\n}\n",
+        options.clone(),
+    );
+    test_options("[alpha, beta, gamma];", "[\n\talpha,\n\tbeta,\n\tgamma\n];\n", options.clone());
+    test_options("[alphabet_soup, beta];", "[alphabet_soup, beta];\n", options);
+    // Only takes effect when not minifying.
+    test_options(
+        "bar(alpha, beta, gamma, delta);",
+        "bar(alpha,beta,gamma,delta);",
+        CodegenOptions { minify: true, print_width: Some(20), ..CodegenOptions::default() },
+    );
+}
+
+#[test]
+fn sourcemap_options() {
+    let source_text = "const a = 1;";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let build = |options: CodegenOptions| {
+        let options =
+            CodegenOptions { source_map_path: Some("node_modules/pkg/test.js".into()), ..options };
+        CodeGenerator::new().with_options(options).build(&ret.program)
+    };
+
+    let default = build(CodegenOptions::default());
+    let map = default.map.unwrap();
+    let token_count = map.get_tokens().count();
+    assert_eq!(map.get_source_contents().unwrap().collect::<Vec<_>>(), vec![source_text]);
+    assert!(map.get_source_root().is_none());
+    assert!(map.get_debug_id().is_none());
+    assert!(map.get_x_google_ignore_list().is_none());
+
+    let options = CodegenOptions {
+        source_map_sources_content: false,
+        source_map_source_root: Some("/root/".into()),
+        source_map_debug_id: true,
+        source_map_ignore_list: vec!["node_modules".into()],
+        ..CodegenOptions::default()
+    };
+    let ret1 = build(options.clone());
+    let map = ret1.map.unwrap();
+    assert!(map.get_source_contents().is_none());
+    assert_eq!(map.get_source_root(), Some("/root/"));
+    assert_eq!(map.get_x_google_ignore_list(), Some(&[0][..]));
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), vec!["node_modules/pkg/test.js"]);
+    assert_eq!(map.get_tokens().count(), token_count);
+    let debug_id = map.get_debug_id().unwrap();
+    assert_eq!(debug_id.len(), 36);
+    assert_eq!(ret1.code, format!("const a = 1;\n//# debugId={debug_id}\n"));
+
+    // The debug ID is deterministic.
+    let ret2 = build(options);
+    assert_eq!(ret2.map.unwrap().get_debug_id(), Some(debug_id));
+}
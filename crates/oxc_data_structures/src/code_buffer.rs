@@ -187,6 +187,26 @@ impl CodeBuffer {
         self.peek_nth_char_back(0)
     }
 
+    /// Remove the last byte from the buffer and return it, if it is an ASCII byte.
+    ///
+    /// Returns `None` and leaves the buffer unchanged if it is empty or ends with a non-ASCII char.
+    ///
+    /// # Example
+    /// ```
+    /// # use oxc_data_structures::CodeBuffer;
+    /// let mut code = CodeBuffer::new();
+    /// code.print_str("foo;\n");
+    /// assert_eq!(code.pop_ascii_byte(), Some(b'\n'));
+    /// assert_eq!(code.into_string(), "foo;");
+    /// ```
+    #[inline]
+    pub fn pop_ascii_byte(&mut self) -> Option<u8> {
+        let byte = self.last_byte().filter(u8::is_ascii)?;
+        // Removing an ASCII byte keeps `buf` valid UTF-8
+        self.buf.pop();
+        Some(byte)
+    }
+
     /// Push a single ASCII byte into the buffer.
     ///
     /// # Panics
@@ -531,6 +551,16 @@ mod test {
         assert_eq!(String::from(code), s.to_string());
     }
 
+    #[test]
+    fn pop_ascii_byte() {
+        let mut code = CodeBuffer::new();
+        assert_eq!(code.pop_ascii_byte(), None);
+        code.print_str("aé\n");
+        assert_eq!(code.pop_ascii_byte(), Some(b'\n'));
+        assert_eq!(code.pop_ascii_byte(), None);
+        assert_eq!(code.into_string(), "aé");
+    }
+
     #[test]
     fn into_string() {
         let s = "Hello, world!";
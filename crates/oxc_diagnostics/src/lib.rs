@@ -58,7 +58,7 @@ use std::{
 
 pub mod reporter;
 
-pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
+pub use crate::service::{DiagnosticFilter, DiagnosticSender, DiagnosticService, DiagnosticTuple};
 
 pub type Error = miette::Error;
 pub type Severity = miette::Severity;
@@ -66,7 +66,7 @@ pub type Severity = miette::Severity;
 pub type Result<T> = std::result::Result<T, OxcDiagnostic>;
 
 use miette::{Diagnostic, SourceCode};
-pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource};
+pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource, SourceSpan};
 
 /// Describes an error or warning that occurred.
 ///
@@ -124,6 +124,14 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    pub fix: Option<OxcFix>,
+}
+
+/// A fix for a diagnostic, which replaces the source text at `span` with `content`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OxcFix {
+    pub span: SourceSpan,
+    pub content: Cow<'static, str>,
 }
 
 impl fmt::Display for OxcDiagnostic {
@@ -180,6 +188,7 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                fix: None,
             }),
         }
     }
@@ -194,6 +203,7 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                fix: None,
             }),
         }
     }
@@ -334,10 +344,81 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Add a fix which replaces the source text at `span` with `content`.
+    ///
+    /// Fixes are not rendered, but can be read by [reporters](reporter::DiagnosticReporter)
+    /// using [`OxcDiagnostic::from_error`].
+    pub fn with_fix<S: Into<SourceSpan>, T: Into<Cow<'static, str>>>(
+        mut self,
+        span: S,
+        content: T,
+    ) -> Self {
+        self.inner.fix = Some(OxcFix { span: span.into(), content: content.into() });
+        self
+    }
+
     /// Add source code to this diagnostic and convert it into an [`Error`].
     ///
     /// You should use a [`NamedSource`] if you have a file name as well as the source code.
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
-        Error::from(self).with_source_code(code)
+        Error::new(OxcDiagnosticWithSource { diagnostic: self, source_code: Box::new(code) })
+    }
+
+    /// Get the [`OxcDiagnostic`] an [`Error`] was created from, either by
+    /// [`OxcDiagnostic::with_source_code`] or by converting it with [`Error::from`].
+    pub fn from_error(error: &Error) -> Option<&Self> {
+        error
+            .downcast_ref::<OxcDiagnosticWithSource>()
+            .map(|error| &error.diagnostic)
+            .or_else(|| error.downcast_ref::<Self>())
+    }
+}
+
+/// An [`OxcDiagnostic`] with source code, see [`OxcDiagnostic::with_source_code`].
+///
+/// Unlike [`Error::with_source_code`], this allows getting the [`OxcDiagnostic`] back
+/// from the [`Error`] with [`OxcDiagnostic::from_error`].
+struct OxcDiagnosticWithSource {
+    diagnostic: OxcDiagnostic,
+    source_code: Box<dyn SourceCode + Send + Sync>,
+}
+
+impl fmt::Debug for OxcDiagnosticWithSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.diagnostic, f)
+    }
+}
+
+impl Display for OxcDiagnosticWithSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for OxcDiagnosticWithSource {}
+
+impl Diagnostic for OxcDiagnosticWithSource {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
     }
 }
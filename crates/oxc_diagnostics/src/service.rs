@@ -5,7 +5,7 @@ use std::{
 };
 
 use cow_utils::CowUtils;
-use miette::LabeledSpan;
+use miette::{LabeledSpan, SourceSpan};
 
 use crate::{
     Error, NamedSource, OxcDiagnostic, Severity,
@@ -16,6 +16,14 @@ pub type DiagnosticTuple = (PathBuf, Vec<Error>);
 pub type DiagnosticSender = mpsc::Sender<Option<DiagnosticTuple>>;
 pub type DiagnosticReceiver = mpsc::Receiver<Option<DiagnosticTuple>>;
 
+/// Selects which diagnostics received by a [`DiagnosticService`] are reported.
+///
+/// See [`DiagnosticService::with_filter`].
+pub trait DiagnosticFilter {
+    /// Returns the diagnostics of the file at `path` which should be reported.
+    fn filter(&mut self, path: &Path, diagnostics: Vec<Error>) -> Vec<Error>;
+}
+
 /// Listens for diagnostics sent over a [channel](DiagnosticSender) by some job, and
 /// formats/reports them to the user.
 ///
@@ -61,6 +69,9 @@ pub struct DiagnosticService {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Diagnostics not selected by the filter are neither counted nor reported
+    filter: Option<Box<dyn DiagnosticFilter>>,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
@@ -70,7 +81,15 @@ impl DiagnosticService {
     /// provided [`DiagnosticReporter`].
     pub fn new(reporter: Box<dyn DiagnosticReporter>) -> Self {
         let (sender, receiver) = mpsc::channel();
-        Self { reporter, quiet: false, silent: false, max_warnings: None, sender, receiver }
+        Self {
+            reporter,
+            quiet: false,
+            silent: false,
+            max_warnings: None,
+            filter: None,
+            sender,
+            receiver,
+        }
     }
 
     /// Set to `true` to only report errors and ignore warnings.
@@ -109,6 +128,15 @@ impl DiagnosticService {
         self
     }
 
+    /// Only count and report the diagnostics selected by `filter`.
+    ///
+    /// Default: [`None`]
+    #[must_use]
+    pub fn with_filter(mut self, filter: Box<dyn DiagnosticFilter>) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
     /// Channel for sending [diagnostic messages] to the service.
     ///
     /// The service will only start processing diagnostics after [`run`](DiagnosticService::run)
@@ -143,14 +171,9 @@ impl DiagnosticService {
         let source = Arc::new(NamedSource::new(path_display, source_text.to_owned()));
         let diagnostics = diagnostics
             .into_iter()
-            .map(|diagnostic| {
-                if source_start == 0 {
-                    return diagnostic.with_source_code(Arc::clone(&source));
-                }
-
-                match &diagnostic.labels {
-                    None => diagnostic.with_source_code(Arc::clone(&source)),
-                    Some(labels) => {
+            .map(|mut diagnostic| {
+                if source_start != 0 {
+                    if let Some(labels) = &diagnostic.labels {
                         let new_labels = labels
                             .iter()
                             .map(|labeled_span| {
@@ -161,10 +184,14 @@ impl DiagnosticService {
                                 )
                             })
                             .collect::<Vec<_>>();
-
-                        diagnostic.with_labels(new_labels).with_source_code(Arc::clone(&source))
+                        diagnostic = diagnostic.with_labels(new_labels);
+                    }
+                    if let Some(fix) = &mut diagnostic.fix {
+                        let offset = fix.span.offset() + source_start as usize;
+                        fix.span = SourceSpan::new(offset.into(), fix.span.len());
                     }
                 }
+                diagnostic.with_source_code(Arc::clone(&source))
             })
             .collect();
         (path.to_path_buf(), diagnostics)
@@ -180,10 +207,27 @@ impl DiagnosticService {
     /// which does some more things. This is the reason why we are returning it.
     /// Let's check at first it we can easily change for the default output before removing this return.
     pub fn run(&mut self, writer: &mut dyn Write) -> DiagnosticResult {
+        let result = self.run_unfinished(writer);
+        self.finish(writer, &result);
+        result
+    }
+
+    /// Like [`run`](DiagnosticService::run), but without writing the output of
+    /// [`DiagnosticReporter::finish`], so that more output can be written before it with
+    /// [`finish`](DiagnosticService::finish).
+    ///
+    /// # Panics
+    ///
+    /// * When the writer fails to write
+    pub fn run_unfinished(&mut self, writer: &mut dyn Write) -> DiagnosticResult {
         let mut warnings_count: usize = 0;
         let mut errors_count: usize = 0;
 
         while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
+            let diagnostics = match &mut self.filter {
+                Some(filter) => filter.filter(&path, diagnostics),
+                None => diagnostics,
+            };
             for diagnostic in diagnostics {
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
@@ -232,13 +276,23 @@ impl DiagnosticService {
             }
         }
 
-        let result = DiagnosticResult::new(
+        writer.flush().or_else(Self::check_for_writer_error).unwrap();
+
+        DiagnosticResult::new(
             warnings_count,
             errors_count,
             self.max_warnings_exceeded(warnings_count),
-        );
+        )
+    }
 
-        if let Some(finish_output) = self.reporter.finish(&result) {
+    /// Write the output of [`DiagnosticReporter::finish`] after
+    /// [`run_unfinished`](DiagnosticService::run_unfinished).
+    ///
+    /// # Panics
+    ///
+    /// * When the writer fails to write
+    pub fn finish(&mut self, writer: &mut dyn Write, result: &DiagnosticResult) {
+        if let Some(finish_output) = self.reporter.finish(result) {
             writer
                 .write_all(finish_output.as_bytes())
                 .or_else(Self::check_for_writer_error)
@@ -246,8 +300,6 @@ impl DiagnosticService {
         }
 
         writer.flush().or_else(Self::check_for_writer_error).unwrap();
-
-        result
     }
 
     fn check_for_writer_error(error: std::io::Error) -> Result<(), std::io::Error> {
@@ -10,6 +10,7 @@ mod primitives;
 mod sequences;
 mod strings;
 mod structs;
+mod tokens;
 use config::{Config, ConfigJS, ConfigTS};
 use formatter::{CompactFormatter, Formatter, PrettyFormatter};
 use sequences::ESTreeSequenceSerializer;
@@ -18,6 +19,7 @@ use structs::ESTreeStructSerializer;
 pub use sequences::SequenceSerializer;
 pub use strings::JsonSafeString;
 pub use structs::{FlatStructSerializer, StructSerializer};
+pub use tokens::{ESTreeToken, ESTreeTokenRegex, ESTreeTokenType};
 
 /// Trait for types which can be serialized to ESTree.
 pub trait ESTree {
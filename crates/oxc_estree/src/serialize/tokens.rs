@@ -0,0 +1,132 @@
+use super::{ESTree, JsonSafeString, Serializer, StructSerializer};
+
+/// A token in the format of token lists produced by ESTree parsers, e.g. Espree's `tokens` option.
+///
+/// ```json
+/// { "type": "Punctuator", "value": "(", "start": 3, "end": 4 }
+/// ```
+pub struct ESTreeToken<'a> {
+    /// Token type.
+    pub r#type: ESTreeTokenType,
+    /// Source text of the token.
+    ///
+    /// For [`ESTreeTokenType::PrivateIdentifier`] tokens, this excludes the leading `#`.
+    pub value: &'a str,
+    /// Pattern and flags of [`ESTreeTokenType::RegularExpression`] tokens.
+    pub regex: Option<ESTreeTokenRegex<'a>>,
+    /// Start offset in source.
+    pub start: u32,
+    /// End offset in source.
+    pub end: u32,
+}
+
+/// `regex` field of a regular expression [`ESTreeToken`].
+pub struct ESTreeTokenRegex<'a> {
+    /// Pattern, without the enclosing `/`s.
+    pub pattern: &'a str,
+    /// Flags.
+    pub flags: &'a str,
+}
+
+/// Type of an [`ESTreeToken`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ESTreeTokenType {
+    /// `true` or `false`
+    Boolean,
+    /// Identifier, including contextual keywords e.g. `async` or `of`
+    Identifier,
+    /// Reserved word e.g. `if` or `this`
+    Keyword,
+    /// `null`
+    Null,
+    /// Number or BigInt literal
+    Numeric,
+    /// Punctuator e.g. `(` or `=>`
+    Punctuator,
+    /// Regular expression literal
+    RegularExpression,
+    /// String literal
+    String,
+    /// Part of a template literal e.g. `` `a${ `` or `` }b` ``
+    Template,
+    /// Private identifier e.g. `#x`
+    PrivateIdentifier,
+    /// Text in JSX children
+    JSXText,
+}
+
+impl ESTreeTokenType {
+    /// Get the `type` string of the token.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Boolean => "Boolean",
+            Self::Identifier => "Identifier",
+            Self::Keyword => "Keyword",
+            Self::Null => "Null",
+            Self::Numeric => "Numeric",
+            Self::Punctuator => "Punctuator",
+            Self::RegularExpression => "RegularExpression",
+            Self::String => "String",
+            Self::Template => "Template",
+            Self::PrivateIdentifier => "PrivateIdentifier",
+            Self::JSXText => "JSXText",
+        }
+    }
+}
+
+impl ESTree for ESTreeToken<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &JsonSafeString(self.r#type.as_str()));
+        state.serialize_field("value", self.value);
+        if let Some(regex) = &self.regex {
+            state.serialize_field("regex", regex);
+        }
+        state.serialize_field("start", &self.start);
+        state.serialize_field("end", &self.end);
+        state.end();
+    }
+}
+
+impl ESTree for ESTreeTokenRegex<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("pattern", self.pattern);
+        state.serialize_field("flags", &JsonSafeString(self.flags));
+        state.end();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::CompactJSSerializer;
+    use super::*;
+
+    #[test]
+    fn serialize_tokens() {
+        let tokens = [
+            ESTreeToken {
+                r#type: ESTreeTokenType::Punctuator,
+                value: "(",
+                regex: None,
+                start: 0,
+                end: 1,
+            },
+            ESTreeToken {
+                r#type: ESTreeTokenType::RegularExpression,
+                value: "/\"/g",
+                regex: Some(ESTreeTokenRegex { pattern: "\"", flags: "g" }),
+                start: 1,
+                end: 5,
+            },
+        ];
+
+        let mut serializer = CompactJSSerializer::new();
+        tokens.serialize(&mut serializer);
+        let s = serializer.into_string();
+        assert_eq!(
+            &s,
+            r#"[{"type":"Punctuator","value":"(","start":0,"end":1},{"type":"RegularExpression","value":"/\"/g","regex":{"pattern":"\"","flags":"g"},"start":1,"end":5}]"#
+        );
+    }
+}
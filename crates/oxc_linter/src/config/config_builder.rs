@@ -1,6 +1,6 @@
 use std::{
     cell::{Ref, RefCell},
-    fmt,
+    fmt, mem,
 };
 
 use itertools::Itertools;
@@ -13,8 +13,8 @@ use crate::{
     AllowWarnDeny, LintConfig, LintFilter, LintFilterKind, Oxlintrc, RuleCategory, RuleEnum,
     RuleWithSeverity,
     config::{
-        ConfigStore, ESLintRule, LintPlugins, OxlintOverrides, OxlintRules,
-        overrides::OxlintOverride,
+        ConfigSource, ConfigStore, ESLintRule, LintPlugins, OxlintOverrides, OxlintRules,
+        overrides::OxlintOverride, sources::ConfigSources,
     },
     rules::RULES,
 };
@@ -25,6 +25,7 @@ pub struct ConfigStoreBuilder {
     config: LintConfig,
     overrides: OxlintOverrides,
     cache: RulesCache,
+    sources: ConfigSources,
 }
 
 impl Default for ConfigStoreBuilder {
@@ -43,8 +44,10 @@ impl ConfigStoreBuilder {
         let rules = FxHashSet::default();
         let overrides = OxlintOverrides::default();
         let cache = RulesCache::new(config.plugins);
+        let mut sources = ConfigSources::default();
+        sources.add_plugins(config.plugins, ConfigSource::Default);
 
-        Self { rules, config, overrides, cache }
+        Self { rules, config, overrides, cache, sources }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -55,6 +58,8 @@ impl ConfigStoreBuilder {
         let config = LintConfig { plugins: LintPlugins::all(), ..LintConfig::default() };
         let overrides = OxlintOverrides::default();
         let cache = RulesCache::new(config.plugins);
+        let mut sources = ConfigSources::default();
+        sources.add_plugins(config.plugins, ConfigSource::Default);
         Self {
             rules: RULES
                 .iter()
@@ -63,6 +68,7 @@ impl ConfigStoreBuilder {
             config,
             overrides,
             cache,
+            sources,
         }
     }
 
@@ -97,20 +103,34 @@ impl ConfigStoreBuilder {
             globals,
             categories,
             rules: oxlintrc_rules,
-            overrides,
+            mut overrides,
             path,
             ignore_patterns: _,
             extends,
         } = oxlintrc;
 
+        let file_source = ConfigSource::File(path.clone());
+        for override_config in overrides.iter_mut() {
+            override_config.path = Some(path.clone());
+        }
         let config = LintConfig { plugins, settings, env, globals, path: Some(path) };
         let rules =
             if start_empty { FxHashSet::default() } else { Self::warn_correctness(plugins) };
         let cache = RulesCache::new(config.plugins);
-        let mut builder = Self { rules, config, overrides, cache };
+        let mut sources = ConfigSources::default();
+        // A config file without `plugins` enables the default plugins, which can't be told apart
+        // from listing them, so the default plugins are attributed to the defaults in that case.
+        let default_plugins = if plugins.contains(LintPlugins::default()) {
+            LintPlugins::default()
+        } else {
+            LintPlugins::empty()
+        };
+        sources.add_plugins(default_plugins, ConfigSource::Default);
+        sources.add_plugins(plugins.difference(default_plugins), file_source.clone());
+        let mut builder = Self { rules, config, overrides, cache, sources };
 
-        if !categories.is_empty() {
-            builder = builder.with_filters(categories.filters());
+        for filter in categories.filters() {
+            builder.apply_filter(filter, &file_source);
         }
 
         {
@@ -134,12 +154,19 @@ impl ConfigStoreBuilder {
                             extended_config
                                 .rules
                                 .override_rules(&mut builder.rules, &builder.cache.borrow());
+                            let extended_source = ConfigSource::File(path.clone());
+                            builder.sources.set_rules(&extended_config.rules, &extended_source);
                             // Use `ConfigStoreBuilder` to load extended config files and then apply rules from those
                             let mut extended_config_store =
                                 ConfigStoreBuilder::from_oxlintrc(true, extended_config)?;
                             let rules = std::mem::take(&mut extended_config_store.rules);
                             builder = builder.with_rules(rules);
-                            builder = builder.and_plugins(extended_config_store.plugins(), true);
+                            builder.sources.extend(
+                                mem::take(&mut extended_config_store.sources),
+                                builder.config.plugins,
+                            );
+                            let extended_plugins = extended_config_store.plugins();
+                            builder = builder.and_plugins(extended_plugins, true);
                             if !extended_config_store.overrides.is_empty() {
                                 let overrides =
                                     std::mem::take(&mut extended_config_store.overrides);
@@ -160,6 +187,7 @@ impl ConfigStoreBuilder {
 
             oxlintrc_rules.override_rules(&mut builder.rules, all_rules.as_slice());
         }
+        builder.sources.set_rules(&oxlintrc_rules, &file_source);
 
         Ok(builder)
     }
@@ -200,6 +228,13 @@ impl ConfigStoreBuilder {
         self.config.plugins
     }
 
+    /// Record that `plugins` were enabled by a command line option, like `--import-plugin`, to
+    /// show where the configuration of a file comes from.
+    pub fn with_plugins_source(mut self, plugins: LintPlugins, source: ConfigSource) -> Self {
+        self.sources.add_plugins(plugins, source);
+        self
+    }
+
     #[cfg(test)]
     pub(crate) fn with_rule(mut self, rule: RuleWithSeverity) -> Self {
         self.rules.insert(rule);
@@ -225,19 +260,29 @@ impl ConfigStoreBuilder {
     }
 
     pub fn with_filter(mut self, filter: LintFilter) -> Self {
+        let source = ConfigSource::CommandLine(filter.to_string());
+        self.apply_filter(filter, &source);
+        self
+    }
+
+    fn apply_filter(&mut self, filter: LintFilter, source: &ConfigSource) {
         let (severity, filter) = filter.into();
 
         match severity {
             AllowWarnDeny::Deny | AllowWarnDeny::Warn => match filter {
                 LintFilterKind::Category(category) => {
-                    self.upsert_where(severity, |r| r.category() == category);
+                    self.upsert_where(severity, source, |r| r.category() == category);
+                }
+                LintFilterKind::Rule(_, name) => {
+                    self.upsert_where(severity, source, |r| r.name() == name);
                 }
-                LintFilterKind::Rule(_, name) => self.upsert_where(severity, |r| r.name() == name),
                 LintFilterKind::Generic(name_or_category) => {
                     if name_or_category == "all" {
-                        self.upsert_where(severity, |r| r.category() != RuleCategory::Nursery);
+                        self.upsert_where(severity, source, |r| {
+                            r.category() != RuleCategory::Nursery
+                        });
                     } else {
-                        self.upsert_where(severity, |r| r.name() == name_or_category);
+                        self.upsert_where(severity, source, |r| r.name() == name_or_category);
                     }
                 }
             },
@@ -257,14 +302,12 @@ impl ConfigStoreBuilder {
                 }
             },
         }
-
-        self
     }
 
     /// Warn/Deny a let of rules based on some predicate. Rules already in `self.rules` get
     /// re-configured, while those that are not are added. Affects rules where `query` returns
     /// `true`.
-    fn upsert_where<F>(&mut self, severity: AllowWarnDeny, query: F)
+    fn upsert_where<F>(&mut self, severity: AllowWarnDeny, source: &ConfigSource, query: F)
     where
         F: Fn(&&RuleEnum) -> bool,
     {
@@ -272,6 +315,7 @@ impl ConfigStoreBuilder {
         // NOTE: we may want to warn users if they're configuring a rule that does not exist.
         let rules_to_configure = all_rules.iter().filter(query);
         for rule in rules_to_configure {
+            self.sources.set_filtered_rule(rule, self.rules.contains(rule), source);
             match self.rules.take(rule) {
                 Some(mut existing_rule) => {
                     existing_rule.severity = severity;
@@ -296,7 +340,7 @@ impl ConfigStoreBuilder {
             self.rules.into_iter().collect::<Vec<_>>()
         };
         rules.sort_unstable_by_key(|r| r.id());
-        Ok(ConfigStore::new(rules, self.config, self.overrides))
+        Ok(ConfigStore::new(rules, self.config, self.overrides).with_sources(self.sources))
     }
 
     /// Warn for all correctness rules in the given set of plugins.
@@ -949,6 +993,78 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_effective_config_sources() {
+        let oxlintrc: Oxlintrc = serde_json::from_str(
+            r#"
+        {
+            "extends": ["fixtures/extends_config/rules_config.json"],
+            "rules": {
+                "no-console": ["warn", { "allow": ["error"] }]
+            },
+            "overrides": [{
+                "files": ["*.test.js"],
+                "rules": { "eqeqeq": "error" }
+            }]
+        }
+        "#,
+        )
+        .unwrap();
+        let store = ConfigStoreBuilder::from_oxlintrc(false, oxlintrc)
+            .unwrap()
+            .with_filter(LintFilter::new(AllowWarnDeny::Deny, "no-empty").unwrap())
+            .build()
+            .unwrap();
+        let config = store.effective_config("a.test.js".as_ref());
+        let source = |name: &str| {
+            let rule = config.rules.iter().find(|rule| rule.rule_name == name).unwrap();
+            (rule.severity, rule.options.clone(), rule.source.clone())
+        };
+
+        let extended = ConfigSource::File("fixtures/extends_config/rules_config.json".into());
+        assert_eq!(source("no-null"), (AllowWarnDeny::Deny, None, extended));
+        assert_eq!(
+            source("no-console"),
+            (
+                AllowWarnDeny::Warn,
+                Some(serde_json::json!([{ "allow": ["error"] }])),
+                ConfigSource::File(PathBuf::new())
+            )
+        );
+        assert_eq!(
+            source("eqeqeq"),
+            (
+                AllowWarnDeny::Deny,
+                None,
+                ConfigSource::Override {
+                    path: Some(PathBuf::new()),
+                    files: vec!["*.test.js".into()]
+                }
+            )
+        );
+        assert_eq!(
+            source("no-empty"),
+            (AllowWarnDeny::Deny, None, ConfigSource::CommandLine("-D no-empty".into()))
+        );
+        assert_eq!(source("no-const-assign").2, ConfigSource::Default);
+        assert!(config.rules.iter().all(|rule| rule.rule_name != "no-debugger"));
+        assert!(config.plugins.iter().all(|(_, source)| *source == ConfigSource::Default));
+
+        let oxlintrc: Oxlintrc = serde_json::from_str(
+            r#"{ "extends": ["fixtures/extends_config/plugins/jest.json"], "plugins": ["react"] }"#,
+        )
+        .unwrap();
+        let store = ConfigStoreBuilder::from_oxlintrc(false, oxlintrc).unwrap().build().unwrap();
+        let config = store.effective_config("a.js".as_ref());
+        assert_eq!(
+            config.plugins,
+            vec![
+                ("react", ConfigSource::File(PathBuf::new())),
+                ("jest", ConfigSource::File("fixtures/extends_config/plugins/jest.json".into())),
+            ]
+        );
+    }
+
     fn config_store_from_path(path: &str) -> ConfigStore {
         ConfigStoreBuilder::from_oxlintrc(true, Oxlintrc::from_file(&PathBuf::from(path)).unwrap())
             .unwrap()
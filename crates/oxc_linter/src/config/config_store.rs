@@ -2,7 +2,9 @@ use std::{path::Path, sync::Arc};
 
 use rustc_hash::FxHashSet;
 
-use super::{LintConfig, LintPlugins, overrides::OxlintOverrides};
+use super::{
+    EffectiveConfig, LintConfig, LintPlugins, overrides::OxlintOverrides, sources::ConfigSources,
+};
 use crate::{RuleWithSeverity, rules::RULES};
 
 // TODO: support `categories` et. al. in overrides.
@@ -26,6 +28,9 @@ struct Config {
 
     /// An optional set of overrides to apply to the base state depending on the file being linted.
     overrides: OxlintOverrides,
+
+    /// Where the entries of this configuration come from.
+    sources: ConfigSources,
 }
 
 /// Resolves a lint configuration for a given file, by applying overrides based on the file's path.
@@ -44,7 +49,13 @@ impl ConfigStore {
             rules: Arc::from(base_rules.into_boxed_slice()),
             config: Arc::new(base_config),
         };
-        Self { base: Config { base, overrides } }
+        Self { base: Config { base, overrides, sources: ConfigSources::default() } }
+    }
+
+    #[must_use]
+    pub(crate) fn with_sources(mut self, sources: ConfigSources) -> Self {
+        self.base.sources = sources;
+        self
     }
 
     pub fn number_of_rules(&self) -> usize {
@@ -62,10 +73,25 @@ impl ConfigStore {
     pub(crate) fn resolve(&self, path: &Path) -> ResolvedLinterState {
         // TODO: based on the `path` provided, resolve the configuration file to use.
         let resolved_config = &self.base;
-        Self::apply_overrides(resolved_config, path)
+        Self::apply_overrides(resolved_config, path, None)
     }
 
-    fn apply_overrides(config: &Config, path: &Path) -> ResolvedLinterState {
+    /// Resolve the configuration for `path`, together with where each entry of it comes from.
+    pub fn effective_config(&self, path: &Path) -> EffectiveConfig {
+        let resolved_config = &self.base;
+        let mut sources = resolved_config.sources.clone();
+        let resolved = Self::apply_overrides(resolved_config, path, Some(&mut sources));
+        sources.effective_config(
+            resolved.rules.iter().map(|rule| (&rule.rule, rule.severity)),
+            &resolved.config,
+        )
+    }
+
+    fn apply_overrides(
+        config: &Config,
+        path: &Path,
+        mut sources: Option<&mut ConfigSources>,
+    ) -> ResolvedLinterState {
         if config.overrides.is_empty() {
             return config.base.clone();
         }
@@ -107,6 +133,10 @@ impl ConfigStore {
             .collect::<Vec<_>>();
 
         for override_config in overrides_to_apply {
+            if let Some(sources) = sources.as_deref_mut() {
+                sources.add_override(override_config, plugins);
+            }
+
             if !override_config.rules.is_empty() {
                 override_config.rules.override_rules(&mut rules, &all_rules);
             }
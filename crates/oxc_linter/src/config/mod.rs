@@ -10,6 +10,7 @@ mod oxlintrc;
 mod plugins;
 mod rules;
 mod settings;
+mod sources;
 pub use config_builder::{ConfigBuilderError, ConfigStoreBuilder};
 pub use config_store::ConfigStore;
 pub use config_store::ResolvedLinterState;
@@ -20,6 +21,7 @@ pub use oxlintrc::Oxlintrc;
 pub use plugins::LintPlugins;
 pub use rules::{ESLintRule, OxlintRules};
 pub use settings::{OxlintSettings, jsdoc::JSDocPluginSettings};
+pub use sources::{ConfigSource, EffectiveConfig, EffectiveRule};
 
 #[derive(Debug, Default, Clone)]
 pub struct LintConfig {
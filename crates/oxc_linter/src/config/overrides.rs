@@ -1,7 +1,7 @@
 use std::{
     borrow::Cow,
     ops::{Deref, DerefMut},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 use nonmax::NonMaxU32;
@@ -105,6 +105,11 @@ pub struct OxlintOverride {
 
     #[serde(default)]
     pub rules: OxlintRules,
+
+    /// Path of the config file containing this override, to show where the configuration of a
+    /// file comes from.
+    #[serde(skip)]
+    pub(crate) path: Option<PathBuf>,
 }
 
 /// A glob pattern.
@@ -143,6 +148,11 @@ impl GlobSet {
     pub fn is_match<P: AsRef<Path>>(&self, path: P) -> bool {
         self.globs.is_match(path)
     }
+
+    /// The glob patterns as written in the config file.
+    pub fn patterns(&self) -> &[String] {
+        &self.raw
+    }
 }
 
 impl Serialize for GlobSet {
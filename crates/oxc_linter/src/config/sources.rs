@@ -0,0 +1,234 @@
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::{AllowWarnDeny, LintPlugins, RuleEnum};
+
+use super::{
+    GlobalValue, LintConfig, OxlintRules, OxlintSettings, overrides::OxlintOverride,
+    rules::transform_rule_and_plugin_name,
+};
+
+/// Where an entry of the effective configuration of a file comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Enabled by default, like the default plugins and the rules in the `correctness` category
+    Default,
+    /// A config file, or a config file extended by it
+    File(PathBuf),
+    /// An entry of `overrides` in a config file, which applies to files matching `files`
+    Override { path: Option<PathBuf>, files: Vec<String> },
+    /// A command line option, like `-D correctness` or `--import-plugin`
+    CommandLine(String),
+}
+
+/// Where the configured rules, plugins, env and globals come from.
+///
+/// Env and globals which are not tracked come from the config file of [`LintConfig::path`].
+/// Rules and plugins which are not tracked are defaults.
+#[derive(Debug, Default, Clone)]
+pub struct ConfigSources {
+    /// Sources and options of rules by `(plugin name, rule name)`
+    rules: FxHashMap<(String, String), RuleSource>,
+    /// Plugins in the order they were enabled
+    plugins: Vec<(LintPlugins, ConfigSource)>,
+    env: FxHashMap<String, ConfigSource>,
+    globals: FxHashMap<String, ConfigSource>,
+}
+
+#[derive(Debug, Clone)]
+struct RuleSource {
+    source: ConfigSource,
+    options: Option<Value>,
+}
+
+/// The configuration used for linting a file, with where each entry comes from.
+///
+/// See [`crate::Linter::effective_config`].
+#[derive(Debug)]
+pub struct EffectiveConfig {
+    /// The config file which applies to the file, if any
+    pub path: Option<PathBuf>,
+    pub plugins: Vec<(&'static str, ConfigSource)>,
+    /// Enabled rules, sorted by plugin and rule name
+    pub rules: Vec<EffectiveRule>,
+    pub env: Vec<(String, ConfigSource)>,
+    pub globals: Vec<(String, GlobalValue, ConfigSource)>,
+    /// Top-level keys of the settings with their values, which only come from the config file.
+    /// Keys which are not set in the config file have their default value.
+    pub settings: Vec<(String, Value, ConfigSource)>,
+}
+
+/// An enabled rule in an [`EffectiveConfig`].
+#[derive(Debug)]
+pub struct EffectiveRule {
+    pub plugin_name: &'static str,
+    pub rule_name: &'static str,
+    pub severity: AllowWarnDeny,
+    /// Options from the config file which configured the rule last
+    pub options: Option<Value>,
+    pub source: ConfigSource,
+}
+
+impl ConfigSources {
+    /// Record `source` for the rules configured in `rules`, which replace their options.
+    pub fn set_rules(&mut self, rules: &OxlintRules, source: &ConfigSource) {
+        for rule in &rules.rules {
+            let (rule_name, plugin_name) =
+                transform_rule_and_plugin_name(&rule.rule_name, &rule.plugin_name);
+            self.rules.insert(
+                (plugin_name.to_string(), rule_name.to_string()),
+                RuleSource { source: source.clone(), options: rule.config.clone() },
+            );
+        }
+    }
+
+    /// Record `source` for a rule enabled by a filter, which keeps its options if it was enabled
+    /// before.
+    pub fn set_filtered_rule(&mut self, rule: &RuleEnum, was_enabled: bool, source: &ConfigSource) {
+        let key = (rule.plugin_name().to_string(), rule.name().to_string());
+        let options = if was_enabled {
+            self.rules.get(&key).and_then(|rule| rule.options.clone())
+        } else {
+            None
+        };
+        self.rules.insert(key, RuleSource { source: source.clone(), options });
+    }
+
+    pub fn add_plugins(&mut self, plugins: LintPlugins, source: ConfigSource) {
+        if !plugins.is_empty() {
+            self.plugins.push((plugins, source));
+        }
+    }
+
+    /// Add the sources of a config file extended by this one, where `plugins` are the plugins
+    /// enabled before extending it.
+    ///
+    /// Extended config files only contribute rules, plugins and overrides, so the env and globals
+    /// of `other` are not carried over. Env and globals of its overrides are recorded when the
+    /// overrides are applied, see [`ConfigSources::add_override`].
+    pub fn extend(&mut self, other: Self, plugins: LintPlugins) {
+        self.rules.extend(other.rules);
+        for (other_plugins, source) in other.plugins {
+            self.add_plugins(other_plugins.difference(plugins), source);
+        }
+    }
+
+    /// Record the sources of an override applied to a file, where `plugins` are the plugins
+    /// enabled before the override.
+    pub fn add_override(&mut self, override_config: &OxlintOverride, plugins: LintPlugins) {
+        let source = ConfigSource::Override {
+            path: override_config.path.clone(),
+            files: override_config.files.patterns().to_vec(),
+        };
+        self.set_rules(&override_config.rules, &source);
+        if let Some(override_plugins) = override_config.plugins {
+            self.add_plugins(override_plugins.difference(plugins), source.clone());
+        }
+        if let Some(env) = &override_config.env {
+            self.env.extend(env.iter().map(|env| (env.to_string(), source.clone())));
+        }
+        if let Some(globals) = &override_config.globals {
+            self.globals.extend(globals.keys().map(|name| (name.clone(), source.clone())));
+        }
+    }
+
+    /// Combine the resolved `rules` and `config` of a file with their sources.
+    pub fn effective_config<'a>(
+        &self,
+        rules: impl IntoIterator<Item = (&'a RuleEnum, AllowWarnDeny)>,
+        config: &LintConfig,
+    ) -> EffectiveConfig {
+        let path = config.path.as_deref().filter(|path| !path.as_os_str().is_empty());
+        let config_source =
+            path.map_or(ConfigSource::Default, |path| ConfigSource::File(path.to_path_buf()));
+
+        let plugins = config
+            .plugins
+            .iter()
+            .map(|plugin| {
+                let source = self
+                    .plugins
+                    .iter()
+                    .rev()
+                    .find(|(plugins, _)| plugins.contains(plugin))
+                    .map_or(ConfigSource::Default, |(_, source)| source.clone());
+                (<&'static str>::from(plugin), source)
+            })
+            .collect();
+
+        let mut rules = rules
+            .into_iter()
+            .map(|(rule, severity)| {
+                let key = (rule.plugin_name().to_string(), rule.name().to_string());
+                let (source, options) = self
+                    .rules
+                    .get(&key)
+                    .map_or((ConfigSource::Default, None), |RuleSource { source, options }| {
+                        (source.clone(), options.clone())
+                    });
+                EffectiveRule {
+                    plugin_name: rule.plugin_name(),
+                    rule_name: rule.name(),
+                    severity,
+                    options,
+                    source,
+                }
+            })
+            .collect::<Vec<_>>();
+        rules.sort_unstable_by_key(|rule| (rule.plugin_name, rule.rule_name));
+
+        let mut env = config
+            .env
+            .iter()
+            .map(|env| {
+                let source = self.env.get(env).unwrap_or(&config_source).clone();
+                (env.to_string(), source)
+            })
+            .collect::<Vec<_>>();
+        env.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        let mut globals = config
+            .globals
+            .iter()
+            .map(|(name, value)| {
+                let source = self.globals.get(name).unwrap_or(&config_source).clone();
+                (name.clone(), *value, source)
+            })
+            .collect::<Vec<_>>();
+        globals.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        let settings = Self::settings(&config.settings)
+            .into_iter()
+            .zip(Self::settings(&OxlintSettings::default()))
+            .map(|((key, value), (_, default_value))| {
+                let source = if value == default_value {
+                    ConfigSource::Default
+                } else {
+                    config_source.clone()
+                };
+                (key, value, source)
+            })
+            .collect();
+
+        EffectiveConfig {
+            path: path.map(Path::to_path_buf),
+            plugins,
+            rules,
+            env,
+            globals,
+            settings,
+        }
+    }
+
+    /// Top-level keys of `settings` with their values, sorted by key.
+    fn settings(settings: &OxlintSettings) -> Vec<(String, Value)> {
+        let Ok(Value::Object(settings)) = serde_json::to_value(settings) else {
+            return vec![];
+        };
+        let mut settings = settings.into_iter().collect::<Vec<_>>();
+        settings.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        settings
+    }
+}
@@ -1,5 +1,6 @@
 use std::{cell::RefCell, path::Path, rc::Rc, sync::Arc};
 
+use oxc_diagnostics::Severity;
 use oxc_semantic::Semantic;
 use oxc_span::SourceType;
 
@@ -95,7 +96,11 @@ impl<'a> ContextHost<'a> {
             module_record,
             disable_directives,
             diagnostics: RefCell::new(Vec::with_capacity(DIAGNOSTICS_INITIAL_CAPACITY)),
-            fix: options.fix,
+            fix: if options.fix.is_none() && options.report_fixes {
+                FixKind::SafeFix
+            } else {
+                options.fix
+            },
             file_path,
             config,
             frameworks: options.framework_hints,
@@ -158,6 +163,23 @@ impl<'a> ContextHost<'a> {
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
+    /// Report directive comments which did not disable any diagnostic.
+    /// See [`DisableDirectives::unused_directives`].
+    pub(crate) fn report_unused_directives<F: Fn(&str) -> bool>(
+        &self,
+        severity: Severity,
+        is_rule_enabled: F,
+    ) {
+        for directive in self.disable_directives.unused_directives(is_rule_enabled) {
+            let diagnostic = directive.diagnostic().with_severity(severity);
+            let fix = self
+                .fix
+                .can_apply(FixKind::SafeFix)
+                .then(|| directive.fix(self.semantic.source_text()));
+            self.push_diagnostic(Message::new(diagnostic, fix));
+        }
+    }
+
     /// Take ownership of all diagnostics collected during linting.
     pub fn take_diagnostics(&self) -> Vec<Message<'a>> {
         // NOTE: diagnostics are only ever borrowed here and in push_diagnostic.
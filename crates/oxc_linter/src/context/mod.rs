@@ -70,7 +70,8 @@ impl<'a> Deref for LintContext<'a> {
 
 impl<'a> LintContext<'a> {
     /// Base URL for the documentation, used to generate rule documentation URLs when a diagnostic is reported.
-    const WEBSITE_BASE_URL: &'static str = "https://oxc.rs/docs/guide/usage/linter/rules";
+    pub(crate) const WEBSITE_BASE_URL: &'static str =
+        "https://oxc.rs/docs/guide/usage/linter/rules";
 
     /// Set the plugin name for the current rule.
     pub fn with_plugin_name(mut self, plugin: &'static str) -> Self {
@@ -373,7 +374,7 @@ impl<'a> LintContext<'a> {
 /// assert_eq!(plugin_name_to_prefix("react"), "eslint-plugin-react");
 /// ```
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     PLUGIN_PREFIXES.get(plugin_name).copied().unwrap_or(plugin_name)
 }
 
@@ -1,12 +1,37 @@
+use std::cell::Cell;
+
 use oxc_ast::Comment;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
 use rustc_hash::FxHashMap;
 
+use crate::fixer::Fix;
+
+/// A disabled rule, and the index of the [`Directive`] which disabled it.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum DisabledRule<'a> {
-    All,
-    Single(&'a str),
+    All(usize),
+    Single(&'a str, usize),
+}
+
+impl DisabledRule<'_> {
+    fn directive(self) -> usize {
+        match self {
+            Self::All(directive) | Self::Single(_, directive) => directive,
+        }
+    }
+}
+
+/// A single rule, or all rules, disabled by a directive comment.
+#[derive(Debug)]
+struct Directive<'a> {
+    /// Span of the whole comment
+    comment_span: Span,
+    /// Name and span of the disabled rule, or `None` if all rules are disabled
+    rule: Option<(&'a str, Span)>,
+    /// Whether a diagnostic was disabled by this directive
+    used: Cell<bool>,
 }
 
 /// A comment which disables one or more specific rules
@@ -18,9 +43,98 @@ pub struct DisableRuleComment<'a> {
     pub rules: Vec<&'a str>,
 }
 
+/// A directive comment which did not disable any diagnostic.
+#[derive(Debug)]
+pub struct UnusedDisableDirective<'a> {
+    /// Span of the whole comment
+    pub span: Span,
+    /// Unused rules with the spans of their names, empty if the comment disables all rules
+    pub rules: Vec<(&'a str, Span)>,
+    /// Whether nothing in the comment was used, so the whole comment can be removed
+    pub is_entirely_unused: bool,
+}
+
+impl<'a> UnusedDisableDirective<'a> {
+    pub fn diagnostic(&self) -> OxcDiagnostic {
+        if self.rules.is_empty() {
+            OxcDiagnostic::warn("Unused disable directive (no problems were reported).")
+                .with_help("Remove this comment.")
+                .with_label(self.span)
+        } else {
+            let rules = self.rules.iter().map(|(name, _)| format!("'{name}'")).collect::<Vec<_>>();
+            OxcDiagnostic::warn(format!(
+                "Unused disable directive (no problems were reported from {}).",
+                rules.join(" or ")
+            ))
+            .with_help(if self.is_entirely_unused {
+                "Remove this comment."
+            } else {
+                "Remove the unused rules from this comment."
+            })
+            .with_labels(self.rules.iter().map(|(_, span)| *span))
+        }
+    }
+
+    /// A fix which removes the comment, or only the unused rules if other parts of the comment
+    /// are still used.
+    #[expect(clippy::cast_possible_truncation)] // for `as u32`
+    pub fn fix(&self, source_text: &'a str) -> Fix<'a> {
+        let is_inline_whitespace = |c: char| c == ' ' || c == '\t';
+
+        if self.is_entirely_unused {
+            // Remove the whitespace before the comment as well, and the whole line if the comment
+            // is the only thing on it.
+            let before = &source_text[..self.span.start as usize];
+            let start = before.trim_end_matches(is_inline_whitespace).len() as u32;
+            let after = &source_text[self.span.end as usize..];
+            let line_rest = after.len() - after.trim_start_matches(is_inline_whitespace).len();
+            let at_line_start = start == 0 || before[..start as usize].ends_with('\n');
+            let end = match after[line_rest..]
+                .strip_prefix("\r\n")
+                .map_or_else(|| after[line_rest..].strip_prefix('\n').map(|_| 1), |_| Some(2))
+            {
+                Some(newline) if at_line_start => self.span.end + (line_rest + newline) as u32,
+                _ => self.span.end,
+            };
+            return Fix::delete(Span::new(start, end));
+        }
+
+        // Remove each rule name together with the comma separating it from its neighbor.
+        let mut removals: Vec<Span> = vec![];
+        for (_, span) in &self.rules {
+            let after = &source_text[span.end as usize..];
+            let after_trimmed = after.trim_start();
+            let removal = if let Some(rest) = after_trimmed.strip_prefix(',') {
+                let end = source_text.len() - rest.trim_start_matches(is_inline_whitespace).len();
+                Span::new(span.start, end as u32)
+            } else {
+                let before = &source_text[..span.start as usize];
+                let before_trimmed = before.trim_end();
+                match before_trimmed.strip_suffix(',') {
+                    Some(rest) => Span::new(rest.len() as u32, span.end),
+                    None => *span,
+                }
+            };
+            match removals.last_mut() {
+                Some(last) if last.end >= removal.start => last.end = last.end.max(removal.end),
+                _ => removals.push(removal),
+            }
+        }
+
+        let span = Span::new(removals[0].start, removals[removals.len() - 1].end);
+        let mut content = String::new();
+        for pair in removals.windows(2) {
+            content.push_str(Span::new(pair[0].end, pair[1].start).source_text(source_text));
+        }
+        Fix::new(content, span)
+    }
+}
+
 pub struct DisableDirectives<'a> {
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
+    /// All rules disabled by directive comments, in source order
+    directives: Box<[Directive<'a>]>,
     /// Spans of comments that disable all rules
     disable_all_comments: Box<[Span]>,
     /// All comments that disable one or more specific rules
@@ -28,14 +142,58 @@ pub struct DisableDirectives<'a> {
 }
 
 impl<'a> DisableDirectives<'a> {
+    /// Whether `rule_name` is disabled at `span`.
+    ///
+    /// All directives which disable it are marked as used,
+    /// see [`DisableDirectives::unused_directives`].
     pub fn contains(&self, rule_name: &'static str, span: Span) -> bool {
-        self.intervals.find(span.start, span.end).any(|interval| {
-            interval.val == DisabledRule::All
+        let mut contains = false;
+        for interval in self.intervals.find(span.start, span.end) {
+            let disabled = match interval.val {
+                DisabledRule::All(_) => true,
                 // Our rule name currently does not contain the prefix.
                 // For example, this will match `@typescript-eslint/no-var-requires` given
                 // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+                DisabledRule::Single(name, _) => name.contains(rule_name),
+            };
+            if disabled {
+                self.directives[interval.val.directive()].used.set(true);
+                contains = true;
+            }
+        }
+        contains
+    }
+
+    /// Directive comments which did not disable any diagnostic, grouped by comment.
+    ///
+    /// `is_rule_enabled` is called with the rule names found in directives. Rules it returns
+    /// `false` for, such as rules which do not exist or belong to a disabled plugin, are never
+    /// reported, as there is no way to tell whether their directives are needed.
+    pub fn unused_directives<F: Fn(&str) -> bool>(
+        &self,
+        is_rule_enabled: F,
+    ) -> Vec<UnusedDisableDirective<'a>> {
+        self.directives
+            .chunk_by(|a, b| a.comment_span == b.comment_span)
+            .filter_map(|directives| {
+                let mut rules = vec![];
+                let mut is_entirely_unused = true;
+                for directive in directives {
+                    let is_unused = !directive.used.get()
+                        && directive.rule.is_none_or(|(name, _)| is_rule_enabled(name));
+                    if !is_unused {
+                        is_entirely_unused = false;
+                    } else if let Some(rule) = directive.rule {
+                        rules.push(rule);
+                    }
+                }
+                (is_entirely_unused || !rules.is_empty()).then(|| UnusedDisableDirective {
+                    span: directives[0].comment_span,
+                    rules,
+                    is_entirely_unused,
+                })
+            })
+            .collect()
     }
 
     pub fn disable_all_comments(&self) -> &[Span] {
@@ -50,10 +208,12 @@ impl<'a> DisableDirectives<'a> {
 pub struct DisableDirectivesBuilder<'a> {
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable` or `oxlint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable` or `oxlint-disable` rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    /// All rules disabled by directive comments, in source order
+    directives: Vec<Directive<'a>>,
+    /// Start and directive of `eslint-disable` or `oxlint-disable`
+    disable_all_start: Option<(u32, usize)>,
+    /// Start and directive of `eslint-disable` or `oxlint-disable` rule_name`
+    disable_start_map: FxHashMap<&'a str, (u32, usize)>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -64,6 +224,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
     pub fn new() -> Self {
         Self {
             intervals: Lapper::new(vec![]),
+            directives: vec![],
             disable_all_start: None,
             disable_start_map: FxHashMap::default(),
             disable_all_comments: vec![],
@@ -75,6 +236,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
         self.build_impl(source_text, comments);
         DisableDirectives {
             intervals: self.intervals,
+            directives: self.directives.into_boxed_slice(),
             disable_all_comments: self.disable_all_comments.into_boxed_slice(),
             disable_rule_comments: self.disable_rule_comments.into_boxed_slice(),
         }
@@ -84,6 +246,35 @@ impl<'a> DisableDirectivesBuilder<'a> {
         self.intervals.insert(Interval { start, stop, val });
     }
 
+    /// Add a directive disabling all rules, returning its index.
+    fn add_disable_all(&mut self, comment: &Comment) -> usize {
+        self.directives.push(Directive {
+            comment_span: comment.span,
+            rule: None,
+            used: Cell::new(false),
+        });
+        self.directives.len() - 1
+    }
+
+    /// Add a directive disabling `rule_name`, returning its index.
+    #[expect(clippy::cast_possible_truncation)] // for `as u32`
+    fn add_disable_rule(
+        &mut self,
+        source_text: &str,
+        comment: &Comment,
+        rule_name: &'a str,
+    ) -> usize {
+        // `rule_name` is a slice of `source_text`
+        let start = (rule_name.as_ptr() as usize - source_text.as_ptr() as usize) as u32;
+        let span = Span::sized(start, rule_name.len() as u32);
+        self.directives.push(Directive {
+            comment_span: comment.span,
+            rule: Some((rule_name, span)),
+            used: Cell::new(false),
+        });
+        self.directives.len() - 1
+    }
+
     #[expect(clippy::cast_possible_truncation)] // for `as u32`
     fn build_impl(&mut self, source_text: &'a str, comments: &[Comment]) {
         let source_len = source_text.len() as u32;
@@ -100,8 +291,9 @@ impl<'a> DisableDirectivesBuilder<'a> {
             {
                 // `eslint-disable`
                 if text.trim().is_empty() {
+                    let directive = self.add_disable_all(comment);
                     if self.disable_all_start.is_none() {
-                        self.disable_all_start = Some(span.end);
+                        self.disable_all_start = Some((span.end, directive));
                     }
                     self.disable_all_comments.push(span);
                     continue;
@@ -114,13 +306,22 @@ impl<'a> DisableDirectivesBuilder<'a> {
                         .take(2)
                         .fold(span.end, |acc, line| acc + line.len() as u32);
                     if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                        let directive = self.add_disable_all(comment);
+                        self.add_interval(span.end, stop, DisabledRule::All(directive));
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
+                            if !rule_name.is_empty() {
+                                let directive =
+                                    self.add_disable_rule(source_text, comment, rule_name);
+                                self.add_interval(
+                                    span.end,
+                                    stop,
+                                    DisabledRule::Single(rule_name, directive),
+                                );
+                            }
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -138,13 +339,22 @@ impl<'a> DisableDirectivesBuilder<'a> {
 
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                        let directive = self.add_disable_all(comment);
+                        self.add_interval(start, stop, DisabledRule::All(directive));
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
+                            if !rule_name.is_empty() {
+                                let directive =
+                                    self.add_disable_rule(source_text, comment, rule_name);
+                                self.add_interval(
+                                    start,
+                                    stop,
+                                    DisabledRule::Single(rule_name, directive),
+                                );
+                            }
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -157,7 +367,12 @@ impl<'a> DisableDirectivesBuilder<'a> {
                     // `eslint-disable rule-name1, rule-name2`
                     let mut rules = vec![];
                     Self::get_rule_names(text, |rule_name| {
-                        self.disable_start_map.entry(rule_name).or_insert(span.end);
+                        if !rule_name.is_empty() {
+                            let directive = self.add_disable_rule(source_text, comment, rule_name);
+                            self.disable_start_map
+                                .entry(rule_name)
+                                .or_insert((span.end, directive));
+                        }
                         rules.push(rule_name);
                     });
                     self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -170,14 +385,18 @@ impl<'a> DisableDirectivesBuilder<'a> {
             {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    if let Some((start, directive)) = self.disable_all_start.take() {
+                        self.add_interval(start, span.start, DisabledRule::All(directive));
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
                     Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                        if let Some((start, directive)) = self.disable_start_map.remove(rule_name) {
+                            self.add_interval(
+                                start,
+                                span.start,
+                                DisabledRule::Single(rule_name, directive),
+                            );
                         }
                     });
                 }
@@ -186,14 +405,14 @@ impl<'a> DisableDirectivesBuilder<'a> {
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        if let Some((start, directive)) = self.disable_all_start {
+            self.add_interval(start, source_len, DisabledRule::All(directive));
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (rule_name, (start, directive)) in disable_start_map {
+            self.add_interval(start, source_len, DisabledRule::Single(rule_name, directive));
         }
     }
 
@@ -604,3 +823,47 @@ semi*/
             .test();
     }
 }
+
+#[test]
+fn test_unused_directives() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source_text = "debugger; // eslint-disable-line no-debugger
+// eslint-disable-next-line no-debugger
+foo();
+/* eslint-disable-next-line no-debugger, unknown-rule, no-console */
+foo();
+foo(); // eslint-disable-line
+";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let directives = DisableDirectivesBuilder::new().build(source_text, &ret.program.comments);
+
+    assert!(directives.contains("no-debugger", Span::new(0, 9)));
+
+    let unused =
+        directives.unused_directives(|rule_name| matches!(rule_name, "no-debugger" | "no-console"));
+    let fixed = unused
+        .iter()
+        .map(|directive| {
+            let fix = directive.fix(source_text);
+            let rules = directive.rules.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            (rules, directive.is_entirely_unused, fix.span.source_text(source_text), fix.content)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fixed,
+        vec![
+            (vec!["no-debugger"], true, "// eslint-disable-next-line no-debugger\n", "".into()),
+            (
+                vec!["no-debugger", "no-console"],
+                false,
+                "no-debugger, unknown-rule, no-console",
+                "unknown-rule".into()
+            ),
+            (vec![], true, " // eslint-disable-line", "".into()),
+        ]
+    );
+}
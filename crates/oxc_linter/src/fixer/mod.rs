@@ -261,7 +261,12 @@ impl<'a> Message<'a> {
 impl From<Message<'_>> for OxcDiagnostic {
     #[inline]
     fn from(message: Message) -> Self {
-        message.error
+        match message.fix {
+            Some(fix) if !(fix.span.is_empty() && fix.content.is_empty()) => {
+                message.error.with_fix(fix.span, fix.content.into_owned())
+            }
+            _ => message.error,
+        }
     }
 }
 
@@ -16,6 +16,7 @@ mod module_record;
 mod options;
 mod rule;
 mod service;
+mod timing;
 mod utils;
 
 pub mod loader;
@@ -26,14 +27,17 @@ use std::{
     path::{Path, PathBuf},
     rc::Rc,
     sync::Arc,
+    time::Instant,
 };
 
+use oxc_diagnostics::Severity;
 use oxc_semantic::{AstNode, Semantic};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 pub use crate::{
     config::{
-        ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintPlugins, Oxlintrc,
+        ConfigBuilderError, ConfigSource, ConfigStore, ConfigStoreBuilder, ESLintRule,
+        EffectiveConfig, EffectiveRule, LintPlugins, Oxlintrc,
     },
     context::LintContext,
     fixer::FixKind,
@@ -42,7 +46,8 @@ pub use crate::{
     options::LintOptions,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
     rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleWithSeverity},
-    service::{LintService, LintServiceOptions},
+    service::{FixSummary, FixedFile, LintCache, LintService, LintServiceOptions},
+    timing::{LintTimings, PhaseTimings, RuleTiming},
 };
 use crate::{
     config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings, ResolvedLinterState},
@@ -70,11 +75,12 @@ pub struct Linter {
     // TODO(refactor): remove duplication with `config` field when nested config is
     // standardized, as we do not need to pass both at that point
     nested_configs: FxHashMap<PathBuf, ConfigStore>,
+    timings: Option<Arc<LintTimings>>,
 }
 
 impl Linter {
     pub fn new(options: LintOptions, config: ConfigStore) -> Self {
-        Self { options, config, nested_configs: FxHashMap::default() }
+        Self { options, config, nested_configs: FxHashMap::default(), timings: None }
     }
 
     // TODO(refactor); remove this when nested config is standardized
@@ -83,7 +89,7 @@ impl Linter {
         config: ConfigStore,
         nested_configs: FxHashMap<PathBuf, ConfigStore>,
     ) -> Self {
-        Self { options, config, nested_configs }
+        Self { options, config, nested_configs, timings: None }
     }
 
     /// Set the kind of auto fixes to apply.
@@ -102,6 +108,37 @@ impl Linter {
         self
     }
 
+    /// Attach safe fixes to reported diagnostics, so that reporters can output them.
+    ///
+    /// Fixes are only reported when no fixes are applied with [`Linter::with_fix`].
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.options.report_fixes = yes;
+        self
+    }
+
+    /// Report `eslint-disable` and `oxlint-disable` directive comments which do not disable any
+    /// diagnostic, with the given severity. `None` turns reporting off.
+    ///
+    /// When safe fixes are enabled, unused directives are removed.
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, severity: Option<Severity>) -> Self {
+        self.options.report_unused_directives = severity;
+        self
+    }
+
+    /// Measure the time spent in each rule and in parsing, semantic analysis and module
+    /// resolution, and add it to `timings`.
+    #[must_use]
+    pub fn with_timings(mut self, timings: Arc<LintTimings>) -> Self {
+        self.timings = Some(timings);
+        self
+    }
+
+    pub(crate) fn timings(&self) -> Option<&LintTimings> {
+        self.timings.as_deref()
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
@@ -119,18 +156,11 @@ impl Linter {
         semantic: Rc<Semantic<'a>>,
         module_record: Arc<ModuleRecord>,
     ) -> Vec<Message<'a>> {
-        // TODO(refactor): remove branch when nested config is standardized
-        let ResolvedLinterState { rules, config } = if self.nested_configs.is_empty() {
-            // Get config + rules for this file. Takes base rules and applies glob-based overrides.
-            self.config.resolve(path)
-        } else if let Some(nearest_config) = self.get_nearest_config(path) {
-            nearest_config.resolve(path)
-        } else {
-            self.config.resolve(path)
-        };
+        let ResolvedLinterState { rules, config } = self.resolve_config(path);
         let ctx_host =
             Rc::new(ContextHost::new(path, semantic, module_record, self.options, config));
 
+        let resolved_rules = &rules;
         let rules = rules
             .iter()
             .filter(|rule| rule.should_run(&ctx_host))
@@ -159,7 +189,40 @@ impl Linter {
         // don't thrash the cache too much. Feel free to tweak based on benchmarking.
         //
         // See https://github.com/oxc-project/oxc/pull/6600 for more context.
-        if semantic.stats().nodes > 200_000 {
+        if let Some(timings) = &self.timings {
+            // Rules are run one after another, so that the time spent in each of them can be measured.
+            let mut rule_timings = vec![];
+            for (rule, ref ctx) in rules {
+                let mut timing = RuleTiming::default();
+
+                let start = Instant::now();
+                rule.run_once(ctx);
+                timing.run_once = start.elapsed();
+
+                let start = Instant::now();
+                for symbol in semantic.scoping().symbol_ids() {
+                    rule.run_on_symbol(symbol, ctx);
+                }
+                timing.run_on_symbol = start.elapsed();
+
+                let start = Instant::now();
+                for node in semantic.nodes() {
+                    rule.run(node, ctx);
+                }
+                timing.run = start.elapsed();
+
+                if should_run_on_jest_node {
+                    let start = Instant::now();
+                    for jest_node in iter_possible_jest_call_node(semantic) {
+                        rule.run_on_jest_node(&jest_node, ctx);
+                    }
+                    timing.run_on_jest_node = start.elapsed();
+                }
+
+                rule_timings.push(((rule.plugin_prefix(), rule.name()), timing));
+            }
+            timings.add_rules(rule_timings);
+        } else if semantic.stats().nodes > 200_000 {
             // Collect rules into a Vec so that we can iterate over the rules multiple times
             let rules = rules.collect::<Vec<_>>();
 
@@ -206,9 +269,39 @@ impl Linter {
             }
         }
 
+        if let Some(severity) = self.options.report_unused_directives {
+            let rule_names =
+                resolved_rules.iter().map(|rule| rule.name()).collect::<FxHashSet<_>>();
+            ctx_host.report_unused_directives(severity, |name| {
+                // Directives may refer to rules with their plugin prefix, e.g. `react/jsx-key`
+                rule_names.contains(name.rsplit('/').next().unwrap_or(name))
+            });
+        }
+
         ctx_host.take_diagnostics()
     }
 
+    /// Get the rules and config to use for linting the given path.
+    pub(crate) fn resolve_config(&self, path: &Path) -> ResolvedLinterState {
+        // Get config + rules for this file. Takes base rules and applies glob-based overrides.
+        self.config_store(path).resolve(path)
+    }
+
+    /// Get the configuration used for linting the given path, with where each rule, plugin, env
+    /// and global comes from.
+    pub fn effective_config(&self, path: &Path) -> EffectiveConfig {
+        self.config_store(path).effective_config(path)
+    }
+
+    fn config_store(&self, path: &Path) -> &ConfigStore {
+        // TODO(refactor): remove branch when nested config is standardized
+        if self.nested_configs.is_empty() {
+            &self.config
+        } else {
+            self.get_nearest_config(path).unwrap_or(&self.config)
+        }
+    }
+
     /// Get the nearest config for the given path, in the following priority order:
     /// 1. config file in the same directory as the path
     /// 2. config file in the closest parent directory
@@ -10,7 +10,7 @@ use rustc_hash::FxHashMap;
 
 use oxc_semantic::Semantic;
 use oxc_span::{CompactStr, Span};
-pub use oxc_syntax::module_record::RequestedModule;
+pub use oxc_syntax::module_record::ImportPhase;
 
 /// ESM Module Record
 ///
@@ -139,6 +139,85 @@ impl<'a> From<&oxc_syntax::module_record::NameSpan<'a>> for NameSpan {
     }
 }
 
+/// [`ImportAttribute Record`](https://tc39.es/ecma262/#importattribute-record)
+///
+/// ## Examples
+///
+/// ```ts
+/// import json from "./foo.json" with { type: "json" };
+/// //                                   ^^^^ key
+/// //                                         ^^^^^^ value
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportAttributeRecord {
+    /// Key of the attribute, either an identifier or a string literal.
+    pub key: NameSpan,
+
+    /// String value of the attribute.
+    pub value: NameSpan,
+}
+
+impl<'a> From<&oxc_syntax::module_record::ImportAttributeRecord<'a>> for ImportAttributeRecord {
+    fn from(other: &oxc_syntax::module_record::ImportAttributeRecord<'a>) -> Self {
+        Self { key: NameSpan::from(&other.key), value: NameSpan::from(&other.value) }
+    }
+}
+
+/// RequestedModule
+#[derive(Debug, Clone)]
+pub struct RequestedModule {
+    /// Span of the import statement.
+    pub statement_span: Span,
+
+    /// Span
+    pub span: Span,
+
+    /// `true` if a `type` modifier was used in the import statement.
+    ///
+    /// ## Examples
+    /// ```ts
+    /// import type { foo } from "foo"; // true, `type` is on module request
+    /// import { type bar } from "bar"; // false, `type` is on specifier
+    /// import { baz } from "baz";      // false, no `type` modifier
+    /// ```
+    pub is_type: bool,
+
+    /// `true` if the module is requested by an import statement.
+    pub is_import: bool,
+
+    /// Attributes of the `with` clause.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// import json from "./foo.json" with { type: "json" };
+    /// ```
+    pub attributes: Vec<ImportAttributeRecord>,
+
+    /// Phase of the import statement. Always `None` for export statements.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// import source wasm from "./foo.wasm"; // Some(ImportPhase::Source)
+    /// import defer * as ns from "mod";      // Some(ImportPhase::Defer)
+    /// ```
+    pub phase: Option<ImportPhase>,
+}
+
+impl<'a> From<&oxc_syntax::module_record::RequestedModule<'a>> for RequestedModule {
+    fn from(other: &oxc_syntax::module_record::RequestedModule<'a>) -> Self {
+        Self {
+            statement_span: other.statement_span,
+            span: other.span,
+            is_type: other.is_type,
+            is_import: other.is_import,
+            attributes: other.attributes.iter().map(ImportAttributeRecord::from).collect(),
+            phase: other.phase,
+        }
+    }
+}
+
 /// [`ImportEntry`](https://tc39.es/ecma262/#importentry-record)
 ///
 /// ## Examples
@@ -217,6 +296,19 @@ pub struct ImportEntry {
     /// import { foo as type } from "mod";
     /// ```
     pub is_type: bool,
+
+    /// Attributes of the import statement.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// import json from "./foo.json" with { type: "json" };
+    /// //                                   ^^^^^^^^^^^^
+    /// ```
+    pub attributes: Vec<ImportAttributeRecord>,
+
+    /// Phase of the import statement. `None` for evaluation phase imports.
+    pub phase: Option<ImportPhase>,
 }
 
 impl<'a> From<&oxc_syntax::module_record::ImportEntry<'a>> for ImportEntry {
@@ -227,6 +319,8 @@ impl<'a> From<&oxc_syntax::module_record::ImportEntry<'a>> for ImportEntry {
             import_name: ImportImportName::from(&other.import_name),
             local_name: NameSpan::from(&other.local_name),
             is_type: other.is_type,
+            attributes: other.attributes.iter().map(ImportAttributeRecord::from).collect(),
+            phase: other.phase,
         }
     }
 }
@@ -470,7 +564,7 @@ impl ModuleRecord {
                 .map(|(name, requested_modules)| {
                     (
                         CompactStr::from(name.as_str()),
-                        requested_modules.iter().copied().collect::<Vec<_>>(),
+                        requested_modules.iter().map(RequestedModule::from).collect::<Vec<_>>(),
                     )
                 })
                 .collect(),
@@ -64,6 +64,18 @@ impl Default for LintFilter {
     }
 }
 
+/// Formats the filter as the command line option which creates it, e.g. `-D correctness`.
+impl fmt::Display for LintFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flag = match self.severity {
+            AllowWarnDeny::Allow => "-A",
+            AllowWarnDeny::Warn => "-W",
+            AllowWarnDeny::Deny => "-D",
+        };
+        write!(f, "{flag} {}", self.kind)
+    }
+}
+
 impl From<LintFilter> for (AllowWarnDeny, LintFilterKind) {
     fn from(val: LintFilter) -> Self {
         (val.severity, val.kind)
@@ -149,6 +161,20 @@ impl LintFilterKind {
     }
 }
 
+impl fmt::Display for LintFilterKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Generic(filter) => filter.fmt(f),
+            Self::Rule(plugin, rule) => {
+                let plugin = <&str>::from(*plugin);
+                let plugin = if plugin.is_empty() { "eslint" } else { plugin };
+                write!(f, "{plugin}/{rule}")
+            }
+            Self::Category(category) => category.fmt(f),
+        }
+    }
+}
+
 impl TryFrom<String> for LintFilterKind {
     type Error = InvalidFilterKind;
 
@@ -4,6 +4,8 @@ mod filter;
 pub use allow_warn_deny::AllowWarnDeny;
 pub use filter::{InvalidFilterKind, LintFilter, LintFilterKind};
 
+use oxc_diagnostics::Severity;
+
 use crate::{FrameworkFlags, fixer::FixKind};
 
 /// Subset of options used directly by the linter.
@@ -11,5 +13,9 @@ use crate::{FrameworkFlags, fixer::FixKind};
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct LintOptions {
     pub fix: FixKind,
+    /// Attach safe fixes to reported diagnostics when fixes are not being applied.
+    pub report_fixes: bool,
+    /// Report directive comments which do not disable any diagnostic, with this severity.
+    pub report_unused_directives: Option<Severity>,
     pub framework_hints: FrameworkFlags,
 }
@@ -1060,3 +1060,20 @@ oxc_macros::declare_all_lint_rules! {
     vitest::prefer_to_be_truthy,
     vitest::require_local_test_context_for_concurrent_snapshots,
 }
+
+impl RuleEnum {
+    /// The plugin prefix used in this rule's diagnostic codes, e.g. `eslint-plugin-react`.
+    pub fn plugin_prefix(&self) -> &'static str {
+        crate::context::plugin_name_to_prefix(self.plugin_name())
+    }
+
+    /// URL of this rule's documentation on the oxc website.
+    pub fn documentation_url(&self) -> String {
+        format!(
+            "{}/{}/{}.html",
+            crate::LintContext::WEBSITE_BASE_URL,
+            self.plugin_name(),
+            self.name()
+        )
+    }
+}
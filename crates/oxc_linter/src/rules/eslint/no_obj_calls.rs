@@ -214,3 +214,16 @@ fn test() {
 
     Tester::new(NoObjCalls::NAME, NoObjCalls::PLUGIN, pass, fail).test_and_snapshot();
 }
+
+#[test]
+fn test_script() {
+    use crate::tester::Tester;
+
+    // Block-level functions are hoisted to the var scope in non-strict mode code.
+    let pass = vec![("{ function f() {} } f();", None), ("{ function JSON() {} } JSON();", None)];
+    let fail = vec![("{ function f() {} } Math();", None)];
+
+    Tester::new(NoObjCalls::NAME, NoObjCalls::PLUGIN, pass, fail)
+        .change_rule_path_extension("cjs")
+        .test();
+}
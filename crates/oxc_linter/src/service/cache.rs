@@ -0,0 +1,390 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Write as _},
+    fs,
+    hash::Hasher,
+    io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use oxc_resolver::Resolver;
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic, Severity};
+
+use crate::{Linter, utils::read_to_string};
+
+/// A persistent cache of lint results, so that files which did not change since the last run
+/// are not linted again.
+///
+/// A cached result is reused when all of these are unchanged:
+/// - the content of the file
+/// - the configuration resolved for the file, including the linter options
+/// - the version of the linter
+/// - the content of all modules the file imports, directly or indirectly, when the import plugin
+///   is enabled
+/// - imports of these modules which could not be resolved, which still can't be resolved
+#[derive(Debug)]
+pub struct LintCache {
+    path: PathBuf,
+    version: String,
+    entries: Mutex<BTreeMap<String, CacheEntry>>,
+    /// Content hashes of files read during this run, `None` when the file can't be read.
+    file_hashes: Mutex<FxHashMap<PathBuf, Option<u64>>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    files: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CacheEntry {
+    hash: u64,
+    config: u64,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    dependencies: BTreeMap<String, u64>,
+    /// Specifiers which could not be resolved, with the directory they are resolved in
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    unresolved: BTreeSet<(String, String)>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedDiagnostic {
+    message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<CachedLabel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    help: Option<String>,
+    severity: CachedSeverity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fix: Option<CachedFix>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedLabel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    offset: usize,
+    length: usize,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    primary: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedFix {
+    offset: usize,
+    length: usize,
+    content: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CachedSeverity {
+    Advice,
+    Warning,
+    Error,
+}
+
+impl LintCache {
+    /// Load the cache stored at `path`.
+    ///
+    /// The cache starts out empty when the file does not exist, can't be parsed, or was written
+    /// by a different `version` of the linter.
+    pub fn load<P: Into<PathBuf>>(path: P, version: &str) -> Self {
+        let path = path.into();
+        let entries = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<CacheFile>(&text).ok())
+            .filter(|cache| cache.version == version)
+            .map(|cache| cache.files)
+            .unwrap_or_default();
+        Self {
+            path,
+            version: version.to_string(),
+            entries: Mutex::new(entries),
+            file_hashes: Mutex::default(),
+        }
+    }
+
+    /// Write the cache back to the path it was loaded from.
+    ///
+    /// # Errors
+    ///
+    /// When the file cannot be written.
+    ///
+    /// # Panics
+    ///
+    /// When the cache lock is poisoned.
+    pub fn save(&self) -> io::Result<()> {
+        let entries = self.entries.lock().unwrap();
+        let json = serde_json::to_string(&CacheFileRef { version: &self.version, files: &entries })
+            .expect("Failed to serialize");
+        fs::write(&self.path, json)
+    }
+
+    /// Get the cached diagnostics of `path` with the given source text, with spans relative to
+    /// the start of the file. Returns `None` when the cached result is missing or outdated.
+    ///
+    /// Unresolved imports of the cached result are resolved again with `resolver`, the result is
+    /// outdated when any of them can be resolved now.
+    pub(super) fn get(
+        &self,
+        path: &Path,
+        source_text: &str,
+        linter: &Linter,
+        resolver: Option<&Resolver>,
+    ) -> Option<Vec<OxcDiagnostic>> {
+        let hash = hash_text(source_text);
+        let config = config_hash(path, linter);
+        let (dependencies, unresolved, diagnostics) = {
+            let entries = self.entries.lock().unwrap();
+            let entry = entries.get(&Self::key(path))?;
+            if entry.hash != hash || entry.config != config {
+                return None;
+            }
+            let dependencies = entry
+                .dependencies
+                .iter()
+                .map(|(dependency, hash)| (dependency.clone(), *hash))
+                .collect::<Vec<_>>();
+            let unresolved = entry.unresolved.iter().cloned().collect::<Vec<_>>();
+            let diagnostics =
+                entry.diagnostics.iter().map(CachedDiagnostic::to_diagnostic).collect::<Vec<_>>();
+            (dependencies, unresolved, diagnostics)
+        };
+        let is_unresolved = |(directory, specifier): &(String, String)| {
+            resolver.is_some_and(|resolver| resolver.resolve(directory, specifier).is_err())
+        };
+        (dependencies
+            .iter()
+            .all(|(dependency, hash)| self.file_hash(Path::new(dependency)) == Some(*hash))
+            && unresolved.iter().all(is_unresolved))
+        .then_some(diagnostics)
+    }
+
+    /// Whether there is a cached result for `path`, which may be outdated.
+    pub(super) fn contains(&self, path: &Path) -> bool {
+        self.entries.lock().unwrap().contains_key(&Self::key(path))
+    }
+
+    /// Create an entry for `path` from the diagnostics of each source section, given with
+    /// the start offset of the section.
+    pub(super) fn entry<'d>(
+        &self,
+        path: &Path,
+        source_text: &str,
+        linter: &Linter,
+        dependencies: impl IntoIterator<Item = &'d Path>,
+        unresolved: impl IntoIterator<Item = (&'d Path, &'d str)>,
+        diagnostics: Vec<(u32, Vec<OxcDiagnostic>)>,
+    ) -> CacheEntry {
+        let dependencies = dependencies
+            .into_iter()
+            .filter_map(|dependency| Some((Self::key(dependency), self.file_hash(dependency)?)))
+            .collect();
+        let unresolved = unresolved
+            .into_iter()
+            .map(|(directory, specifier)| (Self::key(directory), specifier.to_string()))
+            .collect();
+        let diagnostics = diagnostics
+            .into_iter()
+            .flat_map(|(start, diagnostics)| {
+                diagnostics
+                    .into_iter()
+                    .map(move |diagnostic| CachedDiagnostic::new(&diagnostic, start as usize))
+            })
+            .collect();
+        CacheEntry {
+            hash: hash_text(source_text),
+            config: config_hash(path, linter),
+            dependencies,
+            unresolved,
+            diagnostics,
+        }
+    }
+
+    pub(super) fn insert(&self, path: &Path, entry: CacheEntry) {
+        self.entries.lock().unwrap().insert(Self::key(path), entry);
+    }
+
+    pub(super) fn remove(&self, path: &Path) {
+        self.entries.lock().unwrap().remove(&Self::key(path));
+    }
+
+    /// Remember the content hash of a file which was read anyway, so it is not read again.
+    pub(super) fn record_file(&self, path: &Path, source_text: &str) {
+        self.file_hashes.lock().unwrap().insert(path.to_path_buf(), Some(hash_text(source_text)));
+    }
+
+    fn file_hash(&self, path: &Path) -> Option<u64> {
+        if let Some(hash) = self.file_hashes.lock().unwrap().get(path) {
+            return *hash;
+        }
+        let hash = read_to_string(path).ok().map(|source_text| hash_text(&source_text));
+        self.file_hashes.lock().unwrap().insert(path.to_path_buf(), hash);
+        hash
+    }
+
+    fn key(path: &Path) -> String {
+        path.to_string_lossy().into_owned()
+    }
+}
+
+#[derive(Serialize)]
+struct CacheFileRef<'a> {
+    version: &'a str,
+    files: &'a BTreeMap<String, CacheEntry>,
+}
+
+fn hash_text(text: &str) -> u64 {
+    let mut hasher = FxHasher::default();
+    hasher.write(text.as_bytes());
+    hasher.finish()
+}
+
+/// Hash the linter options and the configuration resolved for `path`.
+fn config_hash(path: &Path, linter: &Linter) -> u64 {
+    struct HashWriter(FxHasher);
+
+    impl fmt::Write for HashWriter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0.write(s.as_bytes());
+            Ok(())
+        }
+    }
+
+    let mut writer = HashWriter(FxHasher::default());
+    write!(writer, "{:?}{:?}", linter.options(), linter.resolve_config(path)).unwrap();
+    writer.0.finish()
+}
+
+impl CachedDiagnostic {
+    fn new(diagnostic: &OxcDiagnostic, start: usize) -> Self {
+        let labels = diagnostic.labels.as_ref().map_or_else(Vec::new, |labels| {
+            labels
+                .iter()
+                .map(|label| CachedLabel {
+                    label: label.label().map(ToString::to_string),
+                    offset: label.offset() + start,
+                    length: label.len(),
+                    primary: label.primary(),
+                })
+                .collect()
+        });
+        Self {
+            message: diagnostic.message.to_string(),
+            labels,
+            help: diagnostic.help.as_ref().map(ToString::to_string),
+            severity: match diagnostic.severity {
+                Severity::Advice => CachedSeverity::Advice,
+                Severity::Warning => CachedSeverity::Warning,
+                Severity::Error => CachedSeverity::Error,
+            },
+            scope: diagnostic.code.scope.as_ref().map(ToString::to_string),
+            number: diagnostic.code.number.as_ref().map(ToString::to_string),
+            url: diagnostic.url.as_ref().map(ToString::to_string),
+            fix: diagnostic.fix.as_ref().map(|fix| CachedFix {
+                offset: fix.span.offset() + start,
+                length: fix.span.len(),
+                content: fix.content.to_string(),
+            }),
+        }
+    }
+
+    fn to_diagnostic(&self) -> OxcDiagnostic {
+        let mut diagnostic =
+            OxcDiagnostic::error(self.message.clone()).with_severity(match self.severity {
+                CachedSeverity::Advice => Severity::Advice,
+                CachedSeverity::Warning => Severity::Warning,
+                CachedSeverity::Error => Severity::Error,
+            });
+        if !self.labels.is_empty() {
+            diagnostic = diagnostic.with_labels(self.labels.iter().map(|label| {
+                let span = (label.offset, label.length);
+                if label.primary {
+                    LabeledSpan::new_primary_with_span(label.label.clone(), span)
+                } else {
+                    LabeledSpan::new_with_span(label.label.clone(), span)
+                }
+            }));
+        }
+        if let Some(help) = &self.help {
+            diagnostic = diagnostic.with_help(help.clone());
+        }
+        if let Some(scope) = &self.scope {
+            diagnostic = diagnostic.with_error_code_scope(scope.clone());
+        }
+        if let Some(number) = &self.number {
+            diagnostic = diagnostic.with_error_code_num(number.clone());
+        }
+        if let Some(url) = &self.url {
+            diagnostic = diagnostic.with_url(url.clone());
+        }
+        if let Some(fix) = &self.fix {
+            diagnostic = diagnostic.with_fix((fix.offset, fix.length), fix.content.clone());
+        }
+        diagnostic
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+    use oxc_span::Span;
+
+    use super::LintCache;
+    use crate::{ConfigStoreBuilder, FixKind, LintOptions, Linter};
+
+    #[test]
+    fn cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join(".oxlintcache");
+        let path = Path::new("test.js");
+        let source_text = "<script>debugger;</script>";
+        let linter =
+            Linter::new(LintOptions::default(), ConfigStoreBuilder::empty().build().unwrap());
+
+        let diagnostic = OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_error_code("eslint", "no-debugger")
+            .with_help("Remove the debugger statement")
+            .with_label(Span::new(0, 9))
+            .with_fix((0, 9), "");
+
+        let cache = LintCache::load(&cache_path, "1.0.0");
+        let entry = cache.entry(path, source_text, &linter, [], [], vec![(8, vec![diagnostic])]);
+        cache.insert(path, entry);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(&cache_path, "1.0.0");
+        let diagnostics = cache.get(path, source_text, &linter, None).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic.code.to_string(), "eslint(no-debugger)");
+        assert_eq!(diagnostic.help.as_deref(), Some("Remove the debugger statement"));
+        assert_eq!(diagnostic.labels, Some(vec![LabeledSpan::new(None, 8, 9)]));
+        assert_eq!(diagnostic.fix.as_ref().unwrap().span, (8, 9).into());
+
+        // Outdated when the source text, the linter options or the version change
+        assert!(cache.get(path, "<script>debugger</script>", &linter, None).is_none());
+        let fix_linter =
+            Linter::new(LintOptions::default(), ConfigStoreBuilder::empty().build().unwrap())
+                .with_fix(FixKind::SafeFix);
+        assert!(cache.get(path, source_text, &fix_linter, None).is_none());
+        let cache = LintCache::load(&cache_path, "1.0.1");
+        assert!(!cache.contains(path));
+    }
+}
@@ -9,8 +9,11 @@ use runtime::Runtime;
 
 use crate::Linter;
 
+mod cache;
 mod runtime;
 
+pub use cache::LintCache;
+
 pub struct LintServiceOptions {
     /// Current working directory
     cwd: Box<Path>,
@@ -22,6 +25,35 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Cache of lint results from previous runs
+    cache: Option<Arc<LintCache>>,
+
+    /// Changed paths, when only them and the paths importing them should be linted
+    changed_paths: Option<Vec<PathBuf>>,
+
+    /// Source text to lint instead of reading the paths from the file system
+    source_text: Option<String>,
+
+    /// Keep fixed files in memory instead of writing them to the file system
+    fix_dry_run: bool,
+}
+
+/// A file with fixes applied, see [`LintServiceOptions::with_fix_dry_run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedFile {
+    pub path: PathBuf,
+    pub source_text: String,
+    pub fixed_source_text: String,
+}
+
+/// Number of files which were fixed in a run, and how many passes of linting and fixing them
+/// again were needed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FixSummary {
+    pub files: usize,
+    /// The most passes needed to fix a file
+    pub passes: usize,
 }
 
 impl LintServiceOptions {
@@ -30,7 +62,16 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), paths, tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            paths,
+            tsconfig: None,
+            cross_module: false,
+            cache: None,
+            changed_paths: None,
+            source_text: None,
+            fix_dry_run: false,
+        }
     }
 
     #[inline]
@@ -55,6 +96,50 @@ impl LintServiceOptions {
         self
     }
 
+    /// Reuse results from `cache` for unchanged files, and store results of linted files in it.
+    ///
+    /// The cache is not saved by the service, call [`LintCache::save`] after the run.
+    #[inline]
+    #[must_use]
+    pub fn with_cache(mut self, cache: Arc<LintCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Only lint the `changed_paths` and the paths which import them, directly or indirectly.
+    ///
+    /// Dependents are found in the module graph, so paths other than `changed_paths` are only
+    /// linted when cross module analysis is enabled. All paths are still parsed to build the
+    /// module graph, call [`LintService::take_linted_paths`] for the paths which were linted.
+    #[inline]
+    #[must_use]
+    pub fn with_changed_paths(mut self, changed_paths: Vec<PathBuf>) -> Self {
+        self.changed_paths = Some(changed_paths);
+        self
+    }
+
+    /// Lint `source_text` as the content of the paths to lint, instead of reading them from the
+    /// file system. This is used for linting source text which is not saved to a file, like
+    /// stdin, under a virtual path which determines the source type and configuration.
+    ///
+    /// Fixes are not written to the file system, call [`LintService::take_fixed_source_text`]
+    /// after the run to get the fixed source text.
+    #[inline]
+    #[must_use]
+    pub fn with_source_text(mut self, source_text: String) -> Self {
+        self.source_text = Some(source_text);
+        self
+    }
+
+    /// Do not write fixes to the file system, call [`LintService::take_fixed_files`] after the
+    /// run to get the fixed files.
+    #[inline]
+    #[must_use]
+    pub fn with_fix_dry_run(mut self, yes: bool) -> Self {
+        self.fix_dry_run = yes;
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
@@ -87,6 +172,45 @@ impl LintService {
         tx_error.send(None).unwrap();
     }
 
+    /// Lint `paths` in the next run, instead of the paths passed to [`LintServiceOptions::new`].
+    ///
+    /// Module records of dependencies are kept from previous runs, call [`LintService::invalidate`]
+    /// with the paths which changed since then before running again.
+    pub fn set_paths(&mut self, paths: Vec<Arc<OsStr>>) {
+        self.runtime.set_paths(paths);
+    }
+
+    /// Forget the module records of `changed_paths` and of the modules which import them, so that
+    /// they are processed again in the next run.
+    ///
+    /// Returns the paths of the modules importing `changed_paths`, directly or indirectly, which
+    /// are known from previous runs when cross module analysis is enabled.
+    pub fn invalidate(&mut self, changed_paths: &[PathBuf]) -> Vec<Arc<OsStr>> {
+        self.runtime.invalidate(changed_paths)
+    }
+
+    /// Get the source text passed to [`LintServiceOptions::with_source_text`] with fixes applied,
+    /// or `None` when no fixes were applied.
+    pub fn take_fixed_source_text(&mut self) -> Option<String> {
+        self.runtime.take_fixed_source_text()
+    }
+
+    /// Get the files fixed in the last run with [`LintServiceOptions::with_fix_dry_run`], sorted
+    /// by path.
+    pub fn take_fixed_files(&mut self) -> Vec<FixedFile> {
+        self.runtime.take_fixed_files()
+    }
+
+    /// Get the number of files fixed in the last run, and the passes needed to fix them.
+    pub fn take_fix_summary(&mut self) -> FixSummary {
+        self.runtime.take_fix_summary()
+    }
+
+    /// Get the paths linted in the last run, including the paths reported from the cache.
+    pub fn take_linted_paths(&mut self) -> Vec<Arc<OsStr>> {
+        self.runtime.take_linted_paths()
+    }
+
     /// For tests
     #[cfg(test)]
     pub(crate) fn run_source<'a>(
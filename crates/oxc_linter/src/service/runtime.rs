@@ -2,10 +2,10 @@ use std::{
     borrow::Cow,
     ffi::OsStr,
     fs,
-    mem::take,
+    mem::{self, take},
     path::{Path, PathBuf},
     rc::Rc,
-    sync::{Arc, mpsc},
+    sync::{Arc, Mutex, mpsc},
 };
 
 use indexmap::IndexSet;
@@ -20,13 +20,14 @@ use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic}
 use oxc_parser::{ParseOptions, Parser};
 use oxc_resolver::Resolver;
 use oxc_semantic::{Semantic, SemanticBuilder};
-use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
+use oxc_span::{CompactStr, SourceType, Span, VALID_EXTENSIONS};
 
-use super::LintServiceOptions;
+use super::{FixSummary, FixedFile, LintCache, LintServiceOptions};
 use crate::{
     Fixer, Linter, Message,
     loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXT, PartialLoader},
     module_record::ModuleRecord,
+    timing::Phase,
     utils::read_to_string,
 };
 
@@ -36,11 +37,30 @@ pub struct Runtime {
     paths: IndexSet<Arc<OsStr>, FxBuildHasher>,
     pub(super) linter: Linter,
     resolver: Option<Resolver>,
-
-    #[cfg(test)]
-    pub(super) test_source: std::sync::RwLock<Option<String>>,
+    cache: Option<Arc<LintCache>>,
+    /// Normalized changed paths, when only them and their dependents should be linted
+    changed_paths: Option<FxHashSet<PathBuf>>,
+    /// Source text of the paths to lint, used instead of reading them from the file system
+    source_text: Option<String>,
+    /// Source text with fixes applied, when linting `source_text`
+    fixed_source_text: Mutex<Option<String>>,
+    /// Keep fixed files in `fixed_files` instead of writing them
+    fix_dry_run: bool,
+    fixed_files: Mutex<Vec<FixedFile>>,
+    fix_summary: Mutex<FixSummary>,
+    /// Paths linted or reported from the cache in the last run
+    linted_paths: Mutex<Vec<Arc<OsStr>>>,
+    /// Module records of the module graph, kept across runs so that modules which did not change
+    /// are not processed again. Only populated when cross module analysis is enabled.
+    module_graph: Mutex<ModuleGraph>,
 }
 
+/// Maximum number of passes of linting a file and applying fixes, like ESLint
+const MAX_FIX_PASSES: usize = 10;
+
+/// Module records of sections keyed by module paths, see `ProcessedModule.section_module_records`
+type ModuleGraph = FxHashMap<Arc<OsStr>, SmallVec<[Arc<ModuleRecord>; 1]>>;
+
 /// Output of `Runtime::process_path`
 struct ModuleProcessOutput {
     /// All paths in `Runtime` are stored as `OsStr`, because `OsStr` hash is faster
@@ -102,6 +122,32 @@ struct SectionContent<'a> {
     /// None if section parsing failed. The corresponding item with the same index in
     /// `ProcessedModule.section_module_records` would be `Err(Vec<OxcDiagnostic>)`.
     semantic: Option<Semantic<'a>>,
+    /// Syntax errors the parser recovered from. They are reported together with the
+    /// diagnostics of the rules, which still run on the rest of the section.
+    parse_errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> SectionContent<'a> {
+    /// Lint the section, or report the diagnostics of parsing it if it could not be parsed.
+    fn lint(
+        self,
+        linter: &Linter,
+        path: &Path,
+        record_result: Result<Arc<ModuleRecord>, Vec<OxcDiagnostic>>,
+    ) -> Vec<Message<'a>> {
+        match record_result {
+            Ok(module_record) => {
+                let mut messages = self
+                    .parse_errors
+                    .into_iter()
+                    .map(|err| Message::new(err, None))
+                    .collect::<Vec<_>>();
+                messages.extend(linter.run(path, Rc::new(self.semantic.unwrap()), module_record));
+                messages
+            }
+            Err(errors) => errors.into_iter().map(|err| Message::new(err, None)).collect(),
+        }
+    }
 }
 
 /// A module with its source text and semantic, ready to be linted.
@@ -137,8 +183,17 @@ impl Runtime {
             paths: options.paths.iter().cloned().collect(),
             linter,
             resolver,
-            #[cfg(test)]
-            test_source: std::sync::RwLock::new(None),
+            cache: options.cache,
+            changed_paths: options.changed_paths.map(|paths| {
+                paths.iter().map(|path| normalize_path(path)).collect::<FxHashSet<_>>()
+            }),
+            source_text: options.source_text,
+            fixed_source_text: Mutex::new(None),
+            fix_dry_run: options.fix_dry_run,
+            fixed_files: Mutex::default(),
+            fix_summary: Mutex::default(),
+            linted_paths: Mutex::default(),
+            module_graph: Mutex::default(),
         }
     }
 
@@ -167,7 +222,6 @@ impl Runtime {
         })
     }
 
-    #[cfg_attr(not(test), expect(clippy::unused_self))]
     fn get_source_type_and_text(
         &self,
         path: &Path,
@@ -181,11 +235,10 @@ impl Runtime {
         }
         let source_type = source_type.unwrap_or_default();
 
-        #[cfg(test)]
-        if let (true, Some(test_source)) =
-            (self.paths.contains(path.as_os_str()), &*self.test_source.read().unwrap())
+        if let (true, Some(source_text)) =
+            (self.paths.contains(path.as_os_str()), &self.source_text)
         {
-            return Some(Ok((source_type, test_source.clone())));
+            return Some(Ok((source_type, source_text.clone())));
         }
         let file_result = read_to_string(path).map_err(|e| {
             Error::new(OxcDiagnostic::error(format!(
@@ -266,11 +319,9 @@ impl Runtime {
         // The module graph keyed by module paths. It is looked up when populating `loaded_modules`.
         // The values are module records of sections (check the docs of `ProcessedModule.section_module_records`)
         // Its entries are kept across groups because modules discovered in former groups could be referenced by modules in latter groups.
-        let mut modules_by_path =
-            FxHashMap::<Arc<OsStr>, SmallVec<[Arc<ModuleRecord>; 1]>>::with_capacity_and_hasher(
-                me.paths.len(),
-                FxBuildHasher,
-            );
+        // It starts with the module graph of previous runs, which only contains modules that did not change since then.
+        let mut modules_by_path = take(&mut *me.module_graph.lock().unwrap());
+        modules_by_path.reserve(me.paths.len());
 
         // `encountered_paths` prevents duplicated processing.
         // It is a superset of keys of `modules_by_path` as it also contains paths that are queued to process.
@@ -338,7 +389,10 @@ impl Runtime {
                     };
                     for request in &record.resolved_module_requests {
                         let dep_path = &request.resolved_requested_path;
-                        if encountered_paths.insert(Arc::clone(dep_path)) {
+                        // Dependencies from previous runs are already in the module graph, unless they are linted.
+                        let is_processed =
+                            !me.paths.contains(dep_path) && modules_by_path.contains_key(dep_path);
+                        if encountered_paths.insert(Arc::clone(dep_path)) && !is_processed {
                             scope.spawn({
                                 let tx_resolve_output = tx_process_output.clone();
                                 let dep_path = Arc::clone(dep_path);
@@ -427,82 +481,416 @@ impl Runtime {
                 });
             }
         }
+
+        *me.module_graph.lock().unwrap() = modules_by_path;
     }
 
-    // clippy: the source field is checked and assumed to be less than 4GB, and
-    // we assume that the fix offset will not exceed 2GB in either direction
-    #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
     pub(super) fn run(&mut self, tx_error: &DiagnosticSender) {
+        self.report_cached_paths(tx_error);
         rayon::scope(|scope| {
             self.resolve_modules(scope, true, tx_error, |me, mut module_to_lint| {
                 module_to_lint.content.with_dependent_mut(|owner, sections| {
-                    // If there are fixes, we will accumulate all of them and write to the file at the end.
-                    // This means we do not write multiple times to the same file if there are multiple sources
-                    // in the same file (for example, multiple scripts in an `.astro` file).
-                    let mut new_source_text = Cow::from(owner.source_text.as_str());
-                    // This is used to keep track of the cumulative offset from applying fixes.
-                    // Otherwise, spans for fixes will be incorrect due to varying size of the
-                    // source code after each fix.
-                    let mut fix_offset: i32 = 0;
-
                     let path = Path::new(&module_to_lint.path);
 
+                    if !me.is_changed_or_dependent(path, &module_to_lint.section_module_records) {
+                        return;
+                    }
+                    me.linted_paths.lock().unwrap().push(Arc::clone(&module_to_lint.path));
+
+                    let dependencies = me.cache.as_ref().map(|_| {
+                        let mut dependencies =
+                            Self::dependency_paths(&module_to_lint.section_module_records);
+                        dependencies.remove(path);
+                        let unresolved =
+                            me.unresolved_requests(&module_to_lint.section_module_records);
+                        (dependencies, unresolved)
+                    });
+                    // Module records are needed to lint the fixed source text again
+                    let module_records = module_to_lint
+                        .section_module_records
+                        .iter()
+                        .map(|record| record.as_ref().ok().map(Arc::clone))
+                        .collect::<SmallVec<[_; 1]>>();
+                    let source_type = sections.first().map(|section| section.source.source_type);
+
                     assert_eq!(module_to_lint.section_module_records.len(), sections.len());
-                    for (record_result, section) in
-                        module_to_lint.section_module_records.into_iter().zip(sections.drain(..))
+                    let fix = me.linter.options().fix.is_some();
+                    let (diagnostics, fixed_source_text) = me.lint_sections(
+                        path,
+                        &owner.source_text,
+                        module_to_lint.section_module_records.into_iter().zip(sections.drain(..)),
+                        fix,
+                    );
+
+                    let FixPasses { source_text, result: diagnostics, passes, oscillating } =
+                        fix_in_passes(
+                            &owner.source_text,
+                            (diagnostics, fixed_source_text),
+                            |source_text, fix| {
+                                me.lint_fixed_source_text(
+                                    path,
+                                    source_text,
+                                    source_type,
+                                    &module_records,
+                                    fix,
+                                )
+                            },
+                        );
+
+                    if let (Some(cache), Some((dependencies, unresolved))) =
+                        (&me.cache, dependencies)
                     {
-                        let mut messages = match record_result {
-                            Ok(module_record) => me.linter.run(
+                        // Results of fixed files are outdated, they need to be linted again.
+                        if matches!(source_text, Cow::Borrowed(_)) {
+                            let entry = cache.entry(
                                 path,
-                                Rc::new(section.semantic.unwrap()),
-                                Arc::clone(&module_record),
-                            ),
-                            Err(errors) => {
-                                errors.into_iter().map(|err| Message::new(err, None)).collect()
-                            }
-                        };
-
-                        let source_text = section.source.source_text;
-                        if me.linter.options().fix.is_some() {
-                            let fix_result = Fixer::new(source_text, messages).fix();
-                            if fix_result.fixed {
-                                // write to file, replacing only the changed part
-                                let start =
-                                    section.source.start.saturating_add_signed(fix_offset) as usize;
-                                let end = start + source_text.len();
-                                new_source_text
-                                    .to_mut()
-                                    .replace_range(start..end, &fix_result.fixed_code);
-                                let old_code_len = source_text.len() as u32;
-                                let new_code_len = fix_result.fixed_code.len() as u32;
-                                fix_offset += new_code_len as i32;
-                                fix_offset -= old_code_len as i32;
-                            }
-                            messages = fix_result.messages;
+                                &owner.source_text,
+                                &me.linter,
+                                dependencies.iter().map(PathBuf::as_path),
+                                unresolved.iter().map(|(directory, specifier)| {
+                                    (directory.as_path(), specifier.as_str())
+                                }),
+                                diagnostics.clone(),
+                            );
+                            cache.insert(path, entry);
+                        } else {
+                            cache.remove(path);
                         }
+                    }
 
-                        if !messages.is_empty() {
-                            let errors = messages.into_iter().map(Into::into).collect();
-                            let path = path.strip_prefix(&me.cwd).unwrap_or(path);
+                    let display_path = path.strip_prefix(&me.cwd).unwrap_or(path);
+                    for (section_start, errors) in diagnostics {
+                        if !errors.is_empty() {
                             let diagnostics = DiagnosticService::wrap_diagnostics(
-                                path,
-                                &owner.source_text,
-                                section.source.start,
+                                display_path,
+                                &source_text,
+                                section_start,
                                 errors,
                             );
                             tx_error.send(Some(diagnostics)).unwrap();
                         }
                     }
+                    if oscillating {
+                        let error = OxcDiagnostic::warn(format!(
+                            "Stopped fixing after {passes} passes, because fixes undo each other."
+                        ))
+                        .with_help("Fix the remaining problems manually.")
+                        .with_label(Span::empty(0));
+                        let diagnostics = DiagnosticService::wrap_diagnostics(
+                            display_path,
+                            &source_text,
+                            0,
+                            vec![error],
+                        );
+                        tx_error.send(Some(diagnostics)).unwrap();
+                    }
+
                     // If the new source text is owned, that means it was modified,
                     // so we write the new source text to the file.
-                    if let Cow::Owned(new_source_text) = new_source_text {
-                        fs::write(path, new_source_text).unwrap();
+                    if let Cow::Owned(new_source_text) = source_text {
+                        {
+                            let mut summary = me.fix_summary.lock().unwrap();
+                            summary.files += 1;
+                            summary.passes = summary.passes.max(passes);
+                        }
+                        if me.source_text.is_some() {
+                            *me.fixed_source_text.lock().unwrap() = Some(new_source_text);
+                        } else if me.fix_dry_run {
+                            me.fixed_files.lock().unwrap().push(FixedFile {
+                                path: path.to_path_buf(),
+                                source_text: owner.source_text.clone(),
+                                fixed_source_text: new_source_text,
+                            });
+                        } else {
+                            fs::write(path, new_source_text).unwrap();
+                        }
                     }
                 });
             });
         });
     }
 
+    /// Lint the source sections of a module with `module_source_text` and their module records,
+    /// and apply fixes when `fix` is set.
+    ///
+    /// Returns the diagnostics of each section with the section start, and the source text with
+    /// fixes applied, if any fix was applied.
+    // clippy: the source field is checked and assumed to be less than 4GB, and
+    // we assume that the fix offset will not exceed 2GB in either direction
+    #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    fn lint_sections<'a>(
+        &self,
+        path: &Path,
+        module_source_text: &str,
+        sections: impl IntoIterator<
+            Item = (Result<Arc<ModuleRecord>, Vec<OxcDiagnostic>>, SectionContent<'a>),
+        >,
+        fix: bool,
+    ) -> (Vec<(u32, Vec<OxcDiagnostic>)>, Option<String>) {
+        // If there are fixes, we will accumulate all of them and write to the file at the end.
+        // This means we do not write multiple times to the same file if there are multiple sources
+        // in the same file (for example, multiple scripts in an `.astro` file).
+        let mut new_source_text: Option<String> = None;
+        // This is used to keep track of the cumulative offset from applying fixes.
+        // Otherwise, spans for fixes will be incorrect due to varying size of the
+        // source code after each fix.
+        let mut fix_offset: i32 = 0;
+        let mut diagnostics = vec![];
+
+        for (record_result, section) in sections {
+            let source = section.source;
+            // Fixes are not applied around syntax errors, where the recovered AST can be wrong.
+            let recovered = !section.parse_errors.is_empty();
+            let mut messages = section.lint(&self.linter, path, record_result);
+
+            let source_text = source.source_text;
+            if fix && !recovered {
+                let fix_result = Fixer::new(source_text, messages).fix();
+                if fix_result.fixed {
+                    // write to file, replacing only the changed part
+                    let start = source.start.saturating_add_signed(fix_offset) as usize;
+                    let end = start + source_text.len();
+                    new_source_text
+                        .get_or_insert_with(|| module_source_text.to_string())
+                        .replace_range(start..end, &fix_result.fixed_code);
+                    let old_code_len = source_text.len() as u32;
+                    let new_code_len = fix_result.fixed_code.len() as u32;
+                    fix_offset += new_code_len as i32;
+                    fix_offset -= old_code_len as i32;
+                }
+                messages = fix_result.messages;
+            }
+
+            diagnostics.push((source.start, messages.into_iter().map(Into::into).collect()));
+        }
+        (diagnostics, new_source_text)
+    }
+
+    /// Parse and lint `source_text` of a module after fixes were applied to it.
+    ///
+    /// Imports are resolved to the modules which `module_records` of the sections before the
+    /// fixes import.
+    fn lint_fixed_source_text(
+        &self,
+        path: &Path,
+        source_text: &str,
+        source_type: Option<SourceType>,
+        module_records: &[Option<Arc<ModuleRecord>>],
+        fix: bool,
+    ) -> (Vec<(u32, Vec<OxcDiagnostic>)>, Option<String>) {
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        let allocator = Allocator::default();
+        let mut sections = SmallVec::new();
+        let records = self.process_source(
+            path,
+            ext,
+            true,
+            source_type.unwrap_or_default(),
+            source_text,
+            &allocator,
+            Some(&mut sections),
+        );
+        let records = records.into_iter().enumerate().map(|(i, record)| {
+            record.map(|record| {
+                if let Some(Some(previous)) = module_records.get(i) {
+                    let previous = previous.loaded_modules.read().unwrap();
+                    let mut loaded_modules = record.module_record.loaded_modules.write().unwrap();
+                    for specifier in record.module_record.requested_modules.keys() {
+                        if let Some(module) = previous.get(specifier) {
+                            loaded_modules.insert(specifier.clone(), Arc::clone(module));
+                        }
+                    }
+                }
+                record.module_record
+            })
+        });
+        self.lint_sections(path, source_text, records.zip(sections), fix)
+    }
+
+    pub(super) fn take_fixed_source_text(&mut self) -> Option<String> {
+        self.fixed_source_text.get_mut().unwrap().take()
+    }
+
+    pub(super) fn take_fixed_files(&mut self) -> Vec<FixedFile> {
+        let mut fixed_files = take(self.fixed_files.get_mut().unwrap());
+        fixed_files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+        fixed_files
+    }
+
+    pub(super) fn take_fix_summary(&mut self) -> FixSummary {
+        take(self.fix_summary.get_mut().unwrap())
+    }
+
+    pub(super) fn take_linted_paths(&mut self) -> Vec<Arc<OsStr>> {
+        take(self.linted_paths.get_mut().unwrap())
+    }
+
+    pub(super) fn set_paths(&mut self, paths: Vec<Arc<OsStr>>) {
+        self.paths = paths.into_iter().collect();
+        self.changed_paths = None;
+    }
+
+    /// Remove `changed_paths` and the modules which import them, directly or indirectly, from the
+    /// module graph, and return the paths of these importing modules.
+    pub(super) fn invalidate(&mut self, changed_paths: &[PathBuf]) -> Vec<Arc<OsStr>> {
+        // Files which were created or removed change how specifiers resolve
+        if let Some(resolver) = &self.resolver {
+            resolver.clear_cache();
+        }
+
+        let cwd = &self.cwd;
+        let module_graph = self.module_graph.get_mut().unwrap();
+        let mut dependents = FxHashMap::<PathBuf, Vec<Arc<OsStr>>>::default();
+        for (path, records) in module_graph.iter() {
+            for record in records {
+                for dependency in record.loaded_modules.read().unwrap().values() {
+                    dependents
+                        .entry(normalize_path(&cwd.join(&dependency.resolved_absolute_path)))
+                        .or_default()
+                        .push(Arc::clone(path));
+                }
+            }
+        }
+
+        let mut stack =
+            changed_paths.iter().map(|path| normalize_path(&cwd.join(path))).collect::<Vec<_>>();
+        let mut invalidated_paths = stack.iter().cloned().collect::<FxHashSet<_>>();
+        let mut invalidated_dependents = vec![];
+        while let Some(path) = stack.pop() {
+            for dependent in dependents.get(&path).into_iter().flatten() {
+                let dependent_path = normalize_path(&cwd.join(Path::new(dependent)));
+                if invalidated_paths.insert(dependent_path.clone()) {
+                    invalidated_dependents.push(Arc::clone(dependent));
+                    stack.push(dependent_path);
+                }
+            }
+        }
+        module_graph.retain(|path, _| {
+            !invalidated_paths.contains(&normalize_path(&cwd.join(Path::new(path))))
+        });
+        invalidated_dependents
+    }
+
+    /// Run `f`, and add the time it took to `phase` when timings are measured.
+    fn measure<T>(&self, phase: Phase, f: impl FnOnce() -> T) -> T {
+        match self.linter.timings() {
+            Some(timings) => timings.measure(phase, f),
+            None => f(),
+        }
+    }
+
+    /// Report the cached diagnostics of paths which did not change since they were cached,
+    /// and remove them from the paths to lint.
+    fn report_cached_paths(&mut self, tx_error: &DiagnosticSender) {
+        let Some(cache) = &self.cache else {
+            return;
+        };
+        let cached_paths = self
+            .paths
+            .par_iter()
+            .filter(|path| {
+                let path = Path::new(path);
+                // Dependents of changed paths are only known after building the module graph
+                let is_changed = self
+                    .changed_paths
+                    .as_ref()
+                    .is_none_or(|changed_paths| changed_paths.contains(&normalize_path(path)));
+                if !is_changed || !cache.contains(path) {
+                    return false;
+                }
+                let Ok(source_text) = read_to_string(path) else {
+                    return false;
+                };
+                let Some(diagnostics) =
+                    cache.get(path, &source_text, &self.linter, self.resolver.as_ref())
+                else {
+                    return false;
+                };
+                if !diagnostics.is_empty() {
+                    let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+                    let diagnostics =
+                        DiagnosticService::wrap_diagnostics(path, &source_text, 0, diagnostics);
+                    tx_error.send(Some(diagnostics)).unwrap();
+                }
+                true
+            })
+            .cloned()
+            .collect::<FxHashSet<_>>();
+        self.paths.retain(|path| !cached_paths.contains(path));
+        self.linted_paths.get_mut().unwrap().extend(cached_paths);
+    }
+
+    /// Whether the module at `path` should be linted when only changed paths and their dependents
+    /// are linted.
+    fn is_changed_or_dependent(
+        &self,
+        path: &Path,
+        module_records: &[Result<Arc<ModuleRecord>, Vec<OxcDiagnostic>>],
+    ) -> bool {
+        let Some(changed_paths) = &self.changed_paths else {
+            return true;
+        };
+        changed_paths.contains(&normalize_path(path))
+            || Self::dependency_paths(module_records)
+                .iter()
+                .any(|dependency| changed_paths.contains(&normalize_path(dependency)))
+    }
+
+    /// Paths of all modules imported by the given module records, directly or indirectly.
+    fn dependency_paths(
+        module_records: &[Result<Arc<ModuleRecord>, Vec<OxcDiagnostic>>],
+    ) -> FxHashSet<PathBuf> {
+        let mut paths = FxHashSet::default();
+        let mut stack = module_records
+            .iter()
+            .filter_map(|record| Some(Arc::clone(record.as_ref().ok()?)))
+            .collect::<Vec<_>>();
+        while let Some(record) = stack.pop() {
+            for dependency in record.loaded_modules.read().unwrap().values() {
+                if paths.insert(dependency.resolved_absolute_path.clone()) {
+                    stack.push(Arc::clone(dependency));
+                }
+            }
+        }
+        paths
+    }
+
+    /// Specifiers imported by the given module records or their dependencies which can't be
+    /// resolved, with the directory they are resolved in.
+    fn unresolved_requests(
+        &self,
+        module_records: &[Result<Arc<ModuleRecord>, Vec<OxcDiagnostic>>],
+    ) -> FxHashSet<(PathBuf, CompactStr)> {
+        let mut requests = FxHashSet::default();
+        let Some(resolver) = &self.resolver else {
+            return requests;
+        };
+        let mut visited = FxHashSet::default();
+        let mut stack = module_records
+            .iter()
+            .filter_map(|record| Some(Arc::clone(record.as_ref().ok()?)))
+            .collect::<Vec<_>>();
+        while let Some(record) = stack.pop() {
+            if !visited.insert(record.resolved_absolute_path.clone()) {
+                continue;
+            }
+            let Some(dir) = record.resolved_absolute_path.parent() else {
+                continue;
+            };
+            let loaded_modules = record.loaded_modules.read().unwrap();
+            for specifier in record.requested_modules.keys() {
+                // Resolved modules without a module record, like JSON files, are not loaded.
+                if !loaded_modules.contains_key(specifier)
+                    && resolver.resolve(dir, specifier).is_err()
+                {
+                    requests.insert((dir.to_path_buf(), specifier.clone()));
+                }
+            }
+            stack.extend(loaded_modules.values().map(Arc::clone));
+        }
+        requests
+    }
+
     #[cfg(test)]
     pub(super) fn run_source<'a>(
         &mut self,
@@ -512,9 +900,8 @@ impl Runtime {
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
         use oxc_allocator::CloneIn;
-        use std::sync::Mutex;
 
-        *self.test_source.write().unwrap() = Some(source_text.to_owned());
+        self.source_text = Some(source_text.to_owned());
 
         let messages = Mutex::new(Vec::<Message<'a>>::new());
         rayon::scope(|scope| {
@@ -525,18 +912,10 @@ impl Runtime {
                         module.section_module_records.into_iter().zip(dependent.drain(..))
                     {
                         messages.lock().unwrap().extend(
-                            match record_result {
-                                Ok(module_record) => me.linter.run(
-                                    Path::new(&module.path),
-                                    Rc::new(section.semantic.unwrap()),
-                                    Arc::clone(&module_record),
-                                ),
-                                Err(errors) => {
-                                    errors.into_iter().map(|err| Message::new(err, None)).collect()
-                                }
-                            }
-                            .into_iter()
-                            .map(|message| message.clone_in(allocator)),
+                            section
+                                .lint(&me.linter, Path::new(&module.path), record_result)
+                                .into_iter()
+                                .map(|message| message.clone_in(allocator)),
                         );
                     }
                 });
@@ -566,6 +945,9 @@ impl Runtime {
                 return ModuleProcessOutput { path, processed_module: ProcessedModule::default() };
             }
         };
+        if let (Some(cache), Some(_)) = (&self.cache, &self.resolver) {
+            cache.record_file(Path::new(&path), &source_text);
+        }
         let mut records = SmallVec::<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]>::new();
         let mut module_content: Option<ModuleContent> = None;
         let allocator = Allocator::default();
@@ -622,6 +1004,8 @@ impl Runtime {
         let mut section_module_records = SmallVec::<
             [Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1],
         >::with_capacity(section_sources.len());
+        // Only module records are needed for files which are not linted themselves
+        let module_scan = out_sections.is_none();
         for section_source in section_sources {
             match self.process_source_section(
                 path,
@@ -629,20 +1013,26 @@ impl Runtime {
                 section_source.source_text,
                 section_source.source_type,
                 check_syntax_errors,
+                module_scan,
             ) {
-                Ok((record, semantic)) => {
+                Ok((record, semantic, parse_errors)) => {
                     section_module_records.push(Ok(record));
                     if let Some(sections) = &mut out_sections {
                         sections.push(SectionContent {
                             source: section_source,
                             semantic: Some(semantic),
+                            parse_errors,
                         });
                     }
                 }
                 Err(err) => {
                     section_module_records.push(Err(err));
                     if let Some(sections) = &mut out_sections {
-                        sections.push(SectionContent { source: section_source, semantic: None });
+                        sections.push(SectionContent {
+                            source: section_source,
+                            semantic: None,
+                            parse_errors: vec![],
+                        });
                     }
                 }
             }
@@ -657,25 +1047,36 @@ impl Runtime {
         source_text: &'a str,
         source_type: SourceType,
         check_syntax_errors: bool,
-    ) -> Result<(ResolvedModuleRecord, Semantic<'a>), Vec<OxcDiagnostic>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .with_options(ParseOptions {
-                parse_regular_expression: true,
-                allow_return_outside_function: true,
-                ..ParseOptions::default()
-            })
-            .parse();
+        module_scan: bool,
+    ) -> Result<(ResolvedModuleRecord, Semantic<'a>, Vec<OxcDiagnostic>), Vec<OxcDiagnostic>> {
+        let ret = self.measure(Phase::Parse, || {
+            Parser::new(allocator, source_text, source_type)
+                .with_options(ParseOptions {
+                    parse_regular_expression: true,
+                    allow_return_outside_function: true,
+                    module_scan,
+                    recover: true,
+                    ..ParseOptions::default()
+                })
+                .parse()
+        });
 
-        if !ret.errors.is_empty() {
-            return Err(if ret.is_flow_language { vec![] } else { ret.errors });
+        if ret.is_flow_language && !ret.errors.is_empty() {
+            return Err(vec![]);
+        }
+        // Semantic and the rules still run on a program recovered from syntax errors
+        if ret.panicked {
+            return Err(ret.errors);
         };
 
-        let semantic_ret = SemanticBuilder::new()
-            .with_cfg(true)
-            .with_scope_tree_child_ids(true)
-            .with_build_jsdoc(true)
-            .with_check_syntax_error(check_syntax_errors)
-            .build(allocator.alloc(ret.program));
+        let semantic_ret = self.measure(Phase::Semantic, || {
+            SemanticBuilder::new()
+                .with_cfg(true)
+                .with_scope_tree_child_ids(true)
+                .with_build_jsdoc(true)
+                .with_check_syntax_error(check_syntax_errors)
+                .build(allocator.alloc(ret.program))
+        });
 
         if !semantic_ret.errors.is_empty() {
             return Err(semantic_ret.errors);
@@ -692,18 +1093,173 @@ impl Runtime {
         if let Some(resolver) = &self.resolver {
             // Retrieve all dependent modules from this module.
             let dir = path.parent().unwrap();
-            resolved_module_requests = module_record
-                .requested_modules
-                .keys()
-                .filter_map(|specifier| {
-                    let resolution = resolver.resolve(dir, specifier).ok()?;
-                    Some(ResolvedModuleRequest {
-                        specifier: specifier.clone(),
-                        resolved_requested_path: Arc::<OsStr>::from(resolution.path().as_os_str()),
+            resolved_module_requests = self.measure(Phase::ModuleResolution, || {
+                module_record
+                    .requested_modules
+                    .keys()
+                    .filter_map(|specifier| {
+                        let resolution = resolver.resolve(dir, specifier).ok()?;
+                        Some(ResolvedModuleRequest {
+                            specifier: specifier.clone(),
+                            resolved_requested_path: Arc::<OsStr>::from(
+                                resolution.path().as_os_str(),
+                            ),
+                        })
                     })
-                })
-                .collect();
+                    .collect()
+            });
         }
-        Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic))
+        Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic, ret.errors))
+    }
+}
+
+/// Output of [`fix_in_passes`]
+struct FixPasses<'a, T> {
+    /// Source text with fixes of all passes applied
+    source_text: Cow<'a, str>,
+    /// Result of linting `source_text`
+    result: T,
+    /// Number of passes which applied fixes
+    passes: usize,
+    /// Whether fixing stopped because fixes undo each other
+    oscillating: bool,
+}
+
+/// Lint and fix `source_text` again and again, since fixes can overlap or enable other fixes,
+/// until no more fixes apply or [`MAX_FIX_PASSES`] passes were made.
+///
+/// `first` is the result of linting `source_text` and the source text with fixes applied, if any.
+/// `lint` lints and fixes source text the same way, but only applies fixes when its second
+/// argument is set. Fixes which undo each other are detected by the fixed source text being the
+/// same as the source text of an earlier pass.
+fn fix_in_passes<T>(
+    source_text: &str,
+    first: (T, Option<String>),
+    mut lint: impl FnMut(&str, bool) -> (T, Option<String>),
+) -> FixPasses<'_, T> {
+    let mut source_text = Cow::Borrowed(source_text);
+    let (mut result, mut fixed_source_text) = first;
+    // Source texts of the earlier passes, compared in full so that hash collisions can not stop fixing
+    let mut previous_source_texts = vec![];
+    let mut passes = 0;
+    while let Some(fixed) = fixed_source_text {
+        if *source_text == *fixed || previous_source_texts.iter().any(|text| *text == *fixed) {
+            return FixPasses { source_text, result, passes, oscillating: true };
+        }
+        passes += 1;
+        previous_source_texts.push(mem::replace(&mut source_text, Cow::Owned(fixed)));
+        (result, fixed_source_text) = lint(&source_text, passes < MAX_FIX_PASSES);
+    }
+    FixPasses { source_text, result, passes, oscillating: false }
+}
+
+/// Remove `.` components, so that paths from the command line can be compared with resolved paths.
+fn normalize_path(path: &Path) -> PathBuf {
+    path.components().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{borrow::Cow, ffi::OsStr, fs, path::Path, sync::Arc, sync::mpsc};
+
+    use crate::{ConfigStoreBuilder, LintOptions, LintService, LintServiceOptions, Linter};
+
+    #[test]
+    fn fix_in_passes() {
+        // Each pass removes one `a`
+        let fix = |source_text: &str, fix: bool| {
+            let fixed = source_text.strip_prefix('a').filter(|_| fix).map(str::to_string);
+            (source_text.len(), fixed)
+        };
+
+        let passes = super::fix_in_passes("aab", fix("aab", true), fix);
+        assert_eq!((passes.source_text.as_ref(), passes.result), ("b", 1));
+        assert_eq!((passes.passes, passes.oscillating), (2, false));
+
+        let passes = super::fix_in_passes("b", fix("b", true), fix);
+        assert!(matches!(passes.source_text, Cow::Borrowed("b")));
+        assert_eq!((passes.passes, passes.oscillating), (0, false));
+
+        let text = "a".repeat(20);
+        let passes = super::fix_in_passes(&text, fix(&text, true), fix);
+        assert_eq!(passes.source_text, "a".repeat(10));
+        assert_eq!((passes.result, passes.passes, passes.oscillating), (10, 10, false));
+
+        // Fixes which swap `a` and `b` undo each other
+        let swap = |source_text: &str, _fix: bool| {
+            let fixed = if source_text == "a" { "b" } else { "a" };
+            ((), Some(fixed.to_string()))
+        };
+        let passes = super::fix_in_passes("a", swap("a", true), swap);
+        assert_eq!(passes.source_text, "b");
+        assert_eq!((passes.passes, passes.oscillating), (1, true));
+    }
+
+    #[test]
+    fn lint_recovered_program() {
+        let dir = tempfile::tempdir().unwrap();
+        let cwd = dir.path();
+        fs::write(cwd.join("a.js"), "let a = ;\ndebugger;\n").unwrap();
+        let path = Arc::<OsStr>::from(cwd.join("a.js").into_os_string());
+
+        let linter =
+            Linter::new(LintOptions::default(), ConfigStoreBuilder::default().build().unwrap());
+        let mut service = LintService::new(linter, LintServiceOptions::new(cwd, vec![path]));
+        let (tx_error, rx_error) = mpsc::channel();
+        service.run(&tx_error);
+        drop(tx_error);
+
+        let messages = rx_error
+            .iter()
+            .flatten()
+            .flat_map(|(_, errors)| errors)
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(messages.len(), 2, "{messages:?}");
+        assert!(messages[0].contains("Unexpected token"), "{messages:?}");
+        assert!(messages[1].contains("`debugger` statement is not allowed"), "{messages:?}");
+    }
+
+    #[test]
+    fn invalidate() {
+        let dir = tempfile::tempdir().unwrap();
+        let cwd = dir.path();
+        fs::write(cwd.join("a.js"), "import './b.js';").unwrap();
+        fs::write(cwd.join("b.js"), "import './c.js';").unwrap();
+        fs::write(cwd.join("c.js"), "export {};").unwrap();
+        fs::write(cwd.join("d.js"), "export {};").unwrap();
+        let path = |name: &str| Arc::<OsStr>::from(cwd.join(name).into_os_string());
+
+        let linter =
+            Linter::new(LintOptions::default(), ConfigStoreBuilder::empty().build().unwrap());
+        let options =
+            LintServiceOptions::new(cwd, vec![path("a.js"), path("d.js")]).with_cross_module(true);
+        let mut service = LintService::new(linter, options);
+        let (tx_error, _rx_error) = mpsc::channel();
+        service.run(&tx_error);
+
+        let module_paths = |service: &mut LintService| {
+            let mut paths = service
+                .runtime
+                .module_graph
+                .get_mut()
+                .unwrap()
+                .keys()
+                .map(|path| Path::new(path).file_name().unwrap().to_str().unwrap().to_string())
+                .collect::<Vec<_>>();
+            paths.sort();
+            paths
+        };
+        assert_eq!(module_paths(&mut service), ["a.js", "b.js", "c.js", "d.js"]);
+
+        let mut dependents = service.invalidate(&[cwd.join("c.js")]);
+        dependents.sort();
+        assert_eq!(dependents, [path("a.js"), path("b.js")]);
+        assert_eq!(module_paths(&mut service), ["d.js"]);
+
+        // Unchanged modules are kept, and the invalidated ones are processed again
+        service.set_paths(vec![path("a.js")]);
+        service.run(&tx_error);
+        assert_eq!(module_paths(&mut service), ["a.js", "b.js", "c.js", "d.js"]);
     }
 }
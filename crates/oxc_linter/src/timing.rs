@@ -0,0 +1,109 @@
+use std::{
+    ops::AddAssign,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use rustc_hash::FxHashMap;
+
+/// Time spent in each rule and in each phase of linting, summed over all files and threads.
+///
+/// Enable collecting timings with [`crate::Linter::with_timings`].
+#[derive(Debug, Default)]
+pub struct LintTimings {
+    rules: Mutex<FxHashMap<(&'static str, &'static str), RuleTiming>>,
+    phases: Mutex<PhaseTimings>,
+}
+
+/// Time spent in the callbacks of a rule.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RuleTiming {
+    pub run: Duration,
+    pub run_once: Duration,
+    pub run_on_symbol: Duration,
+    pub run_on_jest_node: Duration,
+}
+
+/// Time spent in the phases of linting files, other than running rules.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseTimings {
+    pub parse: Duration,
+    pub semantic: Duration,
+    pub module_resolution: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Phase {
+    Parse,
+    Semantic,
+    ModuleResolution,
+}
+
+impl RuleTiming {
+    pub fn total(&self) -> Duration {
+        self.run + self.run_once + self.run_on_symbol + self.run_on_jest_node
+    }
+}
+
+impl AddAssign for RuleTiming {
+    fn add_assign(&mut self, other: Self) {
+        self.run += other.run;
+        self.run_once += other.run_once;
+        self.run_on_symbol += other.run_on_symbol;
+        self.run_on_jest_node += other.run_on_jest_node;
+    }
+}
+
+impl LintTimings {
+    /// Timings of all rules which ran, as `(plugin prefix, rule name, timing)`, with the slowest
+    /// rules first.
+    ///
+    /// # Panics
+    ///
+    /// When the lock is poisoned.
+    pub fn rules(&self) -> Vec<(&'static str, &'static str, RuleTiming)> {
+        let mut rules = self
+            .rules
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(&(plugin, name), &timing)| (plugin, name, timing))
+            .collect::<Vec<_>>();
+        rules.sort_unstable_by(|a, b| {
+            b.2.total().cmp(&a.2.total()).then_with(|| (a.0, a.1).cmp(&(b.0, b.1)))
+        });
+        rules
+    }
+
+    /// # Panics
+    ///
+    /// When the lock is poisoned.
+    pub fn phases(&self) -> PhaseTimings {
+        *self.phases.lock().unwrap()
+    }
+
+    /// Add the timings of the rules which ran on one file.
+    pub(crate) fn add_rules(
+        &self,
+        timings: impl IntoIterator<Item = ((&'static str, &'static str), RuleTiming)>,
+    ) {
+        let mut rules = self.rules.lock().unwrap();
+        for (rule, timing) in timings {
+            *rules.entry(rule).or_default() += timing;
+        }
+    }
+
+    /// Run `f` and add the time it took to `phase`.
+    pub(crate) fn measure<T>(&self, phase: Phase, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+        let mut phases = self.phases.lock().unwrap();
+        match phase {
+            Phase::Parse => phases.parse += elapsed,
+            Phase::Semantic => phases.semantic += elapsed,
+            Phase::ModuleResolution => phases.module_resolution += elapsed,
+        }
+        result
+    }
+}
@@ -2,7 +2,7 @@ use std::iter;
 
 use fixedbitset::FixedBitSet;
 use itertools::Itertools;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use base54::base54;
 use oxc_allocator::{Allocator, Vec};
@@ -10,11 +10,14 @@ use oxc_ast::ast::{Declaration, Program, Statement};
 use oxc_data_structures::inline_string::InlineString;
 use oxc_index::Idx;
 use oxc_semantic::{Scoping, Semantic, SemanticBuilder, SymbolId};
-use oxc_span::Atom;
+use oxc_span::{Atom, CompactStr};
 
 pub(crate) mod base54;
+mod name_pattern;
 
-#[derive(Default, Debug, Clone, Copy)]
+pub use name_pattern::NamePattern;
+
+#[derive(Default, Debug, Clone)]
 pub struct MangleOptions {
     /// Pass true to mangle names declared in the top level scope.
     ///
@@ -26,6 +29,72 @@ pub struct MangleOptions {
     ///
     /// Uses base54 if false.
     pub debug: bool,
+
+    /// Names assigned by previous runs, similar to terser's `nameCache`.
+    ///
+    /// Top level symbols found in the cache are given their cached name,
+    /// and newly mangled top level symbols are added to the cache returned in [ManglerReturn::name_cache].
+    /// Only takes effect when `top_level` is `true`.
+    ///
+    /// Default: `None`
+    pub name_cache: Option<NameCache>,
+
+    /// Names that are never mangled, similar to terser's `mangle.reserved`.
+    ///
+    /// Symbols with a matching name keep their name, and no symbol is renamed to a matching name.
+    ///
+    /// Default: `[]`
+    pub reserved: std::vec::Vec<NamePattern>,
+
+    /// Keep function / class names, similar to terser's `mangle.keep_fnames` and `mangle.keep_classnames`.
+    pub keep_names: MangleOptionsKeepNames,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct MangleOptionsKeepNames {
+    /// Do not mangle function names.
+    ///
+    /// Default `false`
+    pub function: bool,
+
+    /// Do not mangle class names.
+    ///
+    /// Default `false`
+    pub class: bool,
+
+    /// Do not mangle function names matching any of these patterns.
+    ///
+    /// Default `[]`
+    pub function_patterns: std::vec::Vec<NamePattern>,
+
+    /// Do not mangle class names matching any of these patterns.
+    ///
+    /// Default `[]`
+    pub class_patterns: std::vec::Vec<NamePattern>,
+}
+
+impl MangleOptionsKeepNames {
+    fn is_empty(&self) -> bool {
+        !self.function
+            && !self.class
+            && self.function_patterns.is_empty()
+            && self.class_patterns.is_empty()
+    }
+}
+
+/// Mapping of original names to mangled names, shared across separately mangled files.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct NameCache {
+    /// Top level variable, function and class names.
+    pub vars: FxHashMap<CompactStr, CompactStr>,
+}
+
+pub struct ManglerReturn {
+    /// The symbol table containing the mangled symbols.
+    pub scoping: Scoping,
+
+    /// The updated name cache, if [MangleOptions::name_cache] was provided.
+    pub name_cache: Option<NameCache>,
 }
 
 type Slot = usize;
@@ -48,8 +117,9 @@ type Slot = usize;
 /// assert!(parsed.errors.is_empty());
 ///
 /// let mangled_symbols = Mangler::new()
-///     .with_options(MangleOptions { top_level: true, debug: true })
-///     .build(&parsed.program);
+///     .with_options(MangleOptions { top_level: true, debug: true, ..MangleOptions::default() })
+///     .build(&parsed.program)
+///     .scoping;
 ///
 /// let js = Codegen::new().with_symbol_table(mangled_symbols).build(&parsed.program);
 /// // this will be `const a = 1 + 2;` if debug = false
@@ -168,7 +238,7 @@ impl Mangler {
     /// Mangles the program. The resulting SymbolTable contains the mangled symbols - `program` is not modified.
     /// Pass the symbol table to oxc_codegen to generate the mangled code.
     #[must_use]
-    pub fn build(self, program: &Program<'_>) -> Scoping {
+    pub fn build(self, program: &Program<'_>) -> ManglerReturn {
         let semantic =
             SemanticBuilder::new().with_scope_tree_child_ids(true).build(program).semantic;
         self.build_with_semantic(semantic, program)
@@ -178,7 +248,11 @@ impl Mangler {
     ///
     /// Panics if the child_ids does not exist in scope_tree.
     #[must_use]
-    pub fn build_with_semantic(self, semantic: Semantic<'_>, program: &Program<'_>) -> Scoping {
+    pub fn build_with_semantic(
+        self,
+        semantic: Semantic<'_>,
+        program: &Program<'_>,
+    ) -> ManglerReturn {
         if self.options.debug {
             self.build_with_semantic_impl(semantic, program, debug_name)
         } else {
@@ -191,14 +265,16 @@ impl Mangler {
         semantic: Semantic<'_>,
         program: &Program<'_>,
         generate_name: G,
-    ) -> Scoping {
+    ) -> ManglerReturn {
         let (mut scoping, ast_nodes) = semantic.into_scoping_and_nodes();
 
         assert!(scoping.has_scope_child_ids(), "child_id needs to be generated");
 
+        let mut name_cache = self.options.name_cache.clone();
+
         // TODO: implement opt-out of direct-eval in a branch of scopes.
         if scoping.root_scope_flags().contains_direct_eval() {
-            return scoping;
+            return ManglerReturn { scoping, name_cache };
         }
 
         let (exported_names, exported_symbols) = if self.options.top_level {
@@ -279,38 +355,105 @@ impl Mangler {
 
         let total_number_of_slots = slot_liveness.len();
 
+        let root_unresolved_references = scoping.root_unresolved_references();
+        let root_bindings = scoping.get_bindings(scoping.root_scope_id());
+
+        // Names of symbols that are not mangled because of `reserved` or `keep_names`.
+        let mut kept_names: FxHashSet<&str> = FxHashSet::default();
+        if !self.options.reserved.is_empty() || !self.options.keep_names.is_empty() {
+            kept_names.extend(
+                scoping
+                    .symbol_ids()
+                    .filter(|&symbol_id| self.is_kept_symbol(&scoping, symbol_id))
+                    .map(|symbol_id| scoping.symbol_name(symbol_id)),
+            );
+        }
+
+        let is_valid_name = |n: &str| {
+            // Do not mangle keywords and unresolved references
+            !is_keyword(n)
+                && !is_special_name(n)
+                && !root_unresolved_references.contains_key(n)
+                && !(root_bindings.contains_key(n)
+                    && (!self.options.top_level || exported_names.contains(n)))
+                // Do not shadow or clash with names that are kept as is
+                && !kept_names.contains(n)
+                && !NamePattern::matches_any(&self.options.reserved, n)
+        };
+
+        // Slots with a name taken from the name cache. Keyed by slot number.
+        let mut cached_slot_names: FxHashMap<Slot, &str> = FxHashMap::default();
+        // Names that must not be generated because they are used by the name cache.
+        let mut cached_names: FxHashSet<&str> = FxHashSet::default();
+        if let Some(name_cache) = &self.options.name_cache {
+            cached_names.extend(name_cache.vars.values().map(CompactStr::as_str));
+            if self.options.top_level {
+                let mut used_names = FxHashSet::default();
+                for (name, &symbol_id) in root_bindings {
+                    if exported_symbols.contains(&symbol_id)
+                        || is_special_name(name)
+                        || self.is_kept_symbol(&scoping, symbol_id)
+                    {
+                        continue;
+                    }
+                    let Some(cached_name) = name_cache.vars.get(*name) else { continue };
+                    let cached_name = cached_name.as_str();
+                    if is_valid_name(cached_name) && used_names.insert(cached_name) {
+                        cached_slot_names.insert(slots[symbol_id.index()], cached_name);
+                    }
+                }
+            }
+        }
+
         let frequencies = self.tally_slot_frequencies(
             &scoping,
             &exported_symbols,
             total_number_of_slots,
             &slots,
+            &cached_slot_names,
             &allocator,
         );
 
-        let root_unresolved_references = scoping.root_unresolved_references();
-        let root_bindings = scoping.get_bindings(scoping.root_scope_id());
-
-        let mut reserved_names = Vec::with_capacity_in(total_number_of_slots, &allocator);
+        let mut reserved_names = Vec::with_capacity_in(frequencies.len(), &allocator);
 
         let mut count = 0;
-        for _ in 0..total_number_of_slots {
+        for _ in 0..frequencies.len() {
             let name = loop {
                 let name = generate_name(count);
                 count += 1;
-                // Do not mangle keywords and unresolved references
                 let n = name.as_str();
-                if !is_keyword(n)
-                    && !is_special_name(n)
-                    && !root_unresolved_references.contains_key(n)
-                    && !(root_bindings.contains_key(n)
-                        && (!self.options.top_level || exported_names.contains(n)))
-                {
+                if is_valid_name(n) && !cached_names.contains(n) {
                     break name;
                 }
             };
             reserved_names.push(name);
         }
 
+        // Top level symbols to record in the name cache, with their original names.
+        let root_symbols_to_cache = if name_cache.is_some() && self.options.top_level {
+            root_bindings
+                .iter()
+                .filter(|(name, symbol_id)| {
+                    !exported_symbols.contains(symbol_id)
+                        && !is_special_name(name)
+                        && !self.is_kept_symbol(&scoping, **symbol_id)
+                })
+                .map(|(name, &symbol_id)| (symbol_id, CompactStr::from(*name)))
+                .collect::<std::vec::Vec<_>>()
+        } else {
+            vec![]
+        };
+
+        // Symbols in cached slots keep the name from the cache.
+        for (symbol_id, slot) in slots.iter().copied().enumerate() {
+            let symbol_id = SymbolId::from_usize(symbol_id);
+            if let Some(cached_name) = cached_slot_names.get(&slot) {
+                if self.is_mangled_symbol(&scoping, &exported_symbols, symbol_id) {
+                    scoping.set_symbol_name(symbol_id, cached_name);
+                }
+            }
+        }
+
         // Group similar symbols for smaller gzipped file
         // <https://github.com/google/closure-compiler/blob/c383a3a1d2fce33b6c778ef76b5a626e07abca41/src/com/google/javascript/jscomp/RenameVars.java#L475-L483>
         // Original Comment:
@@ -361,7 +504,47 @@ impl Mangler {
             }
         }
 
-        scoping
+        // Symbols which could not be given their cached name, because it clashes in this program,
+        // do not replace the cached name, which is still used by other programs.
+        if let Some(name_cache) = &mut name_cache {
+            for (symbol_id, original_name) in root_symbols_to_cache {
+                name_cache
+                    .vars
+                    .entry(original_name)
+                    .or_insert_with(|| CompactStr::from(scoping.symbol_name(symbol_id)));
+            }
+        }
+
+        ManglerReturn { scoping, name_cache }
+    }
+
+    fn is_mangled_symbol(
+        &self,
+        scoping: &Scoping,
+        exported_symbols: &FxHashSet<SymbolId>,
+        symbol_id: SymbolId,
+    ) -> bool {
+        if scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id()
+            && (!self.options.top_level || exported_symbols.contains(&symbol_id))
+        {
+            return false;
+        }
+        !is_special_name(scoping.symbol_name(symbol_id)) && !self.is_kept_symbol(scoping, symbol_id)
+    }
+
+    /// Whether the symbol keeps its original name because of [MangleOptions::reserved] or [MangleOptions::keep_names].
+    fn is_kept_symbol(&self, scoping: &Scoping, symbol_id: SymbolId) -> bool {
+        let name = scoping.symbol_name(symbol_id);
+        if NamePattern::matches_any(&self.options.reserved, name) {
+            return true;
+        }
+        let keep_names = &self.options.keep_names;
+        let flags = scoping.symbol_flags(symbol_id);
+        (flags.is_function()
+            && (keep_names.function
+                || NamePattern::matches_any(&keep_names.function_patterns, name)))
+            || (flags.is_class()
+                && (keep_names.class || NamePattern::matches_any(&keep_names.class_patterns, name)))
     }
 
     fn tally_slot_frequencies<'a>(
@@ -370,22 +553,22 @@ impl Mangler {
         exported_symbols: &FxHashSet<SymbolId>,
         total_number_of_slots: usize,
         slots: &[Slot],
+        cached_slot_names: &FxHashMap<Slot, &str>,
         allocator: &'a Allocator,
     ) -> Vec<'a, SlotFrequency<'a>> {
-        let root_scope_id = scoping.root_scope_id();
         let mut frequencies = Vec::with_capacity_in(total_number_of_slots, allocator);
         for _ in 0..total_number_of_slots {
             frequencies.push(SlotFrequency::new(allocator));
         }
 
+        // Slots with a symbol that keeps its original name.
+        let mut kept_slots = FxHashSet::default();
         for (symbol_id, slot) in slots.iter().copied().enumerate() {
             let symbol_id = SymbolId::from_usize(symbol_id);
-            if scoping.symbol_scope_id(symbol_id) == root_scope_id
-                && (!self.options.top_level || exported_symbols.contains(&symbol_id))
-            {
-                continue;
-            }
-            if is_special_name(scoping.symbol_name(symbol_id)) {
+            if !self.is_mangled_symbol(scoping, exported_symbols, symbol_id) {
+                if self.is_kept_symbol(scoping, symbol_id) {
+                    kept_slots.insert(slot);
+                }
                 continue;
             }
             let index = slot;
@@ -393,6 +576,17 @@ impl Mangler {
             frequencies[index].frequency += scoping.get_resolved_reference_ids(symbol_id).len();
             frequencies[index].symbol_ids.push(symbol_id);
         }
+        // Slots named by the name cache, and slots only used by kept symbols,
+        // do not take part in name generation.
+        if !cached_slot_names.is_empty() || !kept_slots.is_empty() {
+            let mut index = 0;
+            frequencies.retain(|frequency| {
+                let is_kept_only = kept_slots.contains(&index) && frequency.symbol_ids.is_empty();
+                let keep = !cached_slot_names.contains_key(&index) && !is_kept_only;
+                index += 1;
+                keep
+            });
+        }
         frequencies.sort_unstable_by_key(|x| std::cmp::Reverse(x.frequency));
         frequencies
     }
@@ -0,0 +1,38 @@
+use regex::Regex;
+
+use oxc_span::CompactStr;
+
+/// An identifier name, or a regular expression matching identifier names.
+#[derive(Debug, Clone)]
+pub enum NamePattern {
+    /// Matches this exact name, e.g. `require`.
+    Name(CompactStr),
+    /// Matches names containing a match of this regular expression, e.g. `^Vue`.
+    Regex(Regex),
+}
+
+impl NamePattern {
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid regular expression.
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(pattern).map(Self::Regex)
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Name(n) => n == name,
+            Self::Regex(regex) => regex.is_match(name),
+        }
+    }
+
+    pub(crate) fn matches_any(patterns: &[Self], name: &str) -> bool {
+        patterns.iter().any(|pattern| pattern.matches(name))
+    }
+}
+
+impl From<&str> for NamePattern {
+    fn from(name: &str) -> Self {
+        Self::Name(CompactStr::from(name))
+    }
+}
@@ -51,6 +51,7 @@ fn minify(
     let options = MinifierOptions {
         mangle: mangle.then(MangleOptions::default),
         compress: Some(CompressOptions::default()),
+        stats: false,
     };
     let ret = Minifier::new(options).build(allocator, &mut program);
     CodeGenerator::new()
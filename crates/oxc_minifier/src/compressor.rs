@@ -4,36 +4,93 @@ use oxc_semantic::{Scoping, SemanticBuilder};
 use oxc_traverse::ReusableTraverseCtx;
 
 use crate::{
-    CompressOptions,
+    CompressOptions, CompressorStats,
     peephole::{
-        DeadCodeElimination, LatePeepholeOptimizations, Normalize, NormalizeOptions,
-        PeepholeOptimizations,
+        ControlFlowDeadCodeElimination, DeadCodeElimination, LatePeepholeOptimizations, Normalize,
+        NormalizeOptions, PeepholeOptimizations,
     },
 };
 
 pub struct Compressor<'a> {
     allocator: &'a Allocator,
     options: CompressOptions,
+    stats: bool,
+}
+
+pub struct CompressorReturn {
+    /// Statistics of the optimizations, if enabled with [Compressor::with_stats].
+    pub stats: Option<CompressorStats>,
 }
 
 impl<'a> Compressor<'a> {
     pub fn new(allocator: &'a Allocator, options: CompressOptions) -> Self {
-        Self { allocator, options }
+        Self { allocator, options, stats: false }
+    }
+
+    /// Collect [CompressorStats].
+    ///
+    /// This prints the program after each phase to measure the output size, so it is slow.
+    #[must_use]
+    pub fn with_stats(mut self, yes: bool) -> Self {
+        self.stats = yes;
+        self
     }
 
-    pub fn build(self, program: &mut Program<'a>) {
+    pub fn build(self, program: &mut Program<'a>) -> CompressorReturn {
         let scoping = SemanticBuilder::new().build(program).semantic.into_scoping();
-        self.build_with_scoping(scoping, program);
+        self.build_with_scoping(scoping, program)
     }
 
-    pub fn build_with_scoping(self, scoping: Scoping, program: &mut Program<'a>) {
+    pub fn build_with_scoping(
+        self,
+        scoping: Scoping,
+        program: &mut Program<'a>,
+    ) -> CompressorReturn {
+        let mut stats = self.stats.then(|| CompressorStats::new(program));
         let mut ctx = ReusableTraverseCtx::new(scoping, self.allocator);
+        // Runs before the peephole optimizations join statements into sequence expressions,
+        // and before `const` declarations are converted to `let`.
+        if self.options.control_flow_dead_code
+            && ControlFlowDeadCodeElimination::new(self.options.reserved.clone())
+                .build(program, &mut ctx)
+        {
+            if let Some(stats) = &mut stats {
+                stats.record_phase("control_flow_dead_code", program);
+            }
+        }
         let normalize_options =
             NormalizeOptions { convert_while_to_fors: true, convert_const_to_let: true };
-        Normalize::new(normalize_options, self.options).build(program, &mut ctx);
-        PeepholeOptimizations::new(self.options.target, self.options.keep_names)
-            .run_in_loop(program, &mut ctx);
-        LatePeepholeOptimizations::new(self.options.target).build(program, &mut ctx);
+        Normalize::new(normalize_options, self.options.clone()).build(program, &mut ctx);
+        if let Some(stats) = &mut stats {
+            stats.record_phase("normalize", program);
+        }
+        self.run_peephole_optimizations(program, &mut ctx, stats.as_mut());
+        LatePeepholeOptimizations::new(self.options.target)
+            .with_reserved(self.options.reserved.clone())
+            .build(program, &mut ctx);
+        if let Some(stats) = &mut stats {
+            stats.record_phase("late_peephole", program);
+        }
+        CompressorReturn { stats }
+    }
+
+    fn run_peephole_optimizations(
+        &self,
+        program: &mut Program<'a>,
+        ctx: &mut ReusableTraverseCtx<'a>,
+        stats: Option<&mut CompressorStats>,
+    ) {
+        let mut peephole =
+            PeepholeOptimizations::new(self.options.target, self.options.keep_names.clone())
+                .with_reserved(self.options.reserved.clone())
+                .with_stats(stats.is_some());
+        peephole.run_in_loop(program, ctx);
+        if let (Some(stats), Some(peephole_stats)) = (stats, peephole.stats()) {
+            stats.record_iterations(peephole.iterations());
+            stats.record_changes(&peephole_stats.optimizations);
+            stats.record_iteration_stats(&peephole_stats.iteration_stats);
+            stats.record_phase("peephole", program);
+        }
     }
 
     pub fn dead_code_elimination(self, program: &mut Program<'a>) {
@@ -45,7 +102,13 @@ impl<'a> Compressor<'a> {
         let mut ctx = ReusableTraverseCtx::new(scoping, self.allocator);
         let normalize_options =
             NormalizeOptions { convert_while_to_fors: false, convert_const_to_let: false };
-        Normalize::new(normalize_options, self.options).build(program, &mut ctx);
+        Normalize::new(normalize_options, self.options.clone()).build(program, &mut ctx);
         DeadCodeElimination::new().build(program, &mut ctx);
+        if self.options.control_flow_dead_code
+            && ControlFlowDeadCodeElimination::new(self.options.reserved.clone())
+                .build(program, &mut ctx)
+        {
+            DeadCodeElimination::new().build(program, &mut ctx);
+        }
     }
 }
@@ -7,6 +7,7 @@ mod ctx;
 mod keep_var;
 mod options;
 mod peephole;
+mod stats;
 
 #[cfg(test)]
 mod tester;
@@ -16,26 +17,44 @@ use oxc_ast::ast::Program;
 use oxc_mangler::Mangler;
 use oxc_semantic::{Scoping, SemanticBuilder, Stats};
 
-pub use oxc_mangler::MangleOptions;
+pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, NameCache, NamePattern};
 
 pub use crate::{
-    compressor::Compressor, options::CompressOptions, options::CompressOptionsKeepNames,
+    compressor::{Compressor, CompressorReturn},
+    options::CompressOptions,
+    options::CompressOptionsKeepNames,
+    stats::{CompressorStats, IterationStats, OptimizationStats, PhaseStats},
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct MinifierOptions {
     pub mangle: Option<MangleOptions>,
     pub compress: Option<CompressOptions>,
+
+    /// Collect [CompressorStats] from the compressor.
+    ///
+    /// Default: `false`
+    pub stats: bool,
 }
 
 impl Default for MinifierOptions {
     fn default() -> Self {
-        Self { mangle: Some(MangleOptions::default()), compress: Some(CompressOptions::default()) }
+        Self {
+            mangle: Some(MangleOptions::default()),
+            compress: Some(CompressOptions::default()),
+            stats: false,
+        }
     }
 }
 
 pub struct MinifierReturn {
     pub scoping: Option<Scoping>,
+
+    /// The updated name cache, if [MangleOptions::name_cache] was provided.
+    pub name_cache: Option<NameCache>,
+
+    /// Compressor statistics, if [MinifierOptions::stats] is enabled.
+    pub stats: Option<CompressorStats>,
 }
 
 pub struct Minifier {
@@ -48,23 +67,33 @@ impl Minifier {
     }
 
     pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> MinifierReturn {
-        let stats = if let Some(compress) = self.options.compress {
+        // Names reserved by the compressor are not renamed by the mangler either.
+        let compress_reserved =
+            self.options.compress.as_ref().map(|compress| compress.reserved.clone());
+        let (stats, compressor_stats) = if let Some(compress) = self.options.compress {
             let semantic = SemanticBuilder::new().build(program).semantic;
             let stats = semantic.stats();
             let scoping = semantic.into_scoping();
-            Compressor::new(allocator, compress).build_with_scoping(scoping, program);
-            stats
+            let ret = Compressor::new(allocator, compress)
+                .with_stats(self.options.stats)
+                .build_with_scoping(scoping, program);
+            (stats, ret.stats)
         } else {
-            Stats::default()
+            (Stats::default(), None)
         };
-        let scoping = self.options.mangle.map(|options| {
+        let (scoping, name_cache) = self.options.mangle.map_or((None, None), |mut options| {
+            if let Some(reserved) = compress_reserved {
+                options.reserved.extend(reserved);
+            }
             let semantic = SemanticBuilder::new()
                 .with_stats(stats)
                 .with_scope_tree_child_ids(true)
                 .build(program)
                 .semantic;
-            Mangler::default().with_options(options).build_with_semantic(semantic, program)
+            let ret =
+                Mangler::default().with_options(options).build_with_semantic(semantic, program);
+            (Some(ret.scoping), ret.name_cache)
         });
-        MinifierReturn { scoping }
+        MinifierReturn { scoping, name_cache, stats: compressor_stats }
     }
 }
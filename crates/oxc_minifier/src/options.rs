@@ -1,6 +1,7 @@
+use oxc_mangler::NamePattern;
 use oxc_syntax::es_target::ESTarget;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct CompressOptions {
     /// Set desired EcmaScript standard version for output.
     ///
@@ -15,6 +16,15 @@ pub struct CompressOptions {
     /// Keep function / class names.
     pub keep_names: CompressOptionsKeepNames,
 
+    /// Names that are never dropped, similar to [oxc_mangler::MangleOptions::reserved].
+    ///
+    /// Unused names of function and class expressions, unused catch parameters, and dead
+    /// stores to variables with a matching name are kept. [crate::Minifier] also passes
+    /// these names to the mangler, so they are not renamed.
+    ///
+    /// Default `[]`
+    pub reserved: Vec<NamePattern>,
+
     /// Remove `debugger;` statements.
     ///
     /// Default `true`
@@ -24,6 +34,12 @@ pub struct CompressOptions {
     ///
     /// Default `false`
     pub drop_console: bool,
+
+    /// Remove unreachable code, dead stores to local variables and code after calls to
+    /// functions that never return, using the control flow graph.
+    ///
+    /// Default `true`
+    pub control_flow_dead_code: bool,
 }
 
 #[expect(clippy::derivable_impls)]
@@ -38,8 +54,10 @@ impl CompressOptions {
         Self {
             target: ESTarget::ESNext,
             keep_names: CompressOptionsKeepNames::all_false(),
+            reserved: vec![],
             drop_debugger: true,
             drop_console: true,
+            control_flow_dead_code: true,
         }
     }
 
@@ -47,13 +65,15 @@ impl CompressOptions {
         Self {
             target: ESTarget::ESNext,
             keep_names: CompressOptionsKeepNames::all_true(),
+            reserved: vec![],
             drop_debugger: false,
             drop_console: false,
+            control_flow_dead_code: false,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct CompressOptionsKeepNames {
     /// Keep function names so that `Function.prototype.name` is preserved.
     ///
@@ -68,22 +88,42 @@ pub struct CompressOptionsKeepNames {
     ///
     /// Default `false`
     pub class: bool,
+
+    /// Keep names of functions matching any of these patterns, e.g. for error reporting code.
+    ///
+    /// Default `[]`
+    pub function_patterns: Vec<NamePattern>,
+
+    /// Keep names of classes matching any of these patterns, e.g. for Angular dependency injection.
+    ///
+    /// Default `[]`
+    pub class_patterns: Vec<NamePattern>,
 }
 
 impl CompressOptionsKeepNames {
     pub fn all_false() -> Self {
-        Self { function: false, class: false }
+        Self { function: false, class: false, ..Self::default() }
     }
 
     pub fn all_true() -> Self {
-        Self { function: true, class: true }
+        Self { function: true, class: true, ..Self::default() }
     }
 
     pub fn function_only() -> Self {
-        Self { function: true, class: false }
+        Self { function: true, class: false, ..Self::default() }
     }
 
     pub fn class_only() -> Self {
-        Self { function: false, class: true }
+        Self { function: false, class: true, ..Self::default() }
+    }
+
+    /// Whether the name of a function named `name` must be kept.
+    pub fn keeps_function_name(&self, name: &str) -> bool {
+        self.function || self.function_patterns.iter().any(|pattern| pattern.matches(name))
+    }
+
+    /// Whether the name of a class named `name` must be kept.
+    pub fn keeps_class_name(&self, name: &str) -> bool {
+        self.class || self.class_patterns.iter().any(|pattern| pattern.matches(name))
     }
 }
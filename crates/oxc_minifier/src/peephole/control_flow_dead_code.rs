@@ -0,0 +1,440 @@
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Address, GetAddress, Vec};
+use oxc_ast::{AstKind, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_cfg::{
+    BlockNodeId, ControlFlowGraph, EdgeType, InstructionKind,
+    graph::{Direction, visit::EdgeRef},
+};
+use oxc_semantic::{AstNodes, ScopeId, Scoping, Semantic, SemanticBuilder, SymbolFlags};
+use oxc_span::{GetSpan, Span};
+use oxc_traverse::{ReusableTraverseCtx, Traverse, TraverseCtx, traverse_mut_with_ctx};
+
+use oxc_mangler::NamePattern;
+
+use crate::keep_var::KeepVar;
+
+/// Dead code elimination driven by the control flow graph built by [SemanticBuilder::with_cfg].
+///
+/// Removes:
+///
+/// * statements in unreachable basic blocks, e.g. code after `if (x) return; else throw e;`
+/// * dead stores to local variables that are never read afterward, e.g. `x = foo()` -> `foo()`
+/// * statements after calls to functions that never return, e.g. `function fail() { throw e } fail(); dead();`
+///
+/// These cases are not visible to the peephole rules, which only look at the syntax of a single statement list.
+/// The semantic model is rebuilt from the current AST, so this pass can run after other passes have changed it.
+pub struct ControlFlowDeadCodeElimination {
+    /// Statements in unreachable basic blocks.
+    unreachable_statements: FxHashSet<Address>,
+    /// `ExpressionStatement`s of the form `x = expr` where `x` is never read afterward.
+    dead_stores: FxHashSet<Address>,
+    /// `ExpressionStatement`s calling a function that never returns.
+    terminators: FxHashSet<Address>,
+    /// Names of variables whose stores are never removed, see [crate::CompressOptions::reserved].
+    reserved: std::vec::Vec<NamePattern>,
+    changed: bool,
+}
+
+impl<'a> ControlFlowDeadCodeElimination {
+    pub fn new(reserved: std::vec::Vec<NamePattern>) -> Self {
+        Self {
+            unreachable_statements: FxHashSet::default(),
+            dead_stores: FxHashSet::default(),
+            terminators: FxHashSet::default(),
+            reserved,
+            changed: false,
+        }
+    }
+
+    /// Returns `true` if the program is changed.
+    pub fn build(mut self, program: &mut Program<'a>, ctx: &mut ReusableTraverseCtx<'a>) -> bool {
+        let semantic = SemanticBuilder::new().with_cfg(true).build(program).semantic;
+        self.collect(&semantic);
+        drop(semantic);
+        if self.unreachable_statements.is_empty()
+            && self.dead_stores.is_empty()
+            && self.terminators.is_empty()
+        {
+            return false;
+        }
+        traverse_mut_with_ctx(&mut self, program, ctx);
+        self.changed
+    }
+
+    fn collect(&mut self, semantic: &Semantic<'a>) {
+        let Some(cfg) = semantic.cfg() else { return };
+        let nodes = semantic.nodes();
+        let scoping = semantic.scoping();
+
+        let analyze_stores = !scoping.root_scope_flags().contains_direct_eval()
+            && !nodes.iter().any(|node| matches!(node.kind(), AstKind::WithStatement(_)));
+        let mut never_return_functions = FxHashSet::default();
+
+        for node in nodes.iter() {
+            match node.kind() {
+                AstKind::Function(func)
+                    if func.is_declaration()
+                        && !func.r#async
+                        && !func.generator
+                        && func.body.is_some()
+                        && !Self::may_return(cfg, node.cfg_id()) =>
+                {
+                    never_return_functions.insert(node.id());
+                }
+                _ => {}
+            }
+            if node.kind().is_statement() && cfg.basic_block(node.cfg_id()).is_unreachable() {
+                if let Some(address) = Self::statement_address(node.kind()) {
+                    self.unreachable_statements.insert(address);
+                }
+            }
+        }
+
+        for node in nodes.iter() {
+            let AstKind::ExpressionStatement(stmt) = node.kind() else { continue };
+            match &stmt.expression {
+                Expression::CallExpression(call) if !call.optional => {
+                    let Expression::Identifier(ident) = &call.callee else { continue };
+                    let Some(symbol_id) = scoping.get_reference(ident.reference_id()).symbol_id()
+                    else {
+                        continue;
+                    };
+                    // A redeclared or reassigned name may refer to another function at the call.
+                    if never_return_functions.contains(&scoping.symbol_declaration(symbol_id))
+                        && !scoping.symbol_is_mutated(symbol_id)
+                        && scoping.symbol_redeclarations(symbol_id).is_empty()
+                    {
+                        self.terminators.insert(Address::from_ptr(stmt));
+                    }
+                }
+                Expression::AssignmentExpression(assign)
+                    if analyze_stores && assign.operator == AssignmentOperator::Assign =>
+                {
+                    let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+                        continue;
+                    };
+                    if self.reserved.iter().any(|pattern| pattern.matches(&ident.name)) {
+                        continue;
+                    }
+                    if Self::is_dead_store(cfg, nodes, scoping, ident, stmt.span, node.cfg_id()) {
+                        self.dead_stores.insert(Address::from_ptr(stmt));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Whether any reachable `return` (explicit or implicit) exists in the function starting at `entry`.
+    fn may_return(cfg: &ControlFlowGraph, entry: BlockNodeId) -> bool {
+        let graph = cfg.graph();
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![entry];
+        while let Some(block_id) = stack.pop() {
+            if !visited.insert(block_id) {
+                continue;
+            }
+            let block = cfg.basic_block(block_id);
+            if !block.is_unreachable()
+                && block.instructions().iter().any(|instruction| {
+                    matches!(
+                        instruction.kind,
+                        InstructionKind::Return(_) | InstructionKind::ImplicitReturn
+                    )
+                })
+            {
+                return true;
+            }
+            stack.extend(
+                graph
+                    .edges_directed(block_id, Direction::Outgoing)
+                    // Do not walk into nested functions.
+                    .filter(|edge| !matches!(edge.weight(), EdgeType::NewFunction))
+                    .map(|edge| edge.target()),
+            );
+        }
+        false
+    }
+
+    /// A store to a local variable is dead when no read of the variable is reachable from the store.
+    ///
+    /// Stores which may throw are kept, i.e. stores to `const` and `using` bindings, and stores to
+    /// `let` bindings which may happen in their temporal dead zone.
+    fn is_dead_store(
+        cfg: &ControlFlowGraph,
+        nodes: &AstNodes<'a>,
+        scoping: &Scoping,
+        ident: &IdentifierReference<'a>,
+        store_span: Span,
+        store_block_id: BlockNodeId,
+    ) -> bool {
+        let Some(symbol_id) = scoping.get_reference(ident.reference_id()).symbol_id() else {
+            return false;
+        };
+        if !scoping.symbol_flags(symbol_id).intersects(SymbolFlags::Variable) {
+            return false;
+        }
+        let declaration_node_id = scoping.symbol_declaration(symbol_id);
+        let AstKind::VariableDeclarator(declarator) = nodes.kind(declaration_node_id) else {
+            return false;
+        };
+        match declarator.kind {
+            VariableDeclarationKind::Var => {}
+            VariableDeclarationKind::Let => {
+                // Stores before the declaration, or after jumping over it to another `case`,
+                // are in the temporal dead zone.
+                let in_switch_case = nodes
+                    .ancestor_kinds(declaration_node_id)
+                    .take_while(|kind| !kind.is_function_like())
+                    .any(|kind| matches!(kind, AstKind::SwitchCase(_)));
+                if store_span.start < declarator.span.end || in_switch_case {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+        let var_scope_id =
+            Self::var_scope_id(scoping, nodes.get_node(declaration_node_id).scope_id());
+        if var_scope_id == scoping.root_scope_id() {
+            return false;
+        }
+        scoping.get_resolved_references(symbol_id).all(|reference| {
+            let node = nodes.get_node(reference.node_id());
+            // Closures can read the variable at any time.
+            if Self::var_scope_id(scoping, node.scope_id()) != var_scope_id {
+                return false;
+            }
+            if !reference.is_read() {
+                return true;
+            }
+            let read_block_id = node.cfg_id();
+            if read_block_id == store_block_id {
+                // Within a basic block, reads before the store are dead unless the block is in a loop.
+                return node.kind().span().start < store_span.start
+                    && !cfg.is_cyclic(store_block_id);
+            }
+            !cfg.is_reachable(store_block_id, read_block_id)
+        })
+    }
+
+    fn var_scope_id(scoping: &Scoping, scope_id: ScopeId) -> ScopeId {
+        scoping
+            .scope_ancestors(scope_id)
+            .find(|scope_id| scoping.scope_flags(*scope_id).is_var())
+            .unwrap_or_else(|| scoping.root_scope_id())
+    }
+
+    /// Statements that can be removed when they are unreachable.
+    /// Declarations other than `var` are kept for hoisting and TDZ semantics.
+    fn statement_address(kind: AstKind<'a>) -> Option<Address> {
+        let address = match kind {
+            AstKind::BlockStatement(it) => Address::from_ptr(it),
+            AstKind::BreakStatement(it) => Address::from_ptr(it),
+            AstKind::ContinueStatement(it) => Address::from_ptr(it),
+            AstKind::DebuggerStatement(it) => Address::from_ptr(it),
+            AstKind::DoWhileStatement(it) => Address::from_ptr(it),
+            AstKind::EmptyStatement(it) => Address::from_ptr(it),
+            AstKind::ExpressionStatement(it) => Address::from_ptr(it),
+            AstKind::ForInStatement(it) => Address::from_ptr(it),
+            AstKind::ForOfStatement(it) => Address::from_ptr(it),
+            AstKind::ForStatement(it) => Address::from_ptr(it),
+            AstKind::IfStatement(it) => Address::from_ptr(it),
+            AstKind::LabeledStatement(it) => Address::from_ptr(it),
+            AstKind::ReturnStatement(it) => Address::from_ptr(it),
+            AstKind::SwitchStatement(it) => Address::from_ptr(it),
+            AstKind::ThrowStatement(it) => Address::from_ptr(it),
+            AstKind::TryStatement(it) => Address::from_ptr(it),
+            AstKind::WhileStatement(it) => Address::from_ptr(it),
+            AstKind::WithStatement(it) => Address::from_ptr(it),
+            AstKind::VariableDeclaration(it) if it.kind.is_var() => Address::from_ptr(it),
+            _ => return None,
+        };
+        Some(address)
+    }
+
+    fn remove_dead_stores(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &TraverseCtx<'a>) {
+        for stmt in stmts.iter_mut() {
+            let Statement::ExpressionStatement(expr_stmt) = stmt else { continue };
+            if !self.dead_stores.contains(&expr_stmt.address()) {
+                continue;
+            }
+            let Expression::AssignmentExpression(assign) = &mut expr_stmt.expression else {
+                continue;
+            };
+            expr_stmt.expression = ctx.ast.move_expression(&mut assign.right);
+            self.changed = true;
+        }
+    }
+
+    /// Removes unreachable statements and statements after a call that never returns,
+    /// keeping hoisted `var` names and function declarations.
+    fn remove_unreachable_statements(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let terminator_index = stmts.iter().position(|stmt| {
+            matches!(stmt, Statement::ExpressionStatement(s) if self.terminators.contains(&s.address()))
+        });
+        let is_removable = |index: usize, stmt: &Statement<'a>| {
+            if stmt.is_module_declaration()
+                || matches!(stmt.as_declaration(), Some(Declaration::FunctionDeclaration(_)))
+            {
+                return false;
+            }
+            terminator_index.is_some_and(|i| index > i)
+                || self.unreachable_statements.contains(&stmt.address())
+        };
+
+        if !stmts.iter().enumerate().any(|(index, stmt)| is_removable(index, stmt)) {
+            return;
+        }
+
+        let mut keep_var = KeepVar::new(ctx.ast);
+        for (index, stmt) in stmts.iter().enumerate() {
+            if is_removable(index, stmt) {
+                keep_var.visit_statement(stmt);
+            }
+        }
+
+        let mut index = 0;
+        stmts.retain(|stmt| {
+            let keep = !is_removable(index, stmt);
+            index += 1;
+            keep
+        });
+
+        if let Some(stmt) = keep_var.get_variable_declaration_statement() {
+            stmts.push(stmt);
+        }
+        self.changed = true;
+    }
+}
+
+impl<'a> Traverse<'a> for ControlFlowDeadCodeElimination {
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if !self.dead_stores.is_empty() {
+            self.remove_dead_stores(stmts, ctx);
+        }
+        self.remove_unreachable_statements(stmts, ctx);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_codegen::{CodeGenerator, CodegenOptions};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use oxc_traverse::ReusableTraverseCtx;
+
+    use oxc_mangler::NamePattern;
+
+    use super::ControlFlowDeadCodeElimination;
+
+    fn run(source_text: &str) -> String {
+        run_with_reserved(source_text, vec![])
+    }
+
+    fn run_with_reserved(source_text: &str, reserved: Vec<NamePattern>) -> String {
+        let allocator = Allocator::default();
+        let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+        let mut ctx = ReusableTraverseCtx::new(scoping, &allocator);
+        ControlFlowDeadCodeElimination::new(reserved).build(&mut program, &mut ctx);
+        CodeGenerator::new()
+            .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+            .build(&program)
+            .code
+    }
+
+    fn test(source_text: &str, expected: &str) {
+        let expected = run(expected);
+        let result = run(source_text);
+        assert_eq!(
+            result, expected,
+            "\nfor source\n{source_text}\nexpect\n{expected}\ngot\n{result}"
+        );
+    }
+
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    #[test]
+    fn unreachable_code() {
+        test(
+            "function f() { if (x) { return 1 } else { throw 2 } foo(); var y = 1; }",
+            "function f() { if (x) { return 1 } else { throw 2 } var y; }",
+        );
+        test(
+            "function f() { switch (x) { case 1: return 1; default: return 2 } foo() }",
+            "function f() { switch (x) { case 1: return 1; default: return 2 } }",
+        );
+        test(
+            "function f() { try { return 1 } finally { foo() } bar() }",
+            "function f() { try { return 1 } finally { foo() } }",
+        );
+        test(
+            "function f() { if (x) return; else return; function g() {} g() }",
+            "function f() { if (x) return; else return; function g() {} }",
+        );
+        test_same("function f() { if (x) return; foo() }");
+        test_same("function f() { try { return 1 } catch { foo() } bar() }");
+        test_same("function f() { while (x) { if (y) break; return } foo() }");
+    }
+
+    #[test]
+    fn never_returning_calls() {
+        test(
+            "function fail() { throw new Error() } function f() { fail(); foo(); var x = 1 }",
+            "function fail() { throw new Error() } function f() { fail(); var x }",
+        );
+        test(
+            "function fail() { if (x) throw 1; else throw 2 } fail(); foo()",
+            "function fail() { if (x) throw 1; else throw 2 } fail();",
+        );
+        test_same("function maybe() { if (x) throw 1 } maybe(); foo()");
+        test_same("function fail() { throw 1 } fail = noop; fail(); foo()");
+        test_same("function fail() { throw 1 } function fail() {} fail(); foo()");
+        test_same("function fail() { throw 1 } var fail; fail(); foo()");
+        test_same("async function fail() { throw 1 } fail(); foo()");
+        test_same("function* fail() { throw 1 } fail(); foo()");
+        test_same("function fail() { function g() { return } throw 1 } fail?.(); foo()");
+    }
+
+    #[test]
+    fn dead_stores() {
+        test(
+            "function f() { let x = 1; foo(x); x = bar() }",
+            "function f() { let x = 1; foo(x); bar() }",
+        );
+        test(
+            "function f() { var x; if (y) { x = 1; return } x = 2; return x }",
+            "function f() { var x; if (y) { 1; return } x = 2; return x }",
+        );
+        test_same("function f() { let x = 1; x = 2; foo(x) }");
+        test_same("function f() { let x; for (;;) { foo(x); x = 1 } }");
+        test_same("function f() { let x; try { x = 1; foo() } catch { bar(x) } }");
+        test_same("function f() { let x; x = 1; return () => x }");
+        test_same("function f(x) { x = 1; return arguments[0] }");
+        test_same("let x; x = 1");
+        test_same("function f() { let x; x = 1; eval('x') }");
+        test_same("function f() { let x; x += 1 }");
+        // Stores which throw
+        test_same("function f() { const x = 1; foo(x); x = 2 }");
+        test_same("function f() { x = 1; let x; foo(x) }");
+        test_same("function f() { switch (y) { case 0: let x; case 1: x = 1 } }");
+    }
+
+    #[test]
+    fn dead_stores_reserved() {
+        let source_text = "function f() { let exports = {}; foo(exports); exports = bar() }";
+        assert!(!run(source_text).contains("exports = bar()"));
+        let result = run_with_reserved(source_text, vec![NamePattern::from("exports")]);
+        assert!(result.contains("exports = bar()"));
+    }
+}
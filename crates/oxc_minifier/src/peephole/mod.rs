@@ -1,4 +1,5 @@
 mod collapse_variable_declarations;
+mod control_flow_dead_code;
 mod convert_to_dotted_properties;
 mod fold_constants;
 mod minimize_conditional_expression;
@@ -17,21 +18,29 @@ mod replace_known_methods;
 mod statement_fusion;
 mod substitute_alternate_syntax;
 
+use std::time::Instant;
+
 use rustc_hash::FxHashSet;
 
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_data_structures::stack::NonEmptyStack;
+use oxc_mangler::NamePattern;
 use oxc_syntax::{es_target::ESTarget, scope::ScopeId};
 use oxc_traverse::{ReusableTraverseCtx, Traverse, TraverseCtx, traverse_mut_with_ctx};
 
-use crate::{ctx::Ctx, options::CompressOptionsKeepNames};
+use crate::{CompressorStats, ctx::Ctx, options::CompressOptionsKeepNames};
 
-pub use self::normalize::{Normalize, NormalizeOptions};
+pub use self::{
+    control_flow_dead_code::ControlFlowDeadCodeElimination,
+    normalize::{Normalize, NormalizeOptions},
+};
 
 pub struct PeepholeOptimizations {
     target: ESTarget,
     keep_names: CompressOptionsKeepNames,
+    /// Names that are never dropped, see [crate::CompressOptions::reserved].
+    reserved: std::vec::Vec<NamePattern>,
 
     /// Walk the ast in a fixed point loop until no changes are made.
     /// `prev_function_changed`, `functions_changed` and `current_function` track changes
@@ -43,6 +52,10 @@ pub struct PeepholeOptimizations {
     /// Track the current function as a stack.
     current_function:
         NonEmptyStack<(ScopeId, /* prev changed */ bool, /* current changed */ bool)>,
+
+    /// Name of the optimization currently running, for attributing changes in `stats`.
+    current_optimization: &'static str,
+    stats: Option<CompressorStats>,
 }
 
 impl<'a> PeepholeOptimizations {
@@ -50,20 +63,59 @@ impl<'a> PeepholeOptimizations {
         Self {
             target,
             keep_names,
+            reserved: vec![],
             iteration: 0,
             prev_functions_changed: FxHashSet::default(),
             functions_changed: FxHashSet::default(),
             current_function: NonEmptyStack::new((ScopeId::new(0), true, false)),
+            current_optimization: "",
+            stats: None,
         }
     }
 
+    /// Never drop names matching any of these patterns.
+    #[must_use]
+    pub fn with_reserved(mut self, reserved: std::vec::Vec<NamePattern>) -> Self {
+        self.reserved = reserved;
+        self
+    }
+
+    /// Whether `name` matches any of the reserved names.
+    fn is_reserved(&self, name: &str) -> bool {
+        self.reserved.iter().any(|pattern| pattern.matches(name))
+    }
+
+    /// Count the changes made by each optimization.
+    #[must_use]
+    pub fn with_stats(mut self, yes: bool) -> Self {
+        self.stats = yes.then(CompressorStats::default);
+        self
+    }
+
+    /// Number of AST walks done by the last [Self::run_in_loop].
+    pub fn iterations(&self) -> u32 {
+        u32::from(self.iteration) + 1
+    }
+
+    pub fn stats(&self) -> Option<&CompressorStats> {
+        self.stats.as_ref()
+    }
+
     pub fn build(&mut self, program: &mut Program<'a>, ctx: &mut ReusableTraverseCtx<'a>) {
         traverse_mut_with_ctx(self, program, ctx);
     }
 
     pub fn run_in_loop(&mut self, program: &mut Program<'a>, ctx: &mut ReusableTraverseCtx<'a>) {
+        let mut size = self.stats.as_ref().map(|_| CompressorStats::program_size(program));
         loop {
+            let start = size.map(|_| Instant::now());
             self.build(program, ctx);
+            if let (Some(stats), Some(size), Some(start)) = (&mut self.stats, &mut size, start) {
+                let duration = start.elapsed();
+                let size_after = CompressorStats::program_size(program);
+                stats.record_iteration(*size, size_after, duration);
+                *size = size_after;
+            }
             if self.functions_changed.is_empty() {
                 break;
             }
@@ -80,6 +132,9 @@ impl<'a> PeepholeOptimizations {
     fn mark_current_function_as_changed(&mut self) {
         let (_scope_id, _prev_changed, current_changed) = self.current_function.last_mut();
         *current_changed = true;
+        if let Some(stats) = &mut self.stats {
+            stats.record_change(self.current_optimization, 1);
+        }
     }
 
     #[inline]
@@ -152,6 +207,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "minimize_statements";
         self.minimize_statements(stmts, ctx);
     }
 
@@ -160,9 +216,12 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "minimize_expression_in_boolean_context";
         self.try_fold_stmt_in_boolean_context(stmt, ctx);
+        self.current_optimization = "remove_dead_code";
         self.remove_dead_code_exit_statement(stmt, ctx);
         if let Statement::IfStatement(if_stmt) = stmt {
+            self.current_optimization = "minimize_if_statement";
             if let Some(folded_stmt) = self.try_minimize_if(if_stmt, ctx) {
                 *stmt = folded_stmt;
                 self.mark_current_function_as_changed();
@@ -174,6 +233,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
         if !self.is_prev_function_changed() {
             return;
         }
+        self.current_optimization = "minimize_for_statement";
         self.minimize_for_statement(stmt, Ctx(ctx));
     }
 
@@ -182,6 +242,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_return_statement(stmt, ctx);
     }
 
@@ -194,6 +255,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_variable_declaration(decl, ctx);
     }
 
@@ -202,10 +264,15 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "fold_constants";
         self.fold_constants_exit_expression(expr, ctx);
+        self.current_optimization = "minimize_conditions";
         self.minimize_conditions_exit_expression(expr, ctx);
+        self.current_optimization = "remove_dead_code";
         self.remove_dead_code_exit_expression(expr, ctx);
+        self.current_optimization = "replace_known_methods";
         self.replace_known_methods_exit_expression(expr, ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_exit_expression(expr, ctx);
     }
 
@@ -213,6 +280,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
         if !self.is_prev_function_changed() {
             return;
         }
+        self.current_optimization = "minimize_expression_in_boolean_context";
         if expr.operator.is_not()
             && self.try_fold_expr_in_boolean_context(&mut expr.argument, Ctx(ctx))
         {
@@ -225,6 +293,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_call_expression(expr, ctx);
     }
 
@@ -233,6 +302,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_new_expression(expr, ctx);
     }
 
@@ -241,6 +311,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_object_property(prop, ctx);
     }
 
@@ -253,6 +324,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_assignment_target_property(node, ctx);
     }
 
@@ -265,6 +337,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_assignment_target_property_property(prop, ctx);
     }
 
@@ -273,6 +346,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_binding_property(prop, ctx);
     }
 
@@ -285,6 +359,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_method_definition(prop, ctx);
     }
 
@@ -297,6 +372,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_property_definition(prop, ctx);
     }
 
@@ -309,6 +385,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
             return;
         }
         let ctx = Ctx(ctx);
+        self.current_optimization = "substitute_alternate_syntax";
         self.substitute_accessor_property(prop, ctx);
     }
 }
@@ -317,11 +394,25 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
 /// which can be done as a last AST pass.
 pub struct LatePeepholeOptimizations {
     target: ESTarget,
+    /// Names that are never dropped, see [crate::CompressOptions::reserved].
+    reserved: std::vec::Vec<NamePattern>,
 }
 
 impl<'a> LatePeepholeOptimizations {
     pub fn new(target: ESTarget) -> Self {
-        Self { target }
+        Self { target, reserved: vec![] }
+    }
+
+    /// Never drop names matching any of these patterns.
+    #[must_use]
+    pub fn with_reserved(mut self, reserved: std::vec::Vec<NamePattern>) -> Self {
+        self.reserved = reserved;
+        self
+    }
+
+    /// Whether `name` matches any of the reserved names.
+    fn is_reserved(&self, name: &str) -> bool {
+        self.reserved.iter().any(|pattern| pattern.matches(name))
     }
 
     pub fn build(&mut self, program: &mut Program<'a>, ctx: &mut ReusableTraverseCtx<'a>) {
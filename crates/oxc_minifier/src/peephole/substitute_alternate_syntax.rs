@@ -971,11 +971,11 @@ impl<'a> PeepholeOptimizations {
     ///
     /// This compression is not safe if the code relies on `Function::name`.
     fn try_remove_name_from_functions(&mut self, func: &mut Function<'a>, ctx: Ctx<'a, '_>) {
-        if self.keep_names.function {
-            return;
-        }
-
-        if func.id.as_ref().is_some_and(|id| !ctx.scoping().symbol_is_used(id.symbol_id())) {
+        if func.id.as_ref().is_some_and(|id| {
+            !self.keep_names.keeps_function_name(&id.name)
+                && !self.is_reserved(&id.name)
+                && !ctx.scoping().symbol_is_used(id.symbol_id())
+        }) {
             func.id = None;
             self.mark_current_function_as_changed();
         }
@@ -987,11 +987,11 @@ impl<'a> PeepholeOptimizations {
     ///
     /// This compression is not safe if the code relies on `Class::name`.
     fn try_remove_name_from_classes(&mut self, class: &mut Class<'a>, ctx: Ctx<'a, '_>) {
-        if self.keep_names.class {
-            return;
-        }
-
-        if class.id.as_ref().is_some_and(|id| !ctx.scoping().symbol_is_used(id.symbol_id())) {
+        if class.id.as_ref().is_some_and(|id| {
+            !self.keep_names.keeps_class_name(&id.name)
+                && !self.is_reserved(&id.name)
+                && !ctx.scoping().symbol_is_used(id.symbol_id())
+        }) {
             class.id = None;
             self.mark_current_function_as_changed();
         }
@@ -1114,8 +1114,9 @@ impl<'a> LatePeepholeOptimizations {
         if self.target >= ESTarget::ES2019 {
             if let Some(param) = &catch.param {
                 if let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind {
-                    if catch.body.body.is_empty()
-                        || !ctx.scoping().symbol_is_used(ident.symbol_id())
+                    if !self.is_reserved(&ident.name)
+                        && (catch.body.body.is_empty()
+                            || !ctx.scoping().symbol_is_used(ident.symbol_id()))
                     {
                         catch.param = None;
                     }
@@ -1213,7 +1214,7 @@ mod test {
     use oxc_syntax::es_target::ESTarget;
 
     use crate::{
-        CompressOptions,
+        CompressOptions, NamePattern,
         options::CompressOptionsKeepNames,
         tester::{run, test, test_same},
     };
@@ -1879,6 +1880,35 @@ mod test {
         test_same_keep_names(CompressOptionsKeepNames::class_only(), "var a = class C {}");
     }
 
+    #[test]
+    fn test_remove_name_from_expressions_keep_name_patterns() {
+        let keep_names = CompressOptionsKeepNames {
+            function_patterns: vec![NamePattern::from("report")],
+            class_patterns: vec![NamePattern::regex("Service$").unwrap()],
+            ..CompressOptionsKeepNames::all_false()
+        };
+        test_same_keep_names(keep_names.clone(), "var a = function report() {}");
+        test_same_keep_names(keep_names.clone(), "var a = class UserService {}");
+        let result = run(
+            "var a = function f() {}, b = class C {}, c = class report {}",
+            Some(CompressOptions { keep_names, ..CompressOptions::smallest() }),
+        );
+        let expected = run("var a = function () {}, b = class {}, c = class {}", None);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_remove_name_from_expressions_reserved() {
+        let reserved = vec![NamePattern::regex("^Vue").unwrap()];
+        let result = run(
+            "var a = function VueApp() {}, b = class VueComponent {}, c = class C {}",
+            Some(CompressOptions { reserved, ..CompressOptions::smallest() }),
+        );
+        let expected =
+            run("var a = function VueApp() {}, b = class VueComponent {}, c = class {}", None);
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_compress_destructuring_assignment_target() {
         test_same("var {y} = x");
@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use oxc_ast::ast::Program;
+use oxc_codegen::{Codegen, CodegenOptions};
+
+/// Statistics collected by the [crate::Compressor] when enabled with [crate::Compressor::with_stats].
+///
+/// Useful for finding out which optimizations did or did not fire on a given input.
+#[derive(Debug, Default, Clone)]
+pub struct CompressorStats {
+    /// Number of AST walks done by the peephole optimizations until a fixed point is reached.
+    pub iterations: u32,
+
+    /// Output size of each phase of the compressor, in the order they ran.
+    pub phases: Vec<PhaseStats>,
+
+    /// Number of changes made by each peephole optimization, in the order they first fired.
+    ///
+    /// Optimizations run interleaved in the same AST walk, so bytes saved are only measured
+    /// for each phase and each walk, not for each optimization.
+    pub optimizations: Vec<OptimizationStats>,
+
+    /// One entry for each AST walk of the peephole optimizations, in the order they ran.
+    pub iteration_stats: Vec<IterationStats>,
+
+    /// Minified output size of the last recorded phase.
+    size: usize,
+
+    /// Changes made by each peephole optimization in the current AST walk.
+    iteration_optimizations: Vec<OptimizationStats>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PhaseStats {
+    pub name: &'static str,
+
+    /// Minified output size in bytes before this phase, without mangling.
+    pub size_before: usize,
+
+    /// Minified output size in bytes after this phase, without mangling.
+    pub size_after: usize,
+}
+
+impl PhaseStats {
+    /// Bytes removed by this phase. Negative if the phase increased the output size.
+    #[expect(clippy::cast_possible_wrap)]
+    pub fn bytes_saved(&self) -> isize {
+        self.size_before as isize - self.size_after as isize
+    }
+}
+
+/// Statistics of one AST walk in the fixed point loop of the peephole optimizations.
+#[derive(Debug, Clone)]
+pub struct IterationStats {
+    /// Minified output size in bytes before this walk, without mangling.
+    pub size_before: usize,
+
+    /// Minified output size in bytes after this walk, without mangling.
+    pub size_after: usize,
+
+    /// Time spent walking the AST, not including measuring the output size.
+    pub duration: Duration,
+
+    /// Number of changes made by each peephole optimization in this walk.
+    pub optimizations: Vec<OptimizationStats>,
+}
+
+impl IterationStats {
+    /// Bytes removed by this walk. Negative if the walk increased the output size.
+    #[expect(clippy::cast_possible_wrap)]
+    pub fn bytes_saved(&self) -> isize {
+        self.size_before as isize - self.size_after as isize
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OptimizationStats {
+    pub name: &'static str,
+
+    /// Number of times this optimization changed the AST.
+    pub changes: u32,
+}
+
+impl CompressorStats {
+    pub(crate) fn new(program: &Program<'_>) -> Self {
+        Self { size: Self::program_size(program), ..Self::default() }
+    }
+
+    /// Total bytes removed by the compressor.
+    #[expect(clippy::cast_possible_wrap)]
+    pub fn bytes_saved(&self) -> isize {
+        self.phases.first().map_or(0, |phase| phase.size_before as isize - self.size as isize)
+    }
+
+    pub(crate) fn record_phase(&mut self, name: &'static str, program: &Program<'_>) {
+        let size_after = Self::program_size(program);
+        self.phases.push(PhaseStats { name, size_before: self.size, size_after });
+        self.size = size_after;
+    }
+
+    pub(crate) fn record_iterations(&mut self, iterations: u32) {
+        self.iterations += iterations;
+    }
+
+    pub(crate) fn record_changes(&mut self, optimizations: &[OptimizationStats]) {
+        for optimization in optimizations {
+            Self::add_changes(&mut self.optimizations, optimization.name, optimization.changes);
+        }
+    }
+
+    pub(crate) fn record_change(&mut self, name: &'static str, changes: u32) {
+        Self::add_changes(&mut self.optimizations, name, changes);
+        Self::add_changes(&mut self.iteration_optimizations, name, changes);
+    }
+
+    /// Finish recording an AST walk of the peephole optimizations.
+    pub(crate) fn record_iteration(
+        &mut self,
+        size_before: usize,
+        size_after: usize,
+        duration: Duration,
+    ) {
+        let optimizations = std::mem::take(&mut self.iteration_optimizations);
+        self.iteration_stats.push(IterationStats {
+            size_before,
+            size_after,
+            duration,
+            optimizations,
+        });
+    }
+
+    pub(crate) fn record_iteration_stats(&mut self, iteration_stats: &[IterationStats]) {
+        self.iteration_stats.extend_from_slice(iteration_stats);
+    }
+
+    fn add_changes(optimizations: &mut Vec<OptimizationStats>, name: &'static str, changes: u32) {
+        if let Some(optimization) =
+            optimizations.iter_mut().find(|optimization| optimization.name == name)
+        {
+            optimization.changes += changes;
+        } else {
+            optimizations.push(OptimizationStats { name, changes });
+        }
+    }
+
+    pub(crate) fn program_size(program: &Program<'_>) -> usize {
+        Codegen::new()
+            .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+            .build(program)
+            .code
+            .len()
+    }
+}
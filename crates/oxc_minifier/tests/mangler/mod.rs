@@ -2,17 +2,19 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::CodeGenerator;
-use oxc_mangler::{MangleOptions, Mangler};
+use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler, NameCache, NamePattern};
 use oxc_parser::Parser;
-use oxc_span::SourceType;
+use oxc_span::{CompactStr, SourceType};
 
 fn mangle(source_text: &str, top_level: bool) -> String {
     let allocator = Allocator::default();
     let source_type = SourceType::mjs();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let program = ret.program;
-    let symbol_table =
-        Mangler::new().with_options(MangleOptions { debug: false, top_level }).build(&program);
+    let symbol_table = Mangler::new()
+        .with_options(MangleOptions { debug: false, top_level, ..MangleOptions::default() })
+        .build(&program)
+        .scoping;
     CodeGenerator::new().with_scoping(Some(symbol_table)).build(&program).code
 }
 
@@ -76,3 +78,98 @@ fn mangler() {
         insta::assert_snapshot!("mangler", snapshot);
     });
 }
+
+#[test]
+fn name_cache() {
+    fn mangle_with_cache(source_text: &str, name_cache: NameCache) -> (String, NameCache) {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let ret = Mangler::new()
+            .with_options(MangleOptions {
+                top_level: true,
+                name_cache: Some(name_cache),
+                ..MangleOptions::default()
+            })
+            .build(&program);
+        let code = CodeGenerator::new().with_scoping(Some(ret.scoping)).build(&program).code;
+        (code, ret.name_cache.unwrap())
+    }
+
+    let (code, name_cache) =
+        mangle_with_cache("var Shell = 1, other = 2; Shell(other)", NameCache::default());
+    assert_eq!(code, "var e = 1, t = 2;\ne(t);\n");
+    assert_eq!(name_cache.vars.get("Shell").map(CompactStr::as_str), Some("e"));
+    assert_eq!(name_cache.vars.get("other").map(CompactStr::as_str), Some("t"));
+
+    // Cached names are reused, and new names do not collide with cached names.
+    let (code, name_cache) =
+        mangle_with_cache("var fresh = 0, Shell = 1; Shell(fresh, fresh)", name_cache);
+    assert_eq!(code, "var n = 0, e = 1;\ne(n, n);\n");
+    assert_eq!(name_cache.vars.get("Shell").map(CompactStr::as_str), Some("e"));
+    assert_eq!(name_cache.vars.get("fresh").map(CompactStr::as_str), Some("n"));
+    assert_eq!(name_cache.vars.len(), 3);
+
+    // Cached names are not used when they would clash with a global reference,
+    // and the cached name is kept for the next build.
+    let (code, name_cache) = mangle_with_cache("var Shell = 1; Shell(e)", name_cache);
+    assert_eq!(code, "var r = 1;\nr(e);\n");
+    assert_eq!(name_cache.vars.get("Shell").map(CompactStr::as_str), Some("e"));
+    let (code, _) = mangle_with_cache("var Shell = 1; Shell()", name_cache);
+    assert_eq!(code, "var e = 1;\ne();\n");
+}
+
+#[test]
+fn reserved_and_keep_names() {
+    fn mangle_with_options(source_text: &str, options: MangleOptions) -> String {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let scoping = Mangler::new().with_options(options).build(&program).scoping;
+        CodeGenerator::new().with_scoping(Some(scoping)).build(&program).code
+    }
+
+    // Reserved names are kept, and no other symbol is renamed to them.
+    let options = MangleOptions {
+        reserved: vec![NamePattern::from("e"), NamePattern::regex("^Vue").unwrap()],
+        ..MangleOptions::default()
+    };
+    let code = mangle_with_options("function _() { let VueApp, e, x; x(VueApp, e) }", options);
+    assert_eq!(code, "function _() {\n\tlet VueApp, e, t;\n\tt(VueApp, e);\n}\n");
+
+    // Names matching a reserved pattern are never generated.
+    let options = MangleOptions {
+        reserved: vec![NamePattern::regex("^[a-z]$").unwrap()],
+        ..MangleOptions::default()
+    };
+    let code = mangle_with_options("function _(value) { value }", options);
+    assert_eq!(code, "function _(S) {\n\tS;\n}\n");
+
+    // Function and class names can be kept by pattern.
+    let options = MangleOptions {
+        top_level: true,
+        keep_names: MangleOptionsKeepNames {
+            function_patterns: vec![NamePattern::from("report")],
+            class_patterns: vec![NamePattern::regex("Service$").unwrap()],
+            ..MangleOptionsKeepNames::default()
+        },
+        ..MangleOptions::default()
+    };
+    let code = mangle_with_options(
+        "function report() {} function other() {} class UserService {} class Other {}",
+        options,
+    );
+    assert_eq!(code, "function report() {}\nfunction e() {}\nclass UserService {}\nclass t {}\n");
+}
+
+#[test]
+fn annex_b_functions() {
+    // Block-level functions are hoisted to the var scope in non-strict mode code
+    let allocator = Allocator::default();
+    let source_text = "function _() { { function foo() {} } var bar = 2; return foo() + bar }";
+    let program = Parser::new(&allocator, source_text, SourceType::cjs()).parse().program;
+    let scoping = Mangler::new().build(&program).scoping;
+    let mangled = CodeGenerator::new().with_scoping(Some(scoping)).build(&program).code;
+    assert_eq!(
+        mangled,
+        "function _() {\n\t{\n\t\tfunction e() {}\n\t}\n\tvar t = 2;\n\treturn e() + t;\n}\n"
+    );
+}
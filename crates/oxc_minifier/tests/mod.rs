@@ -3,6 +3,7 @@
 mod ecmascript;
 mod mangler;
 mod peephole;
+mod stats;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::{CodeGenerator, CodegenOptions};
@@ -12,7 +13,7 @@ use oxc_span::SourceType;
 
 pub(crate) fn test(source_text: &str, expected: &str, options: CompressOptions) {
     let source_type = SourceType::default();
-    let first = run(source_text, source_type, Some(options));
+    let first = run(source_text, source_type, Some(options.clone()));
 
     let expected = run(expected, source_type, None);
     assert_eq!(first, expected, "\nfor source\n{source_text}\nexpect\n{expected}\ngot\n{first}");
@@ -194,6 +194,7 @@ fn dce_from_terser() {
     );
 
     // NOTE: `if (x)` is changed to `if (true)` because const inlining is not implemented yet.
+    // `x = 10` is removed as a dead store to the local `var x`.
     test(
         r#"function f() {
             g();
@@ -213,7 +214,6 @@ fn dce_from_terser() {
         "#,
         r#"function f() {
             g();
-            x = 10;
             throw new Error("foo");
             var x;
         }
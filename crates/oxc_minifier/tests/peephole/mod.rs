@@ -1,7 +1,11 @@
 mod dead_code_elimination;
 mod esbuild;
 
-use oxc_minifier::CompressOptions;
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_minifier::{CompressOptions, MangleOptions, Minifier, MinifierOptions, NamePattern};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
 
 fn test(source_text: &str, expected: &str) {
     let options = CompressOptions { drop_debugger: false, ..CompressOptions::default() };
@@ -117,3 +121,81 @@ fn eval() {
     test_same("eval?.(x, y)");
     test_same("eval?.(x,y)");
 }
+
+/// The control flow dead code elimination in the whole [Minifier] pipeline, where the peephole
+/// optimizations join statements into sequence expressions.
+#[test]
+fn control_flow_dead_code() {
+    let test = |source_text: &str, expected: &str| {
+        let allocator = Allocator::default();
+        let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let options = MinifierOptions {
+            mangle: None,
+            compress: Some(CompressOptions::default()),
+            stats: false,
+        };
+        Minifier::new(options).build(&allocator, &mut program);
+        let result = CodeGenerator::new()
+            .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+            .build(&program)
+            .code;
+        assert_eq!(result, expected, "\nfor source\n{source_text}");
+    };
+
+    test(
+        "export function f() { let x = 1; foo(x); x = bar() }",
+        "export function f(){let x=1;foo(x),bar()}",
+    );
+    test("function fail() { throw 1 } fail(); foo();", "function fail(){throw 1}fail();");
+    // Stores which throw are kept
+    test(
+        "export function f() { const x = 1; foo(x); x = bar() }",
+        "export function f(){const x=1;foo(x),x=bar()}",
+    );
+    test(
+        "export function f() { x = bar(); let x; foo(x) }",
+        "export function f(){x=bar();let x;foo(x)}",
+    );
+    // The called name may refer to another function
+    test(
+        "function fail() { throw 1 } function fail() {} fail(); foo();",
+        "function fail(){throw 1}function fail(){}fail(),foo();",
+    );
+    test(
+        "function fail() { throw 1 } fail = g; fail(); foo();",
+        "function fail(){throw 1}fail=g,fail(),foo();",
+    );
+}
+
+/// [CompressOptions::reserved] names are neither dropped by the compressor nor renamed by the mangler.
+#[test]
+fn reserved() {
+    let test = |source_text: &str, expected: &str| {
+        let allocator = Allocator::default();
+        let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let options = MinifierOptions {
+            mangle: Some(MangleOptions { top_level: true, ..MangleOptions::default() }),
+            compress: Some(CompressOptions {
+                reserved: vec![NamePattern::from("keep")],
+                ..CompressOptions::default()
+            }),
+            stats: false,
+        };
+        let ret = Minifier::new(options).build(&allocator, &mut program);
+        let result = CodeGenerator::new()
+            .with_options(CodegenOptions { minify: true, ..CodegenOptions::default() })
+            .with_scoping(ret.scoping)
+            .build(&program)
+            .code;
+        assert_eq!(result, expected, "\nfor source\n{source_text}");
+    };
+
+    test(
+        "export function f() { let keep = 1, other = 2; foo(keep, other); keep = bar(); other = bar() }",
+        "export function f(){let keep=1,e=2;foo(keep,e),keep=bar(),bar()}",
+    );
+    test("foo(function keep() {}, function other() {})", "foo(function keep(){},function(){});");
+    test("foo(class keep {}, class other {})", "foo(class keep{},class{});");
+    test("try { foo() } catch (keep) {}", "try{foo()}catch(keep){}");
+    test("try { foo() } catch (other) {}", "try{foo()}catch{}");
+}
@@ -0,0 +1,53 @@
+use oxc_allocator::Allocator;
+use oxc_minifier::{CompressOptions, Compressor, Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+#[test]
+fn compressor_stats() {
+    let allocator = Allocator::default();
+    let source_text = "if (true) { foo(1 + 2) } else { bar() }";
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let stats = Compressor::new(&allocator, CompressOptions::default())
+        .with_stats(true)
+        .build(&mut program)
+        .stats
+        .unwrap();
+
+    assert!(stats.iterations >= 2);
+    let phases = stats.phases.iter().map(|phase| phase.name).collect::<Vec<_>>();
+    assert_eq!(phases, ["normalize", "peephole", "late_peephole"]);
+    assert_eq!(stats.phases[0].size_before, "if(true){foo(1+2)}else{bar()}".len());
+    assert_eq!(stats.phases[2].size_after, "foo(3);".len());
+    assert_eq!(stats.bytes_saved(), 22);
+
+    let changes = |name: &str| {
+        stats.optimizations.iter().find(|optimization| optimization.name == name).map(|p| p.changes)
+    };
+    assert!(changes("fold_constants").is_some_and(|n| n > 0));
+    assert!(changes("remove_dead_code").is_some_and(|n| n > 0));
+    assert_eq!(changes("replace_known_methods"), None);
+
+    // One entry for each AST walk until the fixed point
+    assert_eq!(stats.iteration_stats.len(), stats.iterations as usize);
+    let first = &stats.iteration_stats[0];
+    assert_eq!(first.size_before, stats.phases[0].size_after);
+    assert!(first.bytes_saved() > 0);
+    assert!(first.optimizations.iter().any(|optimization| optimization.name == "fold_constants"));
+    let last = stats.iteration_stats.last().unwrap();
+    assert!(last.optimizations.is_empty());
+    assert_eq!(last.bytes_saved(), 0);
+    assert_eq!(last.size_after, stats.phases[1].size_after);
+}
+
+#[test]
+fn stats_are_opt_in() {
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, "foo(1 + 2)", SourceType::mjs()).parse().program;
+    let ret = Minifier::new(MinifierOptions::default()).build(&allocator, &mut program);
+    assert!(ret.stats.is_none());
+
+    let options = MinifierOptions { stats: true, ..MinifierOptions::default() };
+    let ret = Minifier::new(options).build(&allocator, &mut program);
+    assert!(ret.stats.is_some());
+}
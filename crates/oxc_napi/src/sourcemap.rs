@@ -0,0 +1,71 @@
+use napi_derive::napi;
+
+/// Source map aligned with Rollup's `ExistingRawSourceMap`,
+/// with the `x_google_ignoreList` and `debugId` fields of the generated source map.
+#[napi(object)]
+pub struct SourceMap {
+    pub file: Option<String>,
+    pub mappings: String,
+    pub names: Vec<String>,
+    pub source_root: Option<String>,
+    pub sources: Vec<String>,
+    pub sources_content: Option<Vec<String>>,
+    pub version: u8,
+    #[napi(js_name = "x_google_ignoreList")]
+    pub x_google_ignorelist: Option<Vec<u32>>,
+    pub debug_id: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Default, Debug, Clone)]
+pub struct SourcemapOptions {
+    /// Include the original source code in `sourcesContent`.
+    ///
+    /// @default true
+    pub sources_content: Option<bool>,
+
+    /// Set `sourceRoot` of the source map.
+    pub source_root: Option<String>,
+
+    /// Add a deterministic `debugId` to the source map and a `//# debugId=` comment to the end of the code.
+    ///
+    /// @default false
+    pub debug_id: Option<bool>,
+
+    /// Add the file to `x_google_ignoreList` if its path contains any of these strings, e.g. `node_modules`.
+    pub ignore_list: Option<Vec<String>>,
+}
+
+#[cfg(feature = "codegen")]
+impl SourcemapOptions {
+    /// Set the source map options of `options`, keeping the others.
+    pub fn apply(&self, options: &mut oxc_codegen::CodegenOptions) {
+        if let Some(sources_content) = self.sources_content {
+            options.source_map_sources_content = sources_content;
+        }
+        options.source_map_source_root.clone_from(&self.source_root);
+        if let Some(debug_id) = self.debug_id {
+            options.source_map_debug_id = debug_id;
+        }
+        options.source_map_ignore_list = self.ignore_list.clone().unwrap_or_default();
+    }
+}
+
+impl From<oxc_sourcemap::SourceMap> for SourceMap {
+    fn from(source_map: oxc_sourcemap::SourceMap) -> Self {
+        let json = source_map.to_json();
+        Self {
+            file: json.file,
+            mappings: json.mappings,
+            names: json.names,
+            source_root: json.source_root,
+            sources: json.sources,
+            sources_content: json.sources_content.map(|content| {
+                content.into_iter().map(Option::unwrap_or_default).collect::<Vec<_>>()
+            }),
+            version: 3,
+            x_google_ignorelist: json.x_google_ignore_list,
+            debug_id: json.debug_id,
+        }
+    }
+}
@@ -8,6 +8,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     Context, ParserImpl, diagnostics,
     lexer::{Kind, LexerCheckpoint, LexerContext, Token},
+    module_record::ModuleRecordCheckpoint,
 };
 
 #[derive(Clone, Copy)]
@@ -16,6 +17,8 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_len: usize,
+    module_record: ModuleRecordCheckpoint,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -123,6 +126,7 @@ impl<'a> ParserImpl<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.push_token();
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_token();
     }
@@ -131,6 +135,7 @@ impl<'a> ParserImpl<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance_for_jsx_child(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.push_token();
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_jsx_child();
     }
@@ -178,6 +183,14 @@ impl<'a> ParserImpl<'a> {
         Ok(())
     }
 
+    /// Record the current token before moving past it, if token collection is enabled
+    #[inline]
+    fn push_token(&mut self) {
+        if self.options.tokens && !self.token.kind.is_eof() {
+            self.tokens.push(self.token);
+        }
+    }
+
     pub(crate) fn can_insert_semicolon(&self) -> bool {
         let kind = self.cur_kind();
         if kind == Kind::Semicolon {
@@ -199,7 +212,17 @@ impl<'a> ParserImpl<'a> {
     /// # Errors
     #[inline]
     pub(crate) fn expect(&mut self, kind: Kind) -> Result<()> {
-        self.expect_without_advance(kind)?;
+        if let Err(error) = self.expect_without_advance(kind) {
+            // Synthesize missing closing brackets at the end of file when recovering
+            if self.options.recover
+                && self.at(Kind::Eof)
+                && matches!(kind, Kind::RCurly | Kind::RParen | Kind::RBrack)
+            {
+                self.error(error);
+                return Ok(());
+            }
+            return Err(error);
+        }
         self.advance(kind);
         Ok(())
     }
@@ -278,17 +301,27 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_len: self.tokens.len(),
+            module_record: self.module_record_builder.checkpoint(),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos: errors_lens } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos: errors_lens,
+            tokens_len,
+            module_record,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_lens);
+        self.tokens.truncate(tokens_len);
+        self.module_record_builder.rewind(module_record);
     }
 
     /// # Errors
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 
-use crate::modifiers::Modifier;
+use crate::{json::JsonKind, modifiers::Modifier};
 
 #[inline]
 fn ts_error<C, M>(code: C, message: M) -> OxcDiagnostic
@@ -558,3 +558,44 @@ pub fn computed_property_names_not_allowed_in_enums(span: Span) -> OxcDiagnostic
 pub fn enum_member_cannot_have_numeric_name(span: Span) -> OxcDiagnostic {
     ts_error("2452", "An enum member cannot have a numeric name.").with_label(span)
 }
+
+// ==================================== JSON ===================================
+
+#[cold]
+pub fn json_comment(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Comments are not allowed in JSON")
+        .with_label(span)
+        .with_help("Parse the file as JSONC or JSON5 to allow comments")
+}
+
+#[cold]
+pub fn json_trailing_comma(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Trailing commas are not allowed in JSON")
+        .with_label(span)
+        .with_help("Remove the trailing comma")
+}
+
+#[cold]
+pub fn json_value(kind: JsonKind, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid {kind} value")).with_label(span)
+}
+
+#[cold]
+pub fn json_property_key(kind: JsonKind, span: Span) -> OxcDiagnostic {
+    let help = if kind == JsonKind::Json5 {
+        "Property keys must be strings or identifiers"
+    } else {
+        "Property keys must be double quoted strings"
+    };
+    OxcDiagnostic::error(format!("Invalid {kind} property key")).with_label(span).with_help(help)
+}
+
+#[cold]
+pub fn json_string(kind: JsonKind, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid {kind} string")).with_label(span)
+}
+
+#[cold]
+pub fn json_number(kind: JsonKind, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid {kind} number")).with_label(span)
+}
@@ -0,0 +1,372 @@
+//! Incremental reparsing
+//!
+//! Reparse only the top-level statements affected by an edit, and reuse the rest of the
+//! previous AST. See [`Parser::reparse`](crate::Parser::reparse).
+
+use oxc_allocator::Vec;
+use oxc_ast::{
+    AstBuilder,
+    ast::{Comment, CommentPosition, Program},
+};
+use oxc_ast_visit::VisitMut;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::module_record::{DynamicImport, ModuleRecord};
+
+use crate::{MAX_LEN, ParserImpl, ParserReturn, context::StatementContext, lexer::Kind};
+
+/// Range of top-level statements to reparse.
+struct Region {
+    /// Index of first statement to reparse.
+    first: usize,
+    /// Index of first statement after the region, which is reused.
+    after: usize,
+    /// Start offset of region. Same in old and new source text.
+    start: u32,
+    /// End offset of region in old source text.
+    old_end: u32,
+    /// Amount to add to offsets after the region to get offsets in new source text (wrapping).
+    delta: u32,
+}
+
+impl Region {
+    fn end(&self) -> u32 {
+        self.old_end.wrapping_add(self.delta)
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Reparse after `edit`, reusing unaffected top-level statements of `previous`.
+    ///
+    /// `edit` is the span of the previous source text which was replaced.
+    ///
+    /// Returns `None` if the edit cannot be handled incrementally, and a full parse is required.
+    pub(crate) fn try_reparse(
+        mut self,
+        previous: ParserReturn<'a>,
+        edit: Span,
+    ) -> Option<ParserReturn<'a>> {
+        let region = self.reparse_region(&previous, edit)?;
+
+        // Parse statements in the region
+        self.lexer.set_offset(region.start);
+        self.bump_any();
+        let region_end = region.end();
+        let mut statements = self.ast.vec();
+        while self.cur_token().start < region_end && !self.at(Kind::Eof) {
+            let stmt = self.parse_statement_list_item(StatementContext::StatementList).ok()?;
+            statements.push(stmt);
+        }
+        self.check_unfinished_errors();
+        // The edit must not have changed how source after the region is tokenized or parsed.
+        // Recoverable errors require a full parse to report them in the same way.
+        if self.cur_token().start != region_end
+            || self.prev_token_end > region_end
+            || !self.errors.is_empty()
+            || !self.lexer.errors.is_empty()
+        {
+            return None;
+        }
+
+        let ParserReturn { program: old_program, module_record: old_module_record, .. } = previous;
+        let Program { hashbang, directives, body: old_body, comments: old_comments, .. } =
+            old_program;
+
+        // Splice reparsed statements between unaffected statements, shifting spans after the region
+        let mut shifter = SpanShifter { delta: region.delta };
+        let mut body = self
+            .ast
+            .vec_with_capacity(old_body.len() - (region.after - region.first) + statements.len());
+        let mut old_body = old_body.into_iter();
+        body.extend(old_body.by_ref().take(region.first));
+        body.extend(statements);
+        body.extend(old_body.skip(region.after - region.first).map(|mut stmt| {
+            shifter.visit_statement(&mut stmt);
+            stmt
+        }));
+
+        let comments = self.splice_comments(&old_comments, &region);
+        let irregular_whitespaces = splice_spans(
+            &previous.irregular_whitespaces,
+            &self.lexer.trivia_builder.irregular_whitespaces,
+            &region,
+        )
+        .into_boxed_slice();
+
+        #[expect(clippy::cast_possible_truncation)]
+        let span = Span::new(0, self.source_text.len() as u32);
+        let program = self.ast.program(
+            span,
+            self.source_type,
+            self.source_text,
+            comments,
+            hashbang,
+            directives,
+            body,
+        );
+
+        // Static imports and exports are collected from all top-level statements.
+        // Dynamic imports and `import.meta` were collected while parsing the region.
+        for stmt in &program.body {
+            if let Some(module_decl) = stmt.as_module_declaration() {
+                self.module_record_builder.visit_module_declaration(module_decl);
+            }
+        }
+        let (mut module_record, module_record_errors) = self.module_record_builder.build();
+        splice_module_record(&mut module_record, &old_module_record, &region, self.ast);
+        // Skip checking for exports in TypeScript
+        let errors = if self.source_type.is_typescript() { vec![] } else { module_record_errors };
+
+        Some(ParserReturn {
+            program,
+            module_record,
+            errors,
+            irregular_whitespaces,
+            panicked: false,
+            is_flow_language: false,
+            tokens: self.ast.vec(),
+        })
+    }
+
+    /// Find the range of top-level statements which need to be reparsed.
+    #[expect(clippy::cast_possible_truncation)]
+    fn reparse_region(&self, previous: &ParserReturn<'a>, edit: Span) -> Option<Region> {
+        let program = &previous.program;
+        let old_text = program.source_text;
+        let new_text = self.source_text;
+
+        // Cases which are not supported:
+        // * Previous parse had errors, or `Program` shape depends on the whole file.
+        // * Tokens are collected, or regular expression patterns are parsed
+        //   (their spans are not visited when shifting).
+        if previous.panicked
+            || !previous.errors.is_empty()
+            || previous.is_flow_language
+            || self.options.tokens
+            || self.options.parse_regular_expression
+            || self.source_type.is_unambiguous()
+            || program.source_type != self.source_type
+            || new_text.len() > MAX_LEN
+        {
+            return None;
+        }
+
+        // Source text outside of the edit must be unchanged
+        let (edit_start, edit_end) = (edit.start as usize, edit.end as usize);
+        let suffix_len = old_text.len().checked_sub(edit_end)?;
+        if edit_start > edit_end
+            || edit_start + suffix_len > new_text.len()
+            || old_text.get(..edit_start)? != new_text.get(..edit_start)?
+            || old_text.get(edit_end..)? != new_text.get(new_text.len() - suffix_len..)?
+        {
+            return None;
+        }
+
+        // Statements touching the edit, plus the statement before, which can be affected by ASI.
+        // The first statement is never reused, as it could become a directive.
+        let body = &program.body;
+        let first =
+            body.iter().position(|stmt| stmt.span().end >= edit.start).unwrap_or(body.len());
+        let first = first.checked_sub(1).filter(|&first| first > 0)?;
+        let after = body
+            .iter()
+            .position(|stmt| stmt.span().start > edit.end)
+            .unwrap_or(body.len())
+            .max(first);
+
+        let start = body[first - 1].span().end;
+        let old_end = body.get(after).map_or(old_text.len() as u32, |stmt| stmt.span().start);
+        let delta = (new_text.len() as u32).wrapping_sub(old_text.len() as u32);
+        Some(Region { first, after, start, old_end, delta })
+    }
+
+    /// Combine comments before and after the region with comments lexed in the region.
+    fn splice_comments(&self, old_comments: &[Comment], region: &Region) -> Vec<'a, Comment> {
+        let region_end = region.end();
+        let before = old_comments.iter().take_while(|comment| comment.span.end <= region.start);
+        let within =
+            self.lexer.trivia_builder.comments.iter().filter(|comment| {
+                comment.span.start >= region.start && comment.span.end <= region_end
+            });
+        let after = old_comments.iter().filter(|comment| comment.span.start >= region.old_end).map(
+            |comment| {
+                let mut comment = *comment;
+                comment.span = shift_span(comment.span, region.delta);
+                if comment.position == CommentPosition::Leading {
+                    comment.attached_to = comment.attached_to.wrapping_add(region.delta);
+                }
+                comment
+            },
+        );
+        self.ast.vec_from_iter(before.chain(within).copied().chain(after))
+    }
+}
+
+/// Add dynamic imports and `import.meta`s outside the region from `old_module_record`.
+fn splice_module_record<'a>(
+    module_record: &mut ModuleRecord<'a>,
+    old_module_record: &ModuleRecord<'a>,
+    region: &Region,
+    ast: AstBuilder<'a>,
+) {
+    let dynamic_imports = splice_dynamic_imports(
+        &old_module_record.dynamic_imports,
+        &module_record.dynamic_imports,
+        region,
+    );
+    module_record.dynamic_imports = ast.vec_from_iter(dynamic_imports);
+
+    let require_calls = splice_dynamic_imports(
+        &old_module_record.require_calls,
+        &module_record.require_calls,
+        region,
+    );
+    module_record.require_calls = ast.vec_from_iter(require_calls);
+
+    let import_metas =
+        splice_spans(&old_module_record.import_metas, &module_record.import_metas, region);
+    module_record.has_module_syntax |= !import_metas.is_empty();
+    module_record.import_metas = ast.vec_from_iter(import_metas);
+}
+
+/// Combine dynamic imports before and after the region from `old` with those in the region from `new`.
+fn splice_dynamic_imports(
+    old: &[DynamicImport],
+    new: &[DynamicImport],
+    region: &Region,
+) -> std::vec::Vec<DynamicImport> {
+    let before = old.iter().filter(|import| import.span.end <= region.start);
+    let after = old.iter().filter(|import| import.span.start >= region.old_end);
+    before
+        .chain(new)
+        .copied()
+        .chain(after.map(|import| DynamicImport {
+            span: shift_span(import.span, region.delta),
+            module_request: shift_span(import.module_request, region.delta),
+        }))
+        .collect()
+}
+
+/// Combine spans before and after the region from `old` with spans in the region from `new`.
+fn splice_spans(old: &[Span], new: &[Span], region: &Region) -> std::vec::Vec<Span> {
+    let region_end = region.end();
+    old.iter()
+        .filter(|span| span.end <= region.start)
+        .chain(new.iter().filter(|span| span.start >= region.start && span.end <= region_end))
+        .copied()
+        .chain(
+            old.iter()
+                .filter(|span| span.start >= region.old_end)
+                .map(|span| shift_span(*span, region.delta)),
+        )
+        .collect()
+}
+
+fn shift_span(span: Span, delta: u32) -> Span {
+    Span::new(span.start.wrapping_add(delta), span.end.wrapping_add(delta))
+}
+
+/// Shift all spans in AST by `delta` (wrapping, so can also shift backwards).
+struct SpanShifter {
+    delta: u32,
+}
+
+impl VisitMut<'_> for SpanShifter {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = shift_span(*span, self.delta);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::{SourceType, Span};
+
+    use crate::{ParseOptions, Parser, ParserImpl, ParserReturn, UniquePromise};
+
+    /// Replace `old[start..end]` with `insert`, and check reparsing gives the same result as
+    /// a full parse. Returns whether the reparse was done incrementally.
+    fn reparse(old: &str, start: usize, end: usize, insert: &str) -> bool {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let new = allocator.alloc_str(&format!("{}{insert}{}", &old[..start], &old[end..]));
+        #[expect(clippy::cast_possible_truncation)]
+        let edit = Span::new(start as u32, end as u32);
+
+        let expected = Parser::new(&allocator, new, source_type).parse();
+
+        let previous = Parser::new(&allocator, old, source_type).parse();
+        let ret = Parser::new(&allocator, new, source_type).reparse(previous, edit);
+        assert_same(&ret, &expected, new);
+
+        let previous = Parser::new(&allocator, old, source_type).parse();
+        let unique = UniquePromise::new_for_tests_and_benchmarks();
+        let parser = ParserImpl::new(&allocator, new, source_type, ParseOptions::default(), unique);
+        parser.try_reparse(previous, edit).is_some()
+    }
+
+    fn assert_same(ret: &ParserReturn, expected: &ParserReturn, source_text: &str) {
+        assert_eq!(
+            ret.program.to_estree_ts_json(),
+            expected.program.to_estree_ts_json(),
+            "{source_text}"
+        );
+        assert_eq!(ret.program.comments, expected.program.comments, "{source_text}");
+        assert_eq!(
+            format!("{:?}", ret.module_record),
+            format!("{:?}", expected.module_record),
+            "{source_text}"
+        );
+        assert_eq!(ret.irregular_whitespaces, expected.irregular_whitespaces, "{source_text}");
+        assert_eq!(ret.errors.len(), expected.errors.len(), "{source_text}");
+    }
+
+    #[test]
+    fn reparse_statements() {
+        let source = "a;\nb;\nc;\nd;\n";
+        // Replace `c` with `foo(c)`
+        assert!(reparse(source, 6, 7, "foo(c)"));
+        // Insert statement
+        assert!(reparse(source, 9, 9, "x = 1;\n"));
+        // Delete statement
+        assert!(reparse(source, 6, 9, ""));
+        // Append statement
+        assert!(reparse(source, 12, 12, "e;\n"));
+        // Edit in function body
+        assert!(reparse("a;\nb;\nfunction f() {\n  return 1;\n}\nc;", 30, 31, "x + y"));
+    }
+
+    #[test]
+    fn reparse_comments() {
+        let source = "a; // a\n/* b */ b; // b\n/** c */\nc; /* c */\nd; // d\n";
+        assert!(reparse(source, 33, 34, "foo"));
+        assert!(reparse(source, 24, 44, ""));
+        assert!(reparse(source, 44, 44, "/* x */ x; // x\n"));
+        assert!(reparse(source, 33, 34, "cc \u{a0}"));
+    }
+
+    #[test]
+    fn reparse_module_record() {
+        let source = "import x from 'x';\na;\nb;\nexport { x };\nimport('y');\nimport.meta.z;\n";
+        assert!(reparse(source, 22, 23, "import('b')"));
+        assert!(reparse(source, 22, 23, "export const y = import.meta.y"));
+        assert!(reparse(source, 22, 23, "export { x as z }"));
+        // Duplicate export
+        assert!(reparse(source, 22, 23, "export { x }"));
+    }
+
+    #[test]
+    fn reparse_fallback() {
+        let source = "a;\nb;\nc;\nd;\n";
+        // Edit in first statements
+        assert!(!reparse(source, 0, 1, "x"));
+        assert!(!reparse(source, 3, 4, "x"));
+        // Edit affects code after it
+        assert!(!reparse(source, 6, 6, "/*"));
+        assert!(!reparse(source, 6, 6, "`"));
+        assert!(!reparse("a;\nb;\nc;\n(d);\n", 7, 8, ""));
+        // Syntax error
+        assert!(!reparse(source, 6, 7, "+"));
+        // Previous parse had an error
+        assert!(!reparse("a;\nb;\nc +;\nd;\n", 8, 9, "+ 1"));
+    }
+}
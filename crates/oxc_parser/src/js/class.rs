@@ -159,6 +159,9 @@ impl<'a> ParserImpl<'a> {
 
     fn parse_class_body(&mut self) -> Result<Box<'a, ClassBody<'a>>> {
         let span = self.start_span();
+        if self.try_skip_body() {
+            return Ok(self.ast.alloc_class_body(self.end_span(span), self.ast.vec()));
+        }
         let class_elements =
             self.parse_normal_list(Kind::LCurly, Kind::RCurly, Self::parse_class_element)?;
         Ok(self.ast.alloc_class_body(self.end_span(span), class_elements))
@@ -922,13 +922,13 @@ impl<'a> ParserImpl<'a> {
             )
         })?;
         self.expect(Kind::RParen)?;
-        Ok(self.ast.expression_call(
-            self.end_span(lhs_span),
-            lhs,
-            type_parameters,
-            call_arguments,
-            optional,
-        ))
+        let span = self.end_span(lhs_span);
+        if let (Expression::Identifier(ident), [argument]) = (&lhs, call_arguments.as_slice()) {
+            if ident.name == "require" && !optional && !argument.is_spread() {
+                self.module_record_builder.visit_require_call(span, argument.span());
+            }
+        }
+        Ok(self.ast.expression_call(span, lhs, type_parameters, call_arguments, optional))
     }
 
     fn parse_call_argument(&mut self) -> Result<Argument<'a>> {
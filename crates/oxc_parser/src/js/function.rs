@@ -30,6 +30,13 @@ impl<'a> ParserImpl<'a> {
 
     pub(crate) fn parse_function_body(&mut self) -> Result<Box<'a, FunctionBody<'a>>> {
         let span = self.start_span();
+        if self.try_skip_body() {
+            return Ok(self.ast.alloc_function_body(
+                self.end_span(span),
+                self.ast.vec(),
+                self.ast.vec(),
+            ));
+        }
         self.expect(Kind::LCurly)?;
 
         let (directives, statements) = self.context(Context::Return, Context::empty(), |p| {
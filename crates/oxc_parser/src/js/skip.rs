@@ -0,0 +1,207 @@
+use oxc_span::Span;
+
+use crate::{ParserImpl, lexer::Kind};
+
+/// A dynamic `import()` or `require()` call found while skipping a body.
+struct ScannedCall {
+    is_import: bool,
+    /// Start of the callee.
+    start: u32,
+    /// Number of open delimiters inside the call's parentheses.
+    depth: usize,
+    /// Span of the first argument.
+    argument: Option<Span>,
+    /// Whether the first argument is followed by a `,`.
+    after_argument: bool,
+    /// Whether there is more than one argument.
+    has_more_arguments: bool,
+}
+
+/// Module record entries found while skipping a body.
+/// Only added to the module record once the whole body has been skipped.
+enum ScannedEntry {
+    DynamicImport(Span, Span),
+    RequireCall(Span, Span),
+    ImportMeta(Span),
+}
+
+impl ParserImpl<'_> {
+    /// Skip a `{ ... }` function or class body when
+    /// [`ParseOptions::module_scan`](crate::ParseOptions::module_scan) is enabled.
+    ///
+    /// Dynamic `import()`, `require()` calls and `import.meta` in the body are recorded in the
+    /// module record. Brackets and template substitutions are kept balanced while skipping,
+    /// and a `/` is read as a regular expression if the previous token cannot end an expression.
+    ///
+    /// Returns `false` and rewinds if the body cannot be skipped reliably, so it has to be
+    /// parsed normally. This is the case for lexer errors, for `/` or `<` after `)` or `}`,
+    /// and for `<` in a position where it may start JSX or a TypeScript type assertion.
+    pub(crate) fn try_skip_body(&mut self) -> bool {
+        if !self.options.module_scan || !self.at(Kind::LCurly) {
+            return false;
+        }
+        let checkpoint = self.checkpoint();
+        let lexer_errors = self.lexer.errors.len();
+        let mut delimiters = std::vec::Vec::new();
+        let mut calls: std::vec::Vec<ScannedCall> = std::vec::Vec::new();
+        let mut entries = std::vec::Vec::new();
+        let mut prev = self.cur_token();
+        let mut prev_prev = prev;
+        loop {
+            let depth = delimiters.len();
+            match self.cur_kind() {
+                Kind::LCurly | Kind::LParen | Kind::LBrack | Kind::TemplateHead => {
+                    delimiters.push(self.cur_kind());
+                }
+                Kind::RCurly if delimiters.last() == Some(&Kind::TemplateHead) => {
+                    self.re_lex_template_substitution_tail();
+                    if self.at(Kind::TemplateTail) {
+                        delimiters.pop();
+                    }
+                }
+                Kind::RCurly | Kind::RParen | Kind::RBrack => {
+                    delimiters.pop();
+                }
+                // `)` and `}` end an expression in `(a) / b` and `({}) / b`, but not in
+                // `if (a) /re/` and `{} /re/`, which cannot be told apart without parsing.
+                Kind::Slash | Kind::SlashEq | Kind::LAngle
+                    if matches!(prev.kind, Kind::RParen | Kind::RCurly) =>
+                {
+                    break;
+                }
+                Kind::Slash | Kind::SlashEq if Self::regex_allowed_after(prev.kind) => {
+                    if self.read_regex().is_err() {
+                        break;
+                    }
+                }
+                Kind::LAngle
+                    if (self.source_type.is_jsx() || self.is_ts)
+                        && Self::regex_allowed_after(prev.kind) =>
+                {
+                    break;
+                }
+                Kind::Eof | Kind::Undetermined => break,
+                _ => {}
+            }
+            let token = self.cur_token();
+
+            // Track the arguments of calls which are still open.
+            for call in &mut calls {
+                if depth < call.depth || (depth == call.depth && token.kind == Kind::RParen) {
+                    continue;
+                }
+                if depth == call.depth && token.kind == Kind::Comma {
+                    call.after_argument = true;
+                } else if call.after_argument {
+                    call.has_more_arguments = true;
+                } else {
+                    let start = call.argument.map_or(token.start, |span| span.start);
+                    call.argument = Some(Span::new(start, token.end));
+                }
+            }
+
+            match token.kind {
+                Kind::LParen if !matches!(prev_prev.kind, Kind::Dot | Kind::QuestionDot) => {
+                    let is_import = prev.kind == Kind::Import;
+                    // `import("mod")` may be an import type in TypeScript
+                    if is_import && self.is_ts {
+                        break;
+                    }
+                    if is_import || (prev.kind == Kind::Require && !prev.escaped()) {
+                        calls.push(ScannedCall {
+                            is_import,
+                            start: prev.start,
+                            depth: delimiters.len(),
+                            argument: None,
+                            after_argument: false,
+                            has_more_arguments: false,
+                        });
+                    }
+                }
+                Kind::RParen if calls.last().is_some_and(|call| call.depth == depth) => {
+                    let call = calls.pop().unwrap();
+                    let span = Span::new(call.start, token.end);
+                    let is_method = match self.peek_kind() {
+                        // Method named `import` or `require`
+                        Kind::LCurly => true,
+                        // Return type of a method in TypeScript, or a conditional expression
+                        Kind::Colon if self.is_ts => break,
+                        _ => false,
+                    };
+                    match call.argument {
+                        _ if is_method => {}
+                        Some(argument) if call.is_import => {
+                            entries.push(ScannedEntry::DynamicImport(span, argument));
+                        }
+                        Some(argument) if !call.has_more_arguments => {
+                            entries.push(ScannedEntry::RequireCall(span, argument));
+                        }
+                        _ => {}
+                    }
+                }
+                Kind::Meta if prev.kind == Kind::Dot && prev_prev.kind == Kind::Import => {
+                    entries.push(ScannedEntry::ImportMeta(Span::new(prev_prev.start, token.end)));
+                }
+                _ => {}
+            }
+
+            self.bump_any();
+            if delimiters.is_empty() {
+                if self.lexer.errors.len() != lexer_errors {
+                    break;
+                }
+                for entry in entries {
+                    match entry {
+                        ScannedEntry::DynamicImport(span, module_request) => {
+                            self.module_record_builder.visit_dynamic_import(span, module_request);
+                        }
+                        ScannedEntry::RequireCall(span, module_request) => {
+                            self.module_record_builder.visit_require_call(span, module_request);
+                        }
+                        ScannedEntry::ImportMeta(span) => {
+                            self.module_record_builder.visit_import_meta(span);
+                        }
+                    }
+                }
+                return true;
+            }
+            prev_prev = prev;
+            prev = token;
+        }
+        self.rewind(checkpoint);
+        false
+    }
+
+    /// Whether a `/` after a token of `kind` starts a regular expression, rather than being
+    /// a division operator.
+    fn regex_allowed_after(kind: Kind) -> bool {
+        match kind {
+            Kind::Ident
+            | Kind::PrivateIdentifier
+            | Kind::This
+            | Kind::Super
+            | Kind::RParen
+            | Kind::RBrack
+            | Kind::RCurly
+            | Kind::NoSubstitutionTemplate
+            | Kind::TemplateTail
+            | Kind::Plus2
+            | Kind::Minus2 => false,
+            Kind::Return
+            | Kind::Typeof
+            | Kind::Instanceof
+            | Kind::In
+            | Kind::Of
+            | Kind::New
+            | Kind::Delete
+            | Kind::Void
+            | Kind::Throw
+            | Kind::Case
+            | Kind::Do
+            | Kind::Else
+            | Kind::Yield
+            | Kind::Await => true,
+            _ => !kind.is_literal() && !kind.is_identifier_name(),
+        }
+    }
+}
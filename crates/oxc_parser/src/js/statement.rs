@@ -5,7 +5,9 @@ use oxc_span::{Atom, GetSpan, Span};
 
 use super::{VariableDeclarationParent, grammar::CoverGrammar};
 use crate::{
-    Context, ParserImpl, StatementContext, diagnostics, lexer::Kind, modifiers::Modifiers,
+    Context, ParserImpl, StatementContext, diagnostics,
+    lexer::{Kind, LexerContext},
+    modifiers::Modifiers,
 };
 
 impl<'a> ParserImpl<'a> {
@@ -40,7 +42,7 @@ impl<'a> ParserImpl<'a> {
             if !is_top_level && self.at(Kind::RCurly) {
                 break;
             }
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.parse_statement_list_item_or_recover(is_top_level)?;
 
             if is_top_level {
                 if let Some(module_decl) = stmt.as_module_declaration() {
@@ -73,6 +75,85 @@ impl<'a> ParserImpl<'a> {
         Ok((directives, statements))
     }
 
+    /// Parse a `StatementListItem`, or an [`ErrorStatement`] covering the unparseable source
+    /// text when [`ParseOptions::recover`](crate::ParseOptions::recover) is enabled.
+    fn parse_statement_list_item_or_recover(
+        &mut self,
+        is_top_level: bool,
+    ) -> Result<Statement<'a>> {
+        if !self.options.recover {
+            return self.parse_statement_list_item(StatementContext::StatementList);
+        }
+        let checkpoint = self.checkpoint();
+        let ctx = self.ctx;
+        let lexer_errors = self.lexer.errors.len();
+        match self.parse_statement_list_item(StatementContext::StatementList) {
+            Ok(stmt) => Ok(stmt),
+            Err(error) if self.overlong_error().is_none() => {
+                // The lexer error of the current token may have been taken as `error`.
+                let lexer_errors = lexer_errors.min(self.lexer.errors.len());
+                let attempt_lexer_errors = self.lexer.errors.split_off(lexer_errors);
+                self.rewind(checkpoint);
+                self.ctx = ctx;
+                self.lexer.set_context(LexerContext::Regular);
+                self.error(error);
+                let span = self.start_span();
+                self.skip_to_statement_boundary(is_top_level);
+                // Skipping re-lexes the tokens of the failed attempt, and lexes them without the
+                // context of the parser. Keep the lexer errors of the attempt instead, except for
+                // those past the skipped tokens which are reported again when lexing them next.
+                self.lexer.errors.truncate(lexer_errors);
+                let end = self.prev_token_end;
+                self.lexer.errors.extend(attempt_lexer_errors.into_iter().filter(|error| {
+                    error.labels.as_ref().and_then(|labels| labels.first()).is_none_or(|label| {
+                        u32::try_from(label.offset()).is_ok_and(|offset| offset < end)
+                    })
+                }));
+                Ok(self.ast.statement_error(self.end_span(span)))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Skip tokens until the end of the current statement, which is either a `;`, the first
+    /// token on a new line, or the `}` closing the enclosing block.
+    /// Brackets and template substitutions are kept balanced while skipping.
+    /// At least one token is always consumed.
+    fn skip_to_statement_boundary(&mut self, is_top_level: bool) {
+        let mut delimiters = std::vec::Vec::new();
+        loop {
+            match self.cur_kind() {
+                Kind::LCurly | Kind::LParen | Kind::LBrack | Kind::TemplateHead => {
+                    delimiters.push(self.cur_kind());
+                }
+                Kind::RCurly if delimiters.last() == Some(&Kind::TemplateHead) => {
+                    self.re_lex_template_substitution_tail();
+                    if self.at(Kind::TemplateTail) {
+                        delimiters.pop();
+                    }
+                }
+                Kind::RCurly | Kind::RParen | Kind::RBrack => {
+                    delimiters.pop();
+                }
+                _ => {}
+            }
+            let kind = self.cur_kind();
+            self.bump_any();
+            if kind == Kind::Eof || (delimiters.is_empty() && kind == Kind::Semicolon) {
+                break;
+            }
+            if !delimiters.is_empty() {
+                continue;
+            }
+            if self.at(Kind::Eof)
+                || (self.at(Kind::RCurly) && !is_top_level)
+                || self.cur_token().is_on_new_line
+            {
+                break;
+            }
+        }
+    }
+
     /// `StatementListItem`[Yield, Await, Return] :
     ///     Statement[?Yield, ?Await, ?Return]
     ///     Declaration[?Yield, ?Await]
@@ -199,7 +280,7 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::LCurly)?;
         let mut body = self.ast.vec();
         while !self.at(Kind::RCurly) && !self.at(Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.parse_statement_list_item_or_recover(/* is_top_level */ false)?;
             body.push(stmt);
         }
         self.expect(Kind::RCurly)?;
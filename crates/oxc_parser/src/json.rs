@@ -0,0 +1,350 @@
+//! JSON, JSONC and JSON5
+//!
+//! JSON documents are parsed as a single JavaScript expression, which is then checked to only
+//! contain the syntax allowed by the [`JsonKind`]. See [`Parser::parse_json`](crate::Parser::parse_json).
+
+use std::{fmt, path::Path};
+
+use oxc_ast::ast::{
+    ArrayExpression, ArrayExpressionElement, Expression, ObjectExpression, ObjectPropertyKind,
+    Program, PropertyKey, PropertyKind, UnaryOperator,
+};
+use oxc_diagnostics::{OxcDiagnostic, Result};
+use oxc_span::{GetSpan, Span};
+
+use crate::{ParserImpl, ParserReturn, diagnostics, lexer::Kind};
+
+/// Flavor of JSON accepted by [`Parser::parse_json`](crate::Parser::parse_json).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JsonKind {
+    /// [RFC 8259](https://www.rfc-editor.org/rfc/rfc8259) JSON, e.g. `package.json`.
+    #[default]
+    Json,
+    /// JSON with comments and trailing commas, e.g. `tsconfig.json` and `.oxlintrc.json`.
+    Jsonc,
+    /// [JSON5](https://spec.json5.org), which also allows unquoted keys, single quoted strings,
+    /// hexadecimal numbers, `Infinity` and `NaN`.
+    Json5,
+}
+
+impl JsonKind {
+    /// Infer the [`JsonKind`] from a file path.
+    ///
+    /// Returns `None` if the path is not a JSON file. `tsconfig.json`, `jsconfig.json` and
+    /// `.oxlintrc.json` are JSONC.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let path = path.as_ref();
+        let file_name = path.file_name()?.to_str()?;
+        match path.extension()?.to_str()? {
+            "jsonc" => Some(Self::Jsonc),
+            "json5" => Some(Self::Json5),
+            "json"
+                if file_name.starts_with("tsconfig.")
+                    || file_name.starts_with("jsconfig.")
+                    || file_name == ".oxlintrc.json" =>
+            {
+                Some(Self::Jsonc)
+            }
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    fn allows_comments(self) -> bool {
+        self != Self::Json
+    }
+
+    fn allows_trailing_commas(self) -> bool {
+        self != Self::Json
+    }
+}
+
+impl fmt::Display for JsonKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Json => "JSON",
+            Self::Jsonc => "JSONC",
+            Self::Json5 => "JSON5",
+        })
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    pub(crate) fn parse_json(mut self, kind: JsonKind) -> ParserReturn<'a> {
+        let (program, panicked) = match self.parse_json_program(kind) {
+            Ok(program) => (program, false),
+            Err(error) => {
+                self.error(self.overlong_error().unwrap_or(error));
+                let program = self.ast.program(
+                    Span::default(),
+                    self.source_type,
+                    self.source_text,
+                    self.ast.vec(),
+                    None,
+                    self.ast.vec(),
+                    self.ast.vec(),
+                );
+                (program, true)
+            }
+        };
+
+        let mut errors = self.lexer.errors;
+        errors.extend(self.errors);
+        let (module_record, _) = self.module_record_builder.build();
+        ParserReturn {
+            program,
+            module_record,
+            errors,
+            irregular_whitespaces: self
+                .lexer
+                .trivia_builder
+                .irregular_whitespaces
+                .into_boxed_slice(),
+            panicked,
+            is_flow_language: false,
+            tokens: self.tokens,
+        }
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn parse_json_program(&mut self, kind: JsonKind) -> Result<Program<'a>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let expr = self.parse_expr()?;
+        if !self.at(Kind::Eof) {
+            return Err(self.unexpected());
+        }
+
+        let comments = self.ast.vec_from_iter(self.lexer.trivia_builder.comments.iter().copied());
+        if !kind.allows_comments() {
+            for comment in &comments {
+                self.error(diagnostics::json_comment(comment.span));
+            }
+        }
+        let mut checker = JsonChecker { kind, source_text: self.source_text, errors: vec![] };
+        checker.check_value(&expr);
+        self.errors.extend(checker.errors);
+
+        let span = Span::new(0, self.source_text.len() as u32);
+        let stmt = self.ast.statement_expression(expr.span(), expr);
+        Ok(self.ast.program(
+            span,
+            self.source_type,
+            self.source_text,
+            comments,
+            None,
+            self.ast.vec(),
+            self.ast.vec1(stmt),
+        ))
+    }
+}
+
+/// Reports syntax which is valid JavaScript, but not allowed by the [`JsonKind`].
+struct JsonChecker<'a> {
+    kind: JsonKind,
+    source_text: &'a str,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl JsonChecker<'_> {
+    fn check_value(&mut self, expr: &Expression) {
+        match expr {
+            Expression::ObjectExpression(object) => self.check_object(object),
+            Expression::ArrayExpression(array) => self.check_array(array),
+            Expression::StringLiteral(lit) => self.check_string(lit.span),
+            Expression::NumericLiteral(lit) => self.check_number(lit.span),
+            Expression::BooleanLiteral(_) | Expression::NullLiteral(_) => {}
+            Expression::Identifier(ident)
+                if self.kind == JsonKind::Json5
+                    && matches!(ident.name.as_str(), "Infinity" | "NaN") => {}
+            Expression::UnaryExpression(unary) => {
+                let allowed_operator = match unary.operator {
+                    UnaryOperator::UnaryNegation => true,
+                    UnaryOperator::UnaryPlus => self.kind == JsonKind::Json5,
+                    _ => false,
+                };
+                let allowed_argument = match &unary.argument {
+                    Expression::NumericLiteral(_) => true,
+                    Expression::Identifier(_) => self.kind == JsonKind::Json5,
+                    _ => false,
+                };
+                // JSON does not allow whitespace after `-`
+                let adjacent = self.kind == JsonKind::Json5
+                    || unary.argument.span().start == unary.span.start + 1;
+                if allowed_operator && allowed_argument && adjacent {
+                    self.check_value(&unary.argument);
+                } else {
+                    self.invalid_value(unary.span);
+                }
+            }
+            _ => self.invalid_value(expr.span()),
+        }
+    }
+
+    fn check_object(&mut self, object: &ObjectExpression) {
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                self.invalid_value(property.span());
+                continue;
+            };
+            if property.kind != PropertyKind::Init
+                || property.method
+                || property.shorthand
+                || property.computed
+            {
+                self.invalid_value(property.span);
+                continue;
+            }
+            match &property.key {
+                PropertyKey::StringLiteral(lit) => self.check_string(lit.span),
+                PropertyKey::StaticIdentifier(_) if self.kind == JsonKind::Json5 => {}
+                key => self.errors.push(diagnostics::json_property_key(self.kind, key.span())),
+            }
+            self.check_value(&property.value);
+        }
+        if let Some(last) = object.properties.last() {
+            self.check_trailing_comma(last.span().end, object.span.end);
+        }
+    }
+
+    fn check_array(&mut self, array: &ArrayExpression) {
+        for element in &array.elements {
+            match element {
+                ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_) => {
+                    self.invalid_value(element.span());
+                }
+                _ => self.check_value(element.to_expression()),
+            }
+        }
+        if let Some(last) = array.elements.last() {
+            self.check_trailing_comma(last.span().end, array.span.end);
+        }
+    }
+
+    fn check_string(&mut self, span: Span) {
+        if self.kind == JsonKind::Json5 {
+            if !is_json5_string(span.source_text(self.source_text)) {
+                self.errors.push(diagnostics::json_string(self.kind, span));
+            }
+        } else if !is_json_string(span.source_text(self.source_text)) {
+            self.errors.push(diagnostics::json_string(self.kind, span));
+        }
+    }
+
+    fn check_number(&mut self, span: Span) {
+        let raw = span.source_text(self.source_text);
+        let valid =
+            if self.kind == JsonKind::Json5 { is_json5_number(raw) } else { is_json_number(raw) };
+        if !valid {
+            self.errors.push(diagnostics::json_number(self.kind, span));
+        }
+    }
+
+    /// Check for a `,` between the last element and the closing bracket ending at `end`.
+    fn check_trailing_comma(&mut self, start: u32, end: u32) {
+        if self.kind.allows_trailing_commas() {
+            return;
+        }
+        let text = &self.source_text[start as usize..end as usize - 1];
+        if let Some(offset) = text.find(',') {
+            #[expect(clippy::cast_possible_truncation)]
+            let start = start + offset as u32;
+            self.errors.push(diagnostics::json_trailing_comma(Span::sized(start, 1)));
+        }
+    }
+
+    fn invalid_value(&mut self, span: Span) {
+        self.errors.push(diagnostics::json_value(self.kind, span));
+    }
+}
+
+/// `"` followed by characters other than control characters, with only
+/// `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` and `\uXXXX` escapes.
+fn is_json_string(raw: &str) -> bool {
+    let Some(content) = raw.strip_prefix('"').and_then(|raw| raw.strip_suffix('"')) else {
+        return false;
+    };
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't') => {}
+                Some('u') => {
+                    if !(0..4).all(|_| chars.next().is_some_and(|c| c.is_ascii_hexdigit())) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            },
+            '\0'..='\u{1f}' => return false,
+            _ => {}
+        }
+    }
+    true
+}
+
+/// ES5 strings, which do not have legacy octal escape sequences.
+fn is_json5_string(raw: &str) -> bool {
+    let mut chars = raw.chars().skip(1).peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('1'..='9') => return false,
+                Some('0') if chars.peek().is_some_and(char::is_ascii_digit) => return false,
+                _ => {}
+            }
+        }
+    }
+    true
+}
+
+/// `0` or a digit sequence not starting with `0`, with optional fraction and exponent.
+/// The sign is parsed as a unary expression.
+fn is_json_number(raw: &str) -> bool {
+    let bytes = raw.as_bytes();
+    let digits = |i: usize| bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+
+    let mut i = match digits(0) {
+        0 => return false,
+        n if n > 1 && bytes[0] == b'0' => return false,
+        n => n,
+    };
+    if bytes.get(i) == Some(&b'.') {
+        match digits(i + 1) {
+            0 => return false,
+            n => i += 1 + n,
+        }
+    }
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        i += 1;
+        if matches!(bytes.get(i), Some(b'+' | b'-')) {
+            i += 1;
+        }
+        match digits(i) {
+            0 => return false,
+            n => i += n,
+        }
+    }
+    i == bytes.len()
+}
+
+/// ES5 numeric literals: decimal with optional leading or trailing `.`, or hexadecimal.
+fn is_json5_number(raw: &str) -> bool {
+    if let Some(hex) = raw.strip_prefix("0x").or_else(|| raw.strip_prefix("0X")) {
+        return !hex.is_empty() && hex.bytes().all(|b| b.is_ascii_hexdigit());
+    }
+    let (mantissa, exponent) = match raw.find(['e', 'E']) {
+        Some(index) => (&raw[..index], Some(&raw[index + 1..])),
+        None => (raw, None),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    let valid_integer = is_digits(integer) && !(integer.len() > 1 && integer.starts_with('0'));
+    let valid_mantissa =
+        valid_integer && is_digits(fraction) && !(integer.is_empty() && fraction.is_empty());
+    let valid_exponent = exponent.is_none_or(|exponent| {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        !exponent.is_empty() && is_digits(exponent)
+    });
+    valid_mantissa && valid_exponent
+}
@@ -8,191 +8,353 @@ use std::fmt;
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Kind {
+    /// Token whose kind is not determined yet
     Undetermined,
+    /// End of file
     #[default]
     Eof,
-    Skip, // Whitespace, line breaks, comments
+    /// Whitespace, line breaks and comments
+    Skip,
     // 12.5 Hashbang Comments
+    /// Hashbang comment `#!` at the start of the file
     HashbangComment,
     // 12.7.1 identifier
+    /// Identifier which is not a keyword
     Ident,
     // 12.7.2 keyword
+    /// `await`
     Await,
+    /// `break`
     Break,
+    /// `case`
     Case,
+    /// `catch`
     Catch,
+    /// `class`
     Class,
+    /// `const`
     Const,
+    /// `continue`
     Continue,
+    /// `debugger`
     Debugger,
+    /// `default`
     Default,
+    /// `delete`
     Delete,
+    /// `do`
     Do,
+    /// `else`
     Else,
+    /// `enum`
     Enum,
+    /// `export`
     Export,
+    /// `extends`
     Extends,
+    /// `finally`
     Finally,
+    /// `for`
     For,
+    /// `function`
     Function,
+    /// `if`
     If,
+    /// `import`
     Import,
+    /// `in`
     In,
+    /// `instanceof`
     Instanceof,
+    /// `new`
     New,
+    /// `return`
     Return,
+    /// `super`
     Super,
+    /// `switch`
     Switch,
+    /// `this`
     This,
+    /// `throw`
     Throw,
+    /// `try`
     Try,
+    /// `typeof`
     Typeof,
+    /// `var`
     Var,
+    /// `void`
     Void,
+    /// `while`
     While,
+    /// `with`
     With,
     // Contextual Keywords
+    /// `async`
     Async,
+    /// `from`
     From,
+    /// `get`
     Get,
-    Meta, // import.meta
+    /// `meta` of `import.meta`
+    Meta,
+    /// `of`
     Of,
+    /// `set`
     Set,
-    Target,   // new.target
-    Accessor, // keyword from https://github.com/tc39/proposal-decorators
-    Source,   // import.source https://github.com/tc39/proposal-source-phase-imports
-    Defer,    // import.defer https://github.com/tc39/proposal-defer-import-eval
+    /// `target` of `new.target`
+    Target,
+    /// `accessor` of <https://github.com/tc39/proposal-decorators>
+    Accessor,
+    /// `source` of `import.source`, <https://github.com/tc39/proposal-source-phase-imports>
+    Source,
+    /// `defer` of `import.defer`, <https://github.com/tc39/proposal-defer-import-eval>
+    Defer,
     // TypeScript Contextual Keywords
+    /// `abstract`
     Abstract,
+    /// `as`
     As,
+    /// `asserts`
     Asserts,
+    /// `assert`
     Assert,
+    /// `any`
     Any,
+    /// `boolean`
     Boolean,
+    /// `constructor`
     Constructor,
+    /// `declare`
     Declare,
+    /// `infer`
     Infer,
+    /// `intrinsic`
     Intrinsic,
+    /// `is`
     Is,
+    /// `keyof`
     KeyOf,
+    /// `module`
     Module,
+    /// `namespace`
     Namespace,
+    /// `never`
     Never,
+    /// `out`
     Out,
+    /// `readonly`
     Readonly,
+    /// `require`
     Require,
-    Number, // the "number" keyword for TypeScript
+    /// TypeScript `number` keyword
+    Number,
+    /// `object`
     Object,
+    /// `satisfies`
     Satisfies,
-    String, // the "string" keyword for TypeScript
+    /// TypeScript `string` keyword
+    String,
+    /// `symbol`
     Symbol,
+    /// `type`
     Type,
+    /// `undefined`
     Undefined,
+    /// `unique`
     Unique,
+    /// `using`
     Using,
+    /// `unknown`
     Unknown,
+    /// `global`
     Global,
-    BigInt, // the "bigint" keyword for TypeScript
+    /// TypeScript `bigint` keyword
+    BigInt,
+    /// `override`
     Override,
     // Future keywords (strict mode reserved words)
+    /// `implements`
     Implements,
+    /// `interface`
     Interface,
+    /// `let`
     Let,
+    /// `package`
     Package,
+    /// `private`
     Private,
+    /// `protected`
     Protected,
+    /// `public`
     Public,
+    /// `static`
     Static,
+    /// `yield`
     Yield,
     // 12.8 punctuators
-    Amp, // &
+    /// `&`
+    Amp,
+    /// `&&`
     Amp2,
+    /// `&&=`
     Amp2Eq,
+    /// `&=`
     AmpEq,
-    Bang, // !
+    /// `!`
+    Bang,
+    /// `^`
     Caret,
+    /// `^=`
     CaretEq,
+    /// `:`
     Colon,
+    /// `,`
     Comma,
+    /// `.`
     Dot,
-    Dot3, // ...
+    /// `...`
+    Dot3,
+    /// `=`
     Eq,
+    /// `==`
     Eq2,
+    /// `===`
     Eq3,
-    GtEq, // >=
+    /// `>=`
+    GtEq,
+    /// `<`
     LAngle,
+    /// `[`
     LBrack,
+    /// `{`
     LCurly,
+    /// `(`
     LParen,
-    LtEq, // <=
+    /// `<=`
+    LtEq,
+    /// `-`
     Minus,
+    /// `--`
     Minus2,
+    /// `-=`
     MinusEq,
+    /// `!=`
     Neq,
+    /// `!==`
     Neq2,
+    /// `%`
     Percent,
+    /// `%=`
     PercentEq,
+    /// `|`
     Pipe,
+    /// `||`
     Pipe2,
+    /// `||=`
     Pipe2Eq,
+    /// `|=`
     PipeEq,
+    /// `+`
     Plus,
+    /// `++`
     Plus2,
+    /// `+=`
     PlusEq,
+    /// `?`
     Question,
+    /// `??`
     Question2,
+    /// `??=`
     Question2Eq,
+    /// `?.`
     QuestionDot,
+    /// `>`
     RAngle,
+    /// `]`
     RBrack,
+    /// `}`
     RCurly,
+    /// `)`
     RParen,
+    /// `;`
     Semicolon,
-    ShiftLeft,     // <<
-    ShiftLeftEq,   // <<=
-    ShiftRight,    // >>
-    ShiftRight3,   // >>>
-    ShiftRight3Eq, // >>>=
-    ShiftRightEq,  // >>=
+    /// `<<`
+    ShiftLeft,
+    /// `<<=`
+    ShiftLeftEq,
+    /// `>>`
+    ShiftRight,
+    /// `>>>`
+    ShiftRight3,
+    /// `>>>=`
+    ShiftRight3Eq,
+    /// `>>=`
+    ShiftRightEq,
+    /// `/`
     Slash,
+    /// `/=`
     SlashEq,
+    /// `*`
     Star,
+    /// `**`
     Star2,
+    /// `**=`
     Star2Eq,
+    /// `*=`
     StarEq,
+    /// `~`
     Tilde,
     // arrow function
+    /// `=>`
     Arrow,
     // 12.9.1 Null Literals
+    /// `null`
     Null,
     // 12.9.2 Boolean Literals
+    /// `true`
     True,
+    /// `false`
     False,
     // 12.9.3 Numeric Literals
+    /// Decimal numeric literal, e.g. `10`
     Decimal,
+    /// Numeric literal with a fraction, e.g. `1.5`
     Float,
+    /// Binary numeric literal, e.g. `0b10`
     Binary,
+    /// Octal numeric literal, e.g. `0o10` or legacy `010`
     Octal,
+    /// Hexadecimal numeric literal, e.g. `0x10`
     Hex,
-    // for `1e10`, `1e+10`
+    /// Numeric literal with a positive exponent, e.g. `1e10` or `1e+10`
     PositiveExponential,
-    // for `1e-10`
+    /// Numeric literal with a negative exponent, e.g. `1e-10`
     NegativeExponential,
     // 12.9.4 String Literals
-    /// String Type
+    /// String literal
     Str,
     // 12.9.5 Regular Expression Literals
+    /// Regular expression literal
     RegExp,
     // 12.9.6 Template Literal
+    /// Template literal without substitutions, e.g. `` `a` ``
     NoSubstitutionTemplate,
+    /// Start of a template literal up to the first substitution, e.g. `` `a${ ``
     TemplateHead,
+    /// Part of a template literal between two substitutions, e.g. `}b${`
     TemplateMiddle,
+    /// End of a template literal after the last substitution, e.g. `` }c` ``
     TemplateTail,
     // es2022 Private Identifier
+    /// Private identifier, e.g. `#a`
     PrivateIdentifier,
     // JSX
+    /// Text of a JSX element
     JSXText,
     // Decorator
+    /// `@` of decorators
     At,
 }
 
@@ -200,11 +362,13 @@ pub enum Kind {
 use Kind::*;
 
 impl Kind {
+    /// End of file
     #[inline]
     pub fn is_eof(self) -> bool {
         matches!(self, Eof)
     }
 
+    /// Numeric literal
     #[inline]
     pub fn is_number(self) -> bool {
         matches!(
@@ -213,6 +377,11 @@ impl Kind {
         )
     }
 
+    /// Whether `b` is a digit of this kind of numeric literal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is not [`Kind::Decimal`], [`Kind::Binary`], [`Kind::Octal`] or [`Kind::Hex`].
     #[inline] // Inline into `read_non_decimal` - see comment there as to why
     pub fn matches_number_byte(self, b: u8) -> bool {
         match self {
@@ -287,6 +456,7 @@ impl Kind {
         matches!(self, Null | True | False | Str | RegExp) || self.is_number()
     }
 
+    /// Whether the token after `await` or `yield` can start its operand
     #[inline]
     pub fn is_after_await_or_yield(self) -> bool {
         !self.is_binary_operator() && (self.is_literal() || self.is_identifier_name())
@@ -302,6 +472,7 @@ impl Kind {
         self.is_identifier_name() || self == Str || self.is_number()
     }
 
+    /// `LiteralPropertyName`, `PrivateIdentifier` or any keyword
     #[inline]
     pub fn is_identifier_or_keyword(self) -> bool {
         self.is_literal_property_name()
@@ -309,6 +480,7 @@ impl Kind {
             || self.is_all_keyword()
     }
 
+    /// `var`, `let` or `const`
     #[inline]
     pub fn is_variable_declaration(self) -> bool {
         matches!(self, Var | Let | Const)
@@ -326,6 +498,7 @@ impl Kind {
         self.is_literal_property_name() || matches!(self, LBrack | PrivateIdentifier)
     }
 
+    /// [Assignment operators](https://tc39.es/ecma262/#prod-AssignmentOperator), including `=`
     #[rustfmt::skip]
     #[inline]
     pub fn is_assignment_operator(self) -> bool {
@@ -334,6 +507,7 @@ impl Kind {
             | Star2Eq)
     }
 
+    /// Binary operators, excluding the logical operators
     #[rustfmt::skip]
     #[inline]
     pub fn is_binary_operator(self) -> bool {
@@ -342,16 +516,19 @@ impl Kind {
             | Instanceof | Star2)
     }
 
+    /// `||`, `&&` or `??`
     #[inline]
     pub fn is_logical_operator(self) -> bool {
         matches!(self, Pipe2 | Amp2 | Question2)
     }
 
+    /// [Unary operators](https://tc39.es/ecma262/#sec-unary-operators)
     #[inline]
     pub fn is_unary_operator(self) -> bool {
         matches!(self, Minus | Plus | Bang | Tilde | Typeof | Void | Delete)
     }
 
+    /// `++` or `--`
     #[inline]
     pub fn is_update_operator(self) -> bool {
         matches!(self, Plus2 | Minus2)
@@ -366,6 +543,7 @@ impl Kind {
             || self.is_future_reserved_keyword()
     }
 
+    /// `ReservedWord`
     #[rustfmt::skip]
     #[inline]
     pub fn is_reserved_keyword(self) -> bool {
@@ -375,12 +553,14 @@ impl Kind {
             | True | Try | Typeof | Var | Void | While | With | Yield)
     }
 
+    /// Identifiers which are reserved in strict mode code
     #[rustfmt::skip]
     #[inline]
     pub fn is_strict_mode_contextual_keyword(self) -> bool {
         matches!(self, Let | Static | Implements | Interface | Package | Private | Protected | Public)
     }
 
+    /// Keywords which are identifiers outside of their syntactic context, including the TypeScript keywords
     #[rustfmt::skip]
     #[inline]
     pub fn is_contextual_keyword(self) -> bool {
@@ -392,17 +572,20 @@ impl Kind {
             )
     }
 
+    /// Future reserved words in strict mode code
     #[rustfmt::skip]
     #[inline]
     pub fn is_future_reserved_keyword(self) -> bool {
         matches!(self, Implements | Interface | Package | Private | Protected | Public | Static)
     }
 
+    /// First token of a template literal
     #[inline]
     pub fn is_template_start_of_tagged_template(self) -> bool {
         matches!(self, NoSubstitutionTemplate | TemplateHead)
     }
 
+    /// TypeScript modifiers, e.g. `public` and `readonly`
     #[rustfmt::skip]
     #[inline]
     pub fn is_modifier_kind(self) -> bool {
@@ -410,11 +593,13 @@ impl Kind {
           | Export | In | Out | Public | Private | Protected | Readonly | Static | Override)
     }
 
+    /// `BindingIdentifier`, `PrivateIdentifier`, or the start of an object or array pattern
     #[inline]
     pub fn is_binding_identifier_or_private_identifier_or_pattern(self) -> bool {
         matches!(self, LCurly | LBrack | PrivateIdentifier) || self.is_binding_identifier()
     }
 
+    /// Keyword kind of the identifier name `s`, or [`Kind::Ident`] if it is not a keyword
     pub fn match_keyword(s: &str) -> Self {
         let len = s.len();
         if len <= 1 || len >= 12 || !s.as_bytes()[0].is_ascii_lowercase() {
@@ -525,6 +710,7 @@ impl Kind {
         }
     }
 
+    /// Source text of keywords and punctuators, or a description of the other kinds
     pub fn to_str(self) -> &'static str {
         match self {
             Undetermined => "Unknown",
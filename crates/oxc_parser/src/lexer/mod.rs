@@ -166,6 +166,20 @@ impl<'a> Lexer<'a> {
         self.lookahead.clear();
     }
 
+    /// Move to `offset` in source, to continue lexing from there.
+    ///
+    /// Used for reparsing part of a file. `offset` must be the end of a token,
+    /// or start of file.
+    pub(crate) fn set_offset(&mut self, offset: u32) {
+        self.source.set_offset(offset);
+        self.token = if offset == 0 { Token::new_on_new_line() } else { Token::default() };
+        self.lookahead.clear();
+        if offset > 0 {
+            // Treat comments on the same line as `offset` as trailing comments of the previous token
+            self.trivia_builder.handle_token(self.token);
+        }
+    }
+
     /// Find the nth lookahead token lazily
     pub fn lookahead(&mut self, n: u8) -> Token {
         let n = n as usize;
@@ -59,6 +59,8 @@ impl Lexer<'_> {
     }
 
     pub(crate) fn next_right_angle(&mut self) -> Token {
+        // The leading `>` has already been consumed as `Kind::RAngle`
+        self.token.start = self.offset() - 1;
         let kind = self.read_right_angle();
         self.lookahead.clear();
         self.finish_next(kind)
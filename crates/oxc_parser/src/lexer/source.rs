@@ -189,6 +189,17 @@ impl<'a> Source<'a> {
         self.ptr = pos.ptr;
     }
 
+    /// Move current position to `offset` bytes from start of source.
+    ///
+    /// # Panic
+    /// Panics if `offset` is past end of source, or not on a UTF-8 character boundary.
+    pub(super) fn set_offset(&mut self, offset: u32) {
+        assert!(self.whole().is_char_boundary(offset as usize), "Invalid offset {offset}");
+        // SAFETY: `is_char_boundary` returns `false` for offsets past end of source,
+        // so `offset` is in bounds, and on a UTF-8 character boundary (or EOF)
+        self.ptr = unsafe { self.start.add(offset as usize) };
+    }
+
     /// Advance `Source`'s cursor to end.
     #[inline]
     pub(super) fn advance_to_end(&mut self) {
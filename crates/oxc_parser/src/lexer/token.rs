@@ -4,6 +4,12 @@ use oxc_span::Span;
 
 use super::kind::Kind;
 
+/// Lexer token
+///
+/// Collected into [`ParserReturn::tokens`] when [`ParseOptions::tokens`] is enabled.
+///
+/// [`ParserReturn::tokens`]: crate::ParserReturn::tokens
+/// [`ParseOptions::tokens`]: crate::ParseOptions::tokens
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Token {
     /// Token Kind
@@ -44,14 +50,17 @@ impl Token {
         Self { is_on_new_line: true, ..Self::default() }
     }
 
+    /// Span of the token in source
     pub fn span(&self) -> Span {
         Span::new(self.start, self.end)
     }
 
+    /// Whether the token contains escape sequences, see [`Token::escaped`](#structfield.escaped)
     pub fn escaped(&self) -> bool {
         self.escaped
     }
 
+    /// Whether a numeric literal token contains `_` separators
     #[inline]
     pub fn has_separator(&self) -> bool {
         debug_assert!(!self.has_separator || self.kind.is_number());
@@ -64,6 +73,54 @@ impl Token {
     }
 }
 
+#[cfg(feature = "serialize")]
+mod estree {
+    use oxc_estree::{ESTreeToken, ESTreeTokenRegex, ESTreeTokenType};
+
+    use super::{Kind, Token};
+
+    impl Token {
+        /// Convert to an [`ESTreeToken`], as produced by ESTree parsers' `tokens` option.
+        ///
+        /// Returns `None` for tokens which have no ESTree equivalent (end of file, or a token
+        /// which failed to lex).
+        pub fn to_estree<'a>(&self, source_text: &'a str) -> Option<ESTreeToken<'a>> {
+            let value = &source_text[self.start as usize..self.end as usize];
+            let (r#type, value, regex) = match self.kind {
+                Kind::Undetermined | Kind::Eof | Kind::Skip | Kind::HashbangComment => {
+                    return None;
+                }
+                Kind::True | Kind::False => (ESTreeTokenType::Boolean, value, None),
+                Kind::Null => (ESTreeTokenType::Null, value, None),
+                Kind::Str => (ESTreeTokenType::String, value, None),
+                Kind::RegExp => {
+                    let pattern_end = value.rfind('/').unwrap_or(value.len());
+                    let regex = ESTreeTokenRegex {
+                        pattern: &value[1.min(pattern_end)..pattern_end],
+                        flags: value.get(pattern_end + 1..).unwrap_or_default(),
+                    };
+                    (ESTreeTokenType::RegularExpression, value, Some(regex))
+                }
+                Kind::NoSubstitutionTemplate
+                | Kind::TemplateHead
+                | Kind::TemplateMiddle
+                | Kind::TemplateTail => (ESTreeTokenType::Template, value, None),
+                Kind::PrivateIdentifier => {
+                    (ESTreeTokenType::PrivateIdentifier, value.strip_prefix('#')?, None)
+                }
+                Kind::JSXText => (ESTreeTokenType::JSXText, value, None),
+                kind if kind.is_number() => (ESTreeTokenType::Numeric, value, None),
+                kind if kind.is_reserved_keyword() || matches!(kind, Kind::Let | Kind::Static) => {
+                    (ESTreeTokenType::Keyword, value, None)
+                }
+                kind if kind.is_identifier_name() => (ESTreeTokenType::Identifier, value, None),
+                _ => (ESTreeTokenType::Punctuator, value, None),
+            };
+            Some(ESTreeToken { r#type, value, regex, start: self.start, end: self.end })
+        }
+    }
+}
+
 #[cfg(test)]
 mod size_asserts {
     use super::Token;
@@ -68,6 +68,8 @@
 
 mod context;
 mod cursor;
+mod incremental;
+mod json;
 mod modifiers;
 mod module_record;
 mod state;
@@ -85,7 +87,7 @@ mod lexer;
 #[doc(hidden)]
 pub mod lexer;
 
-use oxc_allocator::{Allocator, Box as ArenaBox};
+use oxc_allocator::{Allocator, Box as ArenaBox, Vec as ArenaVec};
 use oxc_ast::{
     AstBuilder,
     ast::{Expression, Program},
@@ -96,10 +98,14 @@ use oxc_syntax::module_record::ModuleRecord;
 
 use crate::{
     context::{Context, StatementContext},
-    lexer::{Kind, Lexer, Token},
+    lexer::Lexer,
     module_record::ModuleRecordBuilder,
     state::ParserState,
 };
+pub use crate::{
+    json::JsonKind,
+    lexer::{Kind, Token},
+};
 
 /// Maximum length of source which can be parsed (in bytes).
 /// ~4 GiB on 64-bit systems, ~2 GiB on 32-bit systems.
@@ -134,6 +140,8 @@ pub(crate) const MAX_LEN: usize = if std::mem::size_of::<usize>() >= 8 {
 ///
 /// When the parser cannot recover, it will abort and terminate parsing early. [`program`] will
 /// be empty and [`panicked`] will be `true`.
+/// With [`ParseOptions::recover`] enabled, unparseable statements are replaced with
+/// [`ErrorStatement`](oxc_ast::ast::ErrorStatement)s instead, and [`program`] is always usable.
 ///
 /// [`program`]: ParserReturn::program
 /// [`errors`]: ParserReturn::errors
@@ -178,6 +186,12 @@ pub struct ParserReturn<'a> {
 
     /// Whether the file is [flow](https://flow.org).
     pub is_flow_language: bool,
+
+    /// Tokens consumed by the parser, in source order.
+    ///
+    /// Only collected when [`ParseOptions::tokens`] is enabled, otherwise empty.
+    /// Comments and whitespace are not included.
+    pub tokens: ArenaVec<'a, Token>,
 }
 
 /// Parse options
@@ -218,6 +232,36 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Collect the tokens consumed by the parser into [`ParserReturn::tokens`].
+    ///
+    /// Default: `false`
+    pub tokens: bool,
+
+    /// Recover from syntax errors instead of aborting.
+    ///
+    /// When enabled, a statement which cannot be parsed is reported in [`ParserReturn::errors`]
+    /// and replaced with an [`ErrorStatement`] spanning the skipped source text, and missing
+    /// closing brackets at the end of the file are synthesized. The returned [`Program`] is
+    /// always usable, so semantic analysis and linting can still run on the rest of the file.
+    ///
+    /// Default: `false`
+    ///
+    /// [`ErrorStatement`]: oxc_ast::ast::ErrorStatement
+    pub recover: bool,
+
+    /// Skip function and class bodies instead of building their AST.
+    ///
+    /// Bodies are lexed through to find the closing `}`, and dynamic `import()`, `require()`
+    /// and `import.meta` inside them are still recorded in [`ParserReturn::module_record`].
+    /// The returned [`Program`] contains all top-level declarations, but every function and
+    /// class body in it is empty. This is much faster than a full parse when only the module
+    /// graph is needed, e.g. for resolving imports.
+    ///
+    /// Bodies which cannot be skipped reliably, e.g. bodies containing JSX, are parsed as usual.
+    ///
+    /// Default: `false`
+    pub module_scan: bool,
 }
 
 impl Default for ParseOptions {
@@ -227,6 +271,9 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            tokens: false,
+            recover: false,
+            module_scan: false,
         }
     }
 }
@@ -314,6 +361,86 @@ mod parser_parse {
             parser.parse()
         }
 
+        /// Reparse after an edit, reusing unaffected top-level statements of a previous parse.
+        ///
+        /// `previous` is the result of parsing the source text before the edit, and `edit` is
+        /// the span of that source text which was replaced. This [`Parser`] must be created with
+        /// the full source text after the edit. `previous` must have been parsed with the same
+        /// allocator.
+        ///
+        /// Only the top-level statements touching the edit (and the statement preceding them)
+        /// are reparsed. Spans of statements after the edit are shifted. The result is the same
+        /// as calling [`Parser::parse`] on the new source text, and falls back to doing so if
+        /// the edit cannot be handled incrementally e.g. if `previous` contains errors, the edit
+        /// affects the first statement, or the edit changes how the code after it is parsed.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::Parser;
+        /// use oxc_span::{SourceType, Span};
+        ///
+        /// let allocator = Allocator::new();
+        /// let source_type = SourceType::mjs();
+        /// let ret = Parser::new(&allocator, "a;\nb;\nc;", source_type).parse();
+        /// // Replace `b` with `foo(b)`
+        /// let ret = Parser::new(&allocator, "a;\nfoo(b);\nc;", source_type)
+        ///     .reparse(ret, Span::new(3, 4));
+        /// assert_eq!(ret.program.body.len(), 3);
+        /// ```
+        pub fn reparse(self, previous: ParserReturn<'a>, edit: Span) -> ParserReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            if let Some(ret) = parser.try_reparse(previous, edit) {
+                return ret;
+            }
+            // `ParserImpl` from above has been dropped, so can create another `UniquePromise`
+            self.parse()
+        }
+
+        /// Parse a JSON, JSONC or JSON5 document.
+        ///
+        /// The returned [`Program`] contains a single [`ExpressionStatement`] holding the
+        /// document's value, e.g. an [`ObjectExpression`] for `package.json`. Syntax which is
+        /// valid JavaScript but not allowed by `kind` is reported in [`ParserReturn::errors`].
+        ///
+        /// The source type given to [`Parser::new`] should be [`SourceType::default`].
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{JsonKind, Parser};
+        /// use oxc_span::SourceType;
+        ///
+        /// let src = r#"{ "compilerOptions": { "strict": true, }, /* comment */ }"#;
+        /// let allocator = Allocator::new();
+        /// let ret = Parser::new(&allocator, src, SourceType::default()).parse_json(JsonKind::Jsonc);
+        /// assert!(ret.errors.is_empty());
+        /// ```
+        ///
+        /// [`ExpressionStatement`]: oxc_ast::ast::ExpressionStatement
+        /// [`ObjectExpression`]: oxc_ast::ast::ObjectExpression
+        pub fn parse_json(self, kind: JsonKind) -> ParserReturn<'a> {
+            let unique = UniquePromise::new();
+            let options = ParseOptions { preserve_parens: true, ..self.options };
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                options,
+                unique,
+            );
+            parser.parse_json(kind)
+        }
+
         /// Parse a single [`Expression`].
         ///
         /// # Example
@@ -371,6 +498,9 @@ struct ParserImpl<'a> {
     /// The end range of the previous token
     prev_token_end: u32,
 
+    /// Consumed tokens, only collected when `options.tokens` is enabled
+    tokens: ArenaVec<'a, Token>,
+
     /// Parser state
     state: ParserState<'a>,
 
@@ -408,6 +538,7 @@ impl<'a> ParserImpl<'a> {
             errors: vec![],
             token: Token::default(),
             prev_token_end: 0,
+            tokens: ArenaVec::new_in(allocator),
             state: ParserState::default(),
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
@@ -478,6 +609,7 @@ impl<'a> ParserImpl<'a> {
             irregular_whitespaces,
             panicked,
             is_flow_language,
+            tokens: self.tokens,
         }
     }
 
@@ -593,7 +725,7 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression, Statement};
+    use oxc_ast::ast::{CommentKind, Declaration, Expression, Statement};
     use oxc_span::GetSpan;
 
     use super::*;
@@ -665,6 +797,199 @@ mod test {
         }
     }
 
+    #[test]
+    fn tokens() {
+        let allocator = Allocator::default();
+        let opts = ParseOptions { tokens: true, ..ParseOptions::default() };
+        let cases = [
+            ("x", "let a = 1;", vec!["let", "a", "=", "1", ";"]),
+            // Arrow function parameters are parsed speculatively and rewound
+            ("x", "(a, b) => a / b", vec!["(", "a", ",", "b", ")", "=>", "a", "/", "b"]),
+            ("x", "x = /[/]/gu // c", vec!["x", "=", "/[/]/gu"]),
+            ("x", "`a${b}c${d}`", vec!["`a${", "b", "}c${", "d", "}`"]),
+            (
+                "ts",
+                "let a: A<B<C>> = b >> c;",
+                vec!["let", "a", ":", "A", "<", "B", "<", "C", ">", ">", "=", "b", ">>", "c", ";"],
+            ),
+            (
+                "jsx",
+                "<a b=\"c\">d</a>",
+                vec!["<", "a", "b", "=", "\"c\"", ">", "d", "<", "/", "a", ">"],
+            ),
+        ];
+        for (ext, source, expected) in cases {
+            let source_type =
+                SourceType::from_path(Path::new("test").with_extension(ext)).unwrap_or_default();
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            let tokens =
+                ret.tokens.iter().map(|token| token.span().source_text(source)).collect::<Vec<_>>();
+            assert_eq!(tokens, expected, "{source}");
+        }
+
+        let ret = Parser::new(&allocator, "let a = 1;", SourceType::default()).parse();
+        assert!(ret.tokens.is_empty());
+    }
+
+    #[test]
+    fn recover() {
+        let allocator = Allocator::default();
+        let opts = ParseOptions { recover: true, ..ParseOptions::default() };
+        let cases = [
+            ("let a = 1;\nlet b = ;\nlet c = 3;", vec!["let a = 1;", "!let b = ;", "let c = 3;"]),
+            ("a b c\nfoo();", vec!["!a b c", "foo();"]),
+            ("x = { a: 1 +\n};\ny();", vec!["!x = { a: 1 +\n};", "y();"]),
+            ("f(`${a +}`, 1); g();", vec!["!f(`${a +}`, 1);", "g();"]),
+            ("function f() {\n  a +;\n  b();\n}", vec!["function f() {\n  a +;\n  b();\n}"]),
+            ("function f() {\n  a();", vec!["function f() {\n  a();"]),
+            ("} a();", vec!["!} a();"]),
+        ];
+        for (source, expected) in cases {
+            let ret =
+                Parser::new(&allocator, source, SourceType::default()).with_options(opts).parse();
+            assert!(!ret.panicked, "{source}");
+            assert!(!ret.errors.is_empty(), "{source}");
+            let body = ret
+                .program
+                .body
+                .iter()
+                .map(|stmt| {
+                    let text = stmt.span().source_text(source);
+                    if matches!(stmt, Statement::ErrorStatement(_)) {
+                        format!("!{text}")
+                    } else {
+                        text.to_string()
+                    }
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(body, expected, "{source}");
+        }
+
+        let source = "function f() {\n  a +;\n  b();\n}";
+        let ret = Parser::new(&allocator, source, SourceType::default()).with_options(opts).parse();
+        let Statement::FunctionDeclaration(func) = &ret.program.body[0] else { unreachable!() };
+        let body = &func.body.as_ref().unwrap().statements;
+        assert!(matches!(body[0], Statement::ErrorStatement(_)));
+        assert!(matches!(body[1], Statement::ExpressionStatement(_)));
+
+        // Lexer errors of a skipped statement are only reported once.
+        let source = "f('a\n');\ng();";
+        let ret = Parser::new(&allocator, source, SourceType::default()).with_options(opts).parse();
+        assert_eq!(ret.errors.len(), 1, "{:?}", ret.errors);
+
+        // Module requests of a skipped statement are not recorded.
+        let source = "f(import('a'), import.meta, require('b') +);\ng();";
+        let ret = Parser::new(&allocator, source, SourceType::cjs()).with_options(opts).parse();
+        assert!(ret.module_record.dynamic_imports.is_empty());
+        assert!(ret.module_record.import_metas.is_empty());
+        assert!(ret.module_record.require_calls.is_empty());
+        assert!(!ret.module_record.has_module_syntax);
+
+        let ret = Parser::new(&allocator, "let a = ;", SourceType::default()).parse();
+        assert!(ret.panicked);
+    }
+
+    #[test]
+    fn module_scan() {
+        let allocator = Allocator::default();
+        let opts = ParseOptions { module_scan: true, ..ParseOptions::default() };
+        let cases = [
+            ("js", "function f() { return import('a') }"),
+            ("js", "class A { static { require(`b`) } m() { return import.meta } }"),
+            ("js", "const f = () => { x = /}/.test(y) / 2; return import(`./${a + `}`}`) }"),
+            ("js", "function f() { require('a', 1); require(x.y(z)); foo.require('b') }"),
+            ("js", "function f() { if (a) /}/.test(x); return import('b') }"),
+            ("js", "function f() { {} /{/.test(s); return (a) / 2 / require('c') }"),
+            ("jsx", "function f() { return <a href='{'>{import('a')}</a> }"),
+            ("js", "function f() { return { import(a) {}, require(b) { return require(c) } } }"),
+            ("ts", "function f<T>() { return <T>require('a') }"),
+            ("ts", "function f(): void { let a: typeof import('a') = import('b') }"),
+            ("ts", "class A { require(a: string): void; require(a) {} }"),
+        ];
+        for (ext, source) in cases {
+            let source_type =
+                SourceType::from_path(Path::new("test").with_extension(ext)).unwrap_or_default();
+            let expected = Parser::new(&allocator, source, source_type).parse();
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            assert_eq!(ret.program.body.len(), expected.program.body.len(), "{source}");
+            let spans = |imports: &[oxc_syntax::module_record::DynamicImport]| {
+                imports.iter().map(|i| (i.span, i.module_request)).collect::<Vec<_>>()
+            };
+            let (module_record, expected_module_record) =
+                (&ret.module_record, &expected.module_record);
+            assert_eq!(
+                spans(&module_record.dynamic_imports),
+                spans(&expected_module_record.dynamic_imports),
+                "{source}"
+            );
+            assert_eq!(
+                spans(&module_record.require_calls),
+                spans(&expected_module_record.require_calls),
+                "{source}"
+            );
+            assert_eq!(module_record.import_metas, expected_module_record.import_metas, "{source}");
+        }
+
+        let source = "import a from 'a';\nexport function f() { g(); }\nexport class B { m() {} }";
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.module_record.import_entries.len(), 1);
+        assert_eq!(ret.module_record.local_export_entries.len(), 2);
+        let Some(Statement::ExportNamedDeclaration(decl)) = ret.program.body.get(1) else {
+            unreachable!()
+        };
+        let Some(Declaration::FunctionDeclaration(func)) = &decl.declaration else {
+            unreachable!()
+        };
+        let body = func.body.as_ref().unwrap();
+        assert!(body.statements.is_empty());
+        assert_eq!(body.span.source_text(source), "{ g(); }");
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn tokens_to_estree() {
+        use oxc_estree::{CompactJSSerializer, ESTree};
+
+        let allocator = Allocator::default();
+        let source = "class A { #x = this.y ?? /a/g; static of = null }";
+        let opts = ParseOptions { tokens: true, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, source, SourceType::default()).with_options(opts).parse();
+        let tokens =
+            ret.tokens.iter().filter_map(|token| token.to_estree(source)).collect::<Vec<_>>();
+        let mut serializer = CompactJSSerializer::new();
+        tokens.as_slice().serialize(&mut serializer);
+        let types = tokens.iter().map(|token| token.r#type.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            [
+                "Keyword",
+                "Identifier",
+                "Punctuator",
+                "PrivateIdentifier",
+                "Punctuator",
+                "Keyword",
+                "Punctuator",
+                "Identifier",
+                "Punctuator",
+                "RegularExpression",
+                "Punctuator",
+                "Keyword",
+                "Identifier",
+                "Punctuator",
+                "Null",
+                "Punctuator",
+            ]
+        );
+        let json = serializer.into_string();
+        assert!(json.contains(r#"{"type":"PrivateIdentifier","value":"x","start":10,"end":12}"#));
+        assert!(json.contains(
+            r#"{"type":"RegularExpression","value":"/a/g","regex":{"pattern":"a","flags":"g"},"start":25,"end":29}"#
+        ));
+    }
+
     #[test]
     fn v8_intrinsics() {
         let allocator = Allocator::default();
@@ -724,6 +1049,101 @@ mod test {
         }
     }
 
+    /// HTML-like comments of scripts, see <https://tc39.es/ecma262/#sec-html-like-comments>.
+    #[test]
+    fn html_comments() {
+        let allocator = Allocator::default();
+        let sources = [
+            ("x = 1; <!-- comment", 1, 1),
+            ("--> comment\nx = 1;", 1, 1),
+            ("x = 1;\n  --> comment", 1, 1),
+            ("x = 1; /*\n*/ --> comment", 2, 1),
+            ("x = 1;\n/* a */ /* b */ --> comment", 3, 1),
+            ("x = a-->b;", 0, 1),
+        ];
+        for (source, comments, body) in sources {
+            let ret = Parser::new(&allocator, source, SourceType::cjs()).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            assert_eq!(ret.program.comments.len(), comments, "{source}");
+            assert_eq!(ret.program.body.len(), body, "{source}");
+        }
+
+        // Not allowed in modules, and `-->` must start a line
+        let sources = [
+            ("x = 1; <!-- comment", SourceType::mjs()),
+            ("x = 1;\n--> comment", SourceType::mjs()),
+            ("x = 1; /* */ --> comment", SourceType::cjs()),
+        ];
+        for (source, source_type) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+    }
+
+    #[test]
+    fn json() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let pass = [
+            (JsonKind::Json, r#"{"a": [1, -2.5e+3, "\u00e9\n", true, false, null], "b": {}}"#),
+            (JsonKind::Json, "0"),
+            (JsonKind::Json, r#""string""#),
+            (JsonKind::Jsonc, "{\n  // comment\n  \"a\": [1, 2,], /* comment */\n}"),
+            (JsonKind::Json5, "{a: 'b', 'c': .5, d: 5., e: 0xFF, f: +Infinity, g: -NaN, h: [1,],}"),
+            (JsonKind::Json5, "'line \\\n continuation \\x41'"),
+        ];
+        for (kind, source) in pass {
+            let ret = Parser::new(&allocator, source, source_type).parse_json(kind);
+            assert!(ret.errors.is_empty(), "{kind} {source}: {:?}", ret.errors);
+            assert_eq!(ret.program.body.len(), 1);
+            let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+            assert_eq!(stmt.span.source_text(source), source);
+        }
+
+        let fail = [
+            (JsonKind::Json, "{\"a\": 1,}", "Trailing commas are not allowed in JSON"),
+            (JsonKind::Json, "[1,]", "Trailing commas are not allowed in JSON"),
+            (JsonKind::Json, "// comment\n1", "Comments are not allowed in JSON"),
+            (JsonKind::Json, "{a: 1}", "Invalid JSON property key"),
+            (JsonKind::Json, "'a'", "Invalid JSON string"),
+            (JsonKind::Json, r#""\x41""#, "Invalid JSON string"),
+            (JsonKind::Json, "\"\t\"", "Invalid JSON string"),
+            (JsonKind::Json, "01", "Invalid JSON number"),
+            (JsonKind::Json, "1.", "Invalid JSON number"),
+            (JsonKind::Json, "0x1", "Invalid JSON number"),
+            (JsonKind::Json, "+1", "Invalid JSON value"),
+            (JsonKind::Json, "- 1", "Invalid JSON value"),
+            (JsonKind::Jsonc, "[1,,2]", "Invalid JSONC value"),
+            (JsonKind::Jsonc, "{\"a\": undefined}", "Invalid JSONC value"),
+            (JsonKind::Jsonc, "({})", "Invalid JSONC value"),
+            (JsonKind::Jsonc, "{a}", "Invalid JSONC value"),
+            (JsonKind::Json5, "{[a]: 1}", "Invalid JSON5 value"),
+            (JsonKind::Json5, "{1: 1}", "Invalid JSON5 property key"),
+            (JsonKind::Json5, "1_000", "Invalid JSON5 number"),
+            (JsonKind::Json5, "0o7", "Invalid JSON5 number"),
+            (JsonKind::Json5, "'\\01'", "Invalid JSON5 string"),
+            (JsonKind::Json5, "`a`", "Invalid JSON5 value"),
+        ];
+        for (kind, source, message) in fail {
+            let ret = Parser::new(&allocator, source, source_type).parse_json(kind);
+            assert!(!ret.panicked, "{kind} {source}");
+            assert!(
+                ret.errors.iter().any(|error| error.message == message),
+                "{kind} {source}: {:?}",
+                ret.errors
+            );
+        }
+
+        let ret = Parser::new(&allocator, "{} {}", source_type).parse_json(JsonKind::Json);
+        assert!(ret.panicked);
+
+        assert_eq!(JsonKind::from_path("package.json"), Some(JsonKind::Json));
+        assert_eq!(JsonKind::from_path("tsconfig.base.json"), Some(JsonKind::Jsonc));
+        assert_eq!(JsonKind::from_path(".oxlintrc.json"), Some(JsonKind::Jsonc));
+        assert_eq!(JsonKind::from_path("a.json5"), Some(JsonKind::Json5));
+        assert_eq!(JsonKind::from_path("a.js"), None);
+    }
+
     #[test]
     fn hashbang() {
         let allocator = Allocator::default();
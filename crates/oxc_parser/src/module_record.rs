@@ -14,6 +14,14 @@ pub struct ModuleRecordBuilder<'a> {
     exported_bindings_duplicated: Vec<NameSpan<'a>>,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct ModuleRecordCheckpoint {
+    has_module_syntax: bool,
+    dynamic_imports_len: usize,
+    import_metas_len: usize,
+    require_calls_len: usize,
+}
+
 impl<'a> ModuleRecordBuilder<'a> {
     pub fn new(allocator: &'a Allocator) -> Self {
         Self {
@@ -24,6 +32,29 @@ impl<'a> ModuleRecordBuilder<'a> {
         }
     }
 
+    /// Record the entries visited so far, see [`ModuleRecordBuilder::rewind`].
+    pub fn checkpoint(&self) -> ModuleRecordCheckpoint {
+        let module_record = &self.module_record;
+        ModuleRecordCheckpoint {
+            has_module_syntax: module_record.has_module_syntax,
+            dynamic_imports_len: module_record.dynamic_imports.len(),
+            import_metas_len: module_record.import_metas.len(),
+            require_calls_len: module_record.require_calls.len(),
+        }
+    }
+
+    /// Discard the entries visited after `checkpoint` was created.
+    ///
+    /// Module declarations are only visited once a top level statement has been parsed,
+    /// so only the entries visited from within expressions need to be restored.
+    pub fn rewind(&mut self, checkpoint: ModuleRecordCheckpoint) {
+        let module_record = &mut self.module_record;
+        module_record.has_module_syntax = checkpoint.has_module_syntax;
+        module_record.dynamic_imports.truncate(checkpoint.dynamic_imports_len);
+        module_record.import_metas.truncate(checkpoint.import_metas_len);
+        module_record.require_calls.truncate(checkpoint.require_calls_len);
+    }
+
     pub fn build(mut self) -> (ModuleRecord<'a>, Vec<OxcDiagnostic>) {
         // The `ParseModule` algorithm requires `importedBoundNames` (import entries) to be
         // resolved before resolving export entries.
@@ -65,7 +96,7 @@ impl<'a> ModuleRecordBuilder<'a> {
         errors
     }
 
-    fn add_module_request(&mut self, name: Atom<'a>, requested_module: RequestedModule) {
+    fn add_module_request(&mut self, name: Atom<'a>, requested_module: RequestedModule<'a>) {
         self.module_record
             .requested_modules
             .entry(name)
@@ -73,6 +104,23 @@ impl<'a> ModuleRecordBuilder<'a> {
             .push(requested_module);
     }
 
+    fn import_attributes(
+        &self,
+        with_clause: Option<&WithClause<'a>>,
+    ) -> oxc_allocator::Vec<'a, ImportAttributeRecord<'a>> {
+        let attributes = with_clause.into_iter().flat_map(|with_clause| {
+            with_clause.with_entries.iter().map(|attribute| {
+                let key = match &attribute.key {
+                    ImportAttributeKey::Identifier(ident) => NameSpan::new(ident.name, ident.span),
+                    ImportAttributeKey::StringLiteral(lit) => NameSpan::new(lit.value, lit.span),
+                };
+                let value = NameSpan::new(attribute.value.value, attribute.value.span);
+                ImportAttributeRecord { key, value }
+            })
+        });
+        oxc_allocator::Vec::from_iter_in(attributes, self.allocator)
+    }
+
     fn add_import_entry(&mut self, entry: ImportEntry<'a>) {
         self.module_record.import_entries.push(entry);
     }
@@ -175,9 +223,15 @@ impl<'a> ModuleRecordBuilder<'a> {
     }
 
     pub fn visit_import_expression(&mut self, e: &ImportExpression<'a>) {
-        self.module_record
-            .dynamic_imports
-            .push(DynamicImport { span: e.span, module_request: e.source.span() });
+        self.visit_dynamic_import(e.span, e.source.span());
+    }
+
+    pub fn visit_dynamic_import(&mut self, span: Span, module_request: Span) {
+        self.module_record.dynamic_imports.push(DynamicImport { span, module_request });
+    }
+
+    pub fn visit_require_call(&mut self, span: Span, module_request: Span) {
+        self.module_record.require_calls.push(DynamicImport { span, module_request });
     }
 
     pub fn visit_import_meta(&mut self, span: Span) {
@@ -236,6 +290,8 @@ impl<'a> ModuleRecordBuilder<'a> {
                     import_name,
                     local_name,
                     is_type,
+                    attributes: self.import_attributes(decl.with_clause.as_deref()),
+                    phase: decl.phase,
                 });
             }
         }
@@ -246,6 +302,8 @@ impl<'a> ModuleRecordBuilder<'a> {
                 span: module_request.span,
                 is_type: decl.import_kind.is_type(),
                 is_import: true,
+                attributes: self.import_attributes(decl.with_clause.as_deref()),
+                phase: decl.phase,
             },
         );
     }
@@ -277,6 +335,8 @@ impl<'a> ModuleRecordBuilder<'a> {
                 span: module_request.span,
                 is_type: decl.export_kind.is_type(),
                 is_import: false,
+                attributes: self.import_attributes(decl.with_clause.as_deref()),
+                phase: None,
             },
         );
     }
@@ -336,6 +396,8 @@ impl<'a> ModuleRecordBuilder<'a> {
                     span: module_request.span,
                     is_type: decl.export_kind.is_type(),
                     is_import: false,
+                    attributes: self.import_attributes(decl.with_clause.as_deref()),
+                    phase: None,
                 },
             );
         }
@@ -393,7 +455,7 @@ impl<'a> ModuleRecordBuilder<'a> {
 
 #[cfg(test)]
 mod module_record_tests {
-    use oxc_allocator::Allocator;
+    use oxc_allocator::{Allocator, Vec};
     use oxc_span::{SourceType, Span};
     use oxc_syntax::module_record::*;
 
@@ -418,6 +480,8 @@ mod module_record_tests {
             import_name: ImportImportName::Default(Span::new(7, 8)),
             local_name: NameSpan::new("v".into(), Span::new(7, 8)),
             is_type: false,
+            attributes: Vec::new_in(&allocator),
+            phase: None,
         };
         assert_eq!(module_record.import_entries.len(), 1);
         assert_eq!(module_record.import_entries[0], import_entry);
@@ -433,6 +497,8 @@ mod module_record_tests {
             import_name: ImportImportName::NamespaceObject,
             local_name: NameSpan::new("ns".into(), Span::new(12, 14)),
             is_type: false,
+            attributes: Vec::new_in(&allocator),
+            phase: None,
         };
         assert_eq!(module_record.import_entries.len(), 1);
         assert_eq!(module_record.import_entries[0], import_entry);
@@ -448,6 +514,8 @@ mod module_record_tests {
             import_name: ImportImportName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
             local_name: NameSpan::new("x".into(), Span::new(9, 10)),
             is_type: false,
+            attributes: Vec::new_in(&allocator),
+            phase: None,
         };
         assert_eq!(module_record.import_entries.len(), 1);
         assert_eq!(module_record.import_entries[0], import_entry);
@@ -463,6 +531,8 @@ mod module_record_tests {
             import_name: ImportImportName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
             local_name: NameSpan::new("v".into(), Span::new(14, 15)),
             is_type: false,
+            attributes: Vec::new_in(&allocator),
+            phase: None,
         };
         assert_eq!(module_record.import_entries.len(), 1);
         assert_eq!(module_record.import_entries[0], import_entry);
@@ -711,4 +781,62 @@ mod module_record_tests {
         assert_eq!(module_record.dynamic_imports[0].span, Span::new(0, 13));
         assert_eq!(module_record.dynamic_imports[0].module_request, Span::new(7, 12));
     }
+
+    #[test]
+    fn require_calls() {
+        let allocator = Allocator::default();
+        let module_record =
+            build(&allocator, "require('foo'); require(a, b); foo.require('bar'); require(...c)");
+        assert_eq!(module_record.require_calls.len(), 1);
+        assert_eq!(module_record.require_calls[0].span, Span::new(0, 14));
+        assert_eq!(module_record.require_calls[0].module_request, Span::new(8, 13));
+    }
+
+    #[test]
+    fn import_attributes() {
+        let allocator = Allocator::default();
+        let module_record = build(
+            &allocator,
+            "import json from './foo.json' with { type: 'json', 'x': 'y' }; export * from 'mod' with { type: 'css' }",
+        );
+        let attributes = Vec::from_array_in(
+            [
+                ImportAttributeRecord {
+                    key: NameSpan::new("type".into(), Span::new(37, 41)),
+                    value: NameSpan::new("json".into(), Span::new(43, 49)),
+                },
+                ImportAttributeRecord {
+                    key: NameSpan::new("x".into(), Span::new(51, 54)),
+                    value: NameSpan::new("y".into(), Span::new(56, 59)),
+                },
+            ],
+            &allocator,
+        );
+        assert_eq!(module_record.import_entries.len(), 1);
+        assert_eq!(module_record.import_entries[0].attributes, attributes);
+        let requested_module = &module_record.requested_modules["./foo.json"][0];
+        assert_eq!(requested_module.attributes, attributes);
+
+        let requested_module = &module_record.requested_modules["mod"][0];
+        assert_eq!(requested_module.attributes.len(), 1);
+        assert_eq!(requested_module.attributes[0].value.name, "css");
+    }
+
+    #[test]
+    fn import_phase() {
+        let allocator = Allocator::default();
+        let module_record = build(
+            &allocator,
+            "import source wasm from './foo.wasm'; import defer * as ns from 'mod'; import 'bar'",
+        );
+        assert_eq!(module_record.import_entries.len(), 2);
+        assert_eq!(module_record.import_entries[0].phase, Some(ImportPhase::Source));
+        assert_eq!(module_record.import_entries[1].phase, Some(ImportPhase::Defer));
+        assert_eq!(
+            module_record.requested_modules["./foo.wasm"][0].phase,
+            Some(ImportPhase::Source)
+        );
+        assert_eq!(module_record.requested_modules["mod"][0].phase, Some(ImportPhase::Defer));
+        assert_eq!(module_record.requested_modules["bar"][0].phase, None);
+    }
 }
@@ -99,6 +99,7 @@ impl<'a> Format<'a> for Statement<'a> {
             Self::TryStatement(stmt) => stmt.format(p),
             Self::WhileStatement(stmt) => stmt.format(p),
             Self::WithStatement(stmt) => stmt.format(p),
+            Self::ErrorStatement(stmt) => stmt.format(p),
             match_module_declaration!(Self) => self.to_module_declaration().format(p),
             match_declaration!(Self) => self.to_declaration().format(p),
         }
@@ -119,6 +120,12 @@ impl<'a> Format<'a> for EmptyStatement {
     }
 }
 
+impl<'a> Format<'a> for ErrorStatement {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        dynamic_text!(p, self.span.source_text(p.source_text).trim_end())
+    }
+}
+
 impl<'a> Format<'a> for IfStatement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         wrap!(p, self, IfStatement, {
@@ -10,7 +10,7 @@ use oxc_syntax::{
     symbol::SymbolFlags,
 };
 
-use crate::SemanticBuilder;
+use crate::{SemanticBuilder, diagnostics::redeclaration};
 
 pub trait Binder<'a> {
     #[expect(unused_variables)]
@@ -44,6 +44,12 @@ impl<'a> Binder<'a> for VariableDeclarator<'a> {
             let mut target_scope_id = builder.current_scope_id;
             let mut var_scope_ids = vec![];
 
+            let is_for_of = builder
+                .nodes
+                .parent_id(builder.current_node_id)
+                .and_then(|id| builder.nodes.parent_kind(id))
+                .is_some_and(|kind| matches!(kind, AstKind::ForOfStatement(_)));
+
             // Collect all scopes where variable hoisting can occur
             for scope_id in builder.scoping.scope_ancestors(target_scope_id) {
                 let flags = builder.scoping.scope_flags(scope_id);
@@ -63,6 +69,27 @@ impl<'a> Binder<'a> for VariableDeclarator<'a> {
                     if let Some(symbol_id) =
                         builder.check_redeclaration(scope_id, span, &name, excludes, true)
                     {
+                        // Function declarations in blocks are lexical declarations,
+                        // e.g. `{ function f() {} var f; }` is a redeclaration.
+                        if builder.scoping.get_binding(scope_id, &name) == Some(symbol_id)
+                            && matches!(
+                                builder.nodes.kind(builder.scoping.symbol_declaration(symbol_id)),
+                                AstKind::Function(_)
+                            )
+                        {
+                            let symbol_span = builder.scoping.symbol_span(symbol_id);
+                            builder.error(redeclaration(&name, symbol_span, span));
+                        }
+                        // `try {} catch (e) { for (var e of []); }` is not allowed
+                        if is_for_of
+                            && builder
+                                .scoping
+                                .symbol_flags(symbol_id)
+                                .contains(SymbolFlags::CatchVariable)
+                        {
+                            let symbol_span = builder.scoping.symbol_span(symbol_id);
+                            builder.error(redeclaration(&name, symbol_span, span));
+                        }
                         builder.add_redeclare_variable(symbol_id, span);
                         declared_symbol_id = Some(symbol_id);
 
@@ -164,7 +191,7 @@ fn is_function_part_of_if_statement(function: &Function, builder: &SemanticBuild
 }
 
 impl<'a> Binder<'a> for Function<'a> {
-    fn bind(&self, builder: &mut SemanticBuilder) {
+    fn bind(&self, builder: &mut SemanticBuilder<'a>) {
         let current_scope_id = builder.current_scope_id;
         let scope_flags = builder.current_scope_flags();
         if let Some(ident) = &self.id {
@@ -181,23 +208,49 @@ impl<'a> Binder<'a> for Function<'a> {
                 // The visitor is already inside the function scope,
                 // retrieve the parent scope for the function id to bind to.
 
-                let (includes, excludes) =
-                    if (scope_flags.is_strict_mode() || self.r#async || self.generator)
-                        && !function_as_var(scope_flags, builder.source_type)
+                let is_lexical = !function_as_var(scope_flags, builder.source_type);
+                let is_annex_b =
+                    is_lexical && !scope_flags.is_strict_mode() && !self.r#async && !self.generator;
+                let (includes, excludes) = if is_lexical && !is_annex_b {
+                    (
+                        SymbolFlags::Function | SymbolFlags::BlockScopedVariable,
+                        SymbolFlags::BlockScopedVariableExcludes,
+                    )
+                } else {
+                    (
+                        SymbolFlags::FunctionScopedVariable,
+                        SymbolFlags::FunctionScopedVariableExcludes,
+                    )
+                };
+
+                if is_annex_b {
+                    // Only duplicate function declarations are allowed in a block,
+                    // e.g. `{ var f; function f() {} }` is a redeclaration.
+                    if let Some(&symbol_id) = builder
+                        .hoisting_variables
+                        .get(&current_scope_id)
+                        .and_then(|symbols| symbols.get(&ident.name))
                     {
-                        (
-                            SymbolFlags::Function | SymbolFlags::BlockScopedVariable,
-                            SymbolFlags::BlockScopedVariableExcludes,
-                        )
-                    } else {
-                        (
-                            SymbolFlags::FunctionScopedVariable,
-                            SymbolFlags::FunctionScopedVariableExcludes,
-                        )
-                    };
+                        let symbol_span = builder.scoping.symbol_span(symbol_id);
+                        builder.error(redeclaration(&ident.name, symbol_span, ident.span));
+                    }
+                }
 
                 let symbol_id = builder.declare_symbol(ident.span, &ident.name, includes, excludes);
                 ident.symbol_id.set(Some(symbol_id));
+
+                if is_annex_b {
+                    let var_scope_id = builder
+                        .scoping
+                        .scope_ancestors(current_scope_id)
+                        .find(|&scope_id| builder.scoping.scope_flags(scope_id).is_var())
+                        .unwrap();
+                    builder
+                        .annex_b_functions
+                        .entry(var_scope_id)
+                        .or_default()
+                        .push((ident.name, symbol_id));
+                }
             } else if self.r#type == FunctionType::FunctionExpression {
                 // https://tc39.es/ecma262/#sec-runtime-semantics-instantiateordinaryfunctionexpression
                 // 5. Perform ! funcEnv.CreateImmutableBinding(name, false).
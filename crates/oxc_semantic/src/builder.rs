@@ -80,6 +80,10 @@ pub struct SemanticBuilder<'a> {
     pub(crate) namespace_stack: Vec<Option<SymbolId>>,
     current_reference_flags: ReferenceFlags,
     pub(crate) hoisting_variables: FxHashMap<ScopeId, FxHashMap<Atom<'a>, SymbolId>>,
+    /// Function declarations inside blocks in non-strict mode code, keyed by their var scope.
+    /// References to them from outside the block are resolved when leaving the var scope.
+    /// <https://tc39.es/ecma262/#sec-block-level-function-declarations-web-legacy-compatibility-semantics>
+    pub(crate) annex_b_functions: FxHashMap<ScopeId, Vec<(Atom<'a>, SymbolId)>>,
 
     // builders
     pub(crate) nodes: AstNodes<'a>,
@@ -134,6 +138,7 @@ impl<'a> SemanticBuilder<'a> {
             namespace_stack: vec![],
             nodes: AstNodes::default(),
             hoisting_variables: FxHashMap::default(),
+            annex_b_functions: FxHashMap::default(),
             scoping,
             unresolved_references: UnresolvedReferencesStack::new(),
             unused_labels: UnusedLabels::default(),
@@ -476,6 +481,9 @@ impl<'a> SemanticBuilder<'a> {
     ///
     /// This gets called every time [`SemanticBuilder`] exits a scope.
     fn resolve_references_for_current_scope(&mut self) {
+        if !self.annex_b_functions.is_empty() {
+            self.hoist_annex_b_functions();
+        }
         let (current_refs, parent_refs) = self.unresolved_references.current_and_parent_mut();
 
         for (name, mut references) in current_refs.drain() {
@@ -535,6 +543,48 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 
+    /// Move the bindings of the block-level functions declared in the current var scope which are
+    /// hoisted to it in non-strict mode code from their blocks to the var scope.
+    ///
+    /// The block binding and the var binding always hold the same function, and must keep the same
+    /// name, so they are represented by a single symbol declared in the var scope. References in
+    /// the block are already resolved to it.
+    ///
+    /// A function is not hoisted if replacing it with a `var` declaration would produce an early
+    /// error, i.e. its name is already bound in the var scope or in an enclosing block.
+    /// If several functions have the same name, the first one is bound.
+    fn hoist_annex_b_functions(&mut self) {
+        let Some(mut functions) = self.annex_b_functions.remove(&self.current_scope_id) else {
+            return;
+        };
+        let var_scope_id = self.current_scope_id;
+        let scoping = &self.scoping;
+        functions.retain(|&(name, symbol_id)| {
+            let block_scope_id = scoping.symbol_scope_id(symbol_id);
+            for scope_id in scoping.scope_ancestors(block_scope_id).skip(1) {
+                if let Some(binding) = scoping.get_binding(scope_id, &name) {
+                    // `try {} catch (f) { { function f() {} } }` is allowed
+                    let is_catch_parameter = scoping.symbol_flags(binding)
+                        == SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable;
+                    if scope_id == var_scope_id || !is_catch_parameter {
+                        return false;
+                    }
+                }
+                if scope_id == var_scope_id {
+                    break;
+                }
+            }
+            true
+        });
+        for (name, symbol_id) in functions {
+            if self.scoping.get_binding(var_scope_id, &name).is_none() {
+                let block_scope_id = self.scoping.symbol_scope_id(symbol_id);
+                self.scoping.move_binding(block_scope_id, var_scope_id, &name);
+                self.scoping.set_symbol_scope_id(symbol_id, var_scope_id);
+            }
+        }
+    }
+
     pub(crate) fn add_redeclare_variable(&mut self, symbol_id: SymbolId, span: Span) {
         self.scoping.add_symbol_redeclaration(symbol_id, span);
     }
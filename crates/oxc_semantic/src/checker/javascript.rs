@@ -431,6 +431,24 @@ pub fn check_function_declaration<'a>(
     };
 }
 
+fn labelled_function_declaration(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Invalid function declaration")
+        .with_help("Labelled function declarations cannot be the body of an if, with or iteration statement")
+        .with_label(span)
+}
+
+/// It is a Syntax Error if IsLabelledFunction(Statement) is true.
+/// <https://tc39.es/ecma262/#sec-islabelledfunction>
+pub fn check_labelled_function<'a>(stmt: &Statement<'a>, ctx: &SemanticBuilder<'a>) {
+    let mut stmt = stmt;
+    while let Statement::LabeledStatement(labeled) = stmt {
+        stmt = &labeled.body;
+        if let Statement::FunctionDeclaration(decl) = stmt {
+            ctx.error(labelled_function_declaration(decl.span));
+        }
+    }
+}
+
 fn reg_exp_flag_u_and_v(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(
         "The 'u' and 'v' regular expression flags cannot be enabled at the same time",
@@ -42,6 +42,7 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
 
         AstKind::WithStatement(stmt) => {
             js::check_function_declaration(&stmt.body, false, ctx);
+            js::check_labelled_function(&stmt.body, ctx);
             js::check_with_statement(stmt, ctx);
         }
         AstKind::SwitchStatement(stmt) => js::check_switch_statement(stmt, ctx),
@@ -53,11 +54,13 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
         }
         AstKind::ForInStatement(stmt) => {
             js::check_function_declaration(&stmt.body, false, ctx);
+            js::check_labelled_function(&stmt.body, ctx);
             js::check_for_statement_left(&stmt.left, true, ctx);
             ts::check_for_statement_left(&stmt.left, true, ctx);
         }
         AstKind::ForOfStatement(stmt) => {
             js::check_function_declaration(&stmt.body, false, ctx);
+            js::check_labelled_function(&stmt.body, ctx);
             js::check_for_statement_left(&stmt.left, false, ctx);
             ts::check_for_statement_left(&stmt.left, false, ctx);
         }
@@ -65,11 +68,14 @@ pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
         | AstKind::DoWhileStatement(DoWhileStatement { body, .. })
         | AstKind::ForStatement(ForStatement { body, .. }) => {
             js::check_function_declaration(body, false, ctx);
+            js::check_labelled_function(body, ctx);
         }
         AstKind::IfStatement(stmt) => {
             js::check_function_declaration(&stmt.consequent, true, ctx);
+            js::check_labelled_function(&stmt.consequent, ctx);
             if let Some(alternate) = &stmt.alternate {
                 js::check_function_declaration(alternate, true, ctx);
+                js::check_labelled_function(alternate, ctx);
             }
         }
         AstKind::Class(class) => {
@@ -223,6 +223,78 @@ fn var_hoisting() {
     .test();
 }
 
+#[test]
+fn annex_b_block_function_hoisting() {
+    // Non-strict block-level functions are visible after the block
+    SemanticTester::js("{ function f() {} } f();")
+        .with_module(false)
+        .has_some_symbol("f")
+        .has_number_of_reads(1)
+        .test();
+    SemanticTester::js("function g() { if (a) { function f() {} } f(); }")
+        .with_module(false)
+        .has_some_symbol("f")
+        .has_number_of_reads(1)
+        .test();
+
+    // but not outside of their function
+    SemanticTester::js("function g() { { function f() {} } } f();")
+        .with_module(false)
+        .has_some_symbol("f")
+        .has_number_of_reads(0)
+        .test();
+
+    // or in strict mode code
+    SemanticTester::js("{ function f() {} } f();")
+        .has_some_symbol("f")
+        .has_number_of_reads(0)
+        .test();
+
+    // or when replacing them with `var` would conflict with a lexical declaration
+    SemanticTester::js("let f; { function f() {} } f();")
+        .with_module(false)
+        .has_root_symbol("f")
+        .has_number_of_reads(1)
+        .test();
+    SemanticTester::js("{ function f() {} } let f; f();")
+        .with_module(false)
+        .has_root_symbol("f")
+        .has_number_of_reads(1)
+        .test();
+}
+
+#[test]
+fn annex_b_early_errors() {
+    for source in [
+        "{ function f() {} function f() {} }",
+        "switch (a) { case 1: function f() {} default: function f() {} }",
+        "try {} catch (e) { var e; for (var e in o) {} }",
+        "if (a) function f() {} else function g() {}",
+        "l: m: function f() {}",
+    ] {
+        SemanticTester::js(source).with_module(false).build();
+    }
+
+    for source in [
+        "{ function f() {} var f; }",
+        "{ var f; function f() {} }",
+        "{ function f() {} { var f; } }",
+        "try {} catch (e) { for (var e of []) {} }",
+    ] {
+        SemanticTester::js(source).with_module(false).has_error("has already been declared");
+    }
+
+    for source in [
+        "if (a) l: function f() {}",
+        "if (a) ; else l: m: function f() {}",
+        "while (a) l: function f() {}",
+        "for (;;) l: function f() {}",
+        "with (a) l: function f() {}",
+    ] {
+        SemanticTester::js(source).with_module(false).has_error("Invalid function declaration");
+    }
+}
+
 #[test]
 fn get_child_ids() {
     let test = SemanticTester::js(
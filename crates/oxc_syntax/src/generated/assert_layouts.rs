@@ -19,13 +19,23 @@ const _: () = {
     assert!(offset_of!(NameSpan, name) == 0);
     assert!(offset_of!(NameSpan, span) == 16);
 
-    assert!(size_of::<ImportEntry>() == 96);
+    assert!(size_of::<ImportAttributeRecord>() == 48);
+    assert!(align_of::<ImportAttributeRecord>() == 8);
+    assert!(offset_of!(ImportAttributeRecord, key) == 0);
+    assert!(offset_of!(ImportAttributeRecord, value) == 24);
+
+    assert!(size_of::<ImportPhase>() == 1);
+    assert!(align_of::<ImportPhase>() == 1);
+
+    assert!(size_of::<ImportEntry>() == 136);
     assert!(align_of::<ImportEntry>() == 8);
     assert!(offset_of!(ImportEntry, statement_span) == 0);
     assert!(offset_of!(ImportEntry, module_request) == 8);
     assert!(offset_of!(ImportEntry, import_name) == 32);
     assert!(offset_of!(ImportEntry, local_name) == 64);
     assert!(offset_of!(ImportEntry, is_type) == 88);
+    assert!(offset_of!(ImportEntry, attributes) == 96);
+    assert!(offset_of!(ImportEntry, phase) == 128);
 
     assert!(size_of::<ImportImportName>() == 32);
     assert!(align_of::<ImportImportName>() == 8);
@@ -95,13 +105,23 @@ const _: () = {
     assert!(offset_of!(NameSpan, name) == 0);
     assert!(offset_of!(NameSpan, span) == 8);
 
-    assert!(size_of::<ImportEntry>() == 64);
+    assert!(size_of::<ImportAttributeRecord>() == 32);
+    assert!(align_of::<ImportAttributeRecord>() == 4);
+    assert!(offset_of!(ImportAttributeRecord, key) == 0);
+    assert!(offset_of!(ImportAttributeRecord, value) == 16);
+
+    assert!(size_of::<ImportPhase>() == 1);
+    assert!(align_of::<ImportPhase>() == 1);
+
+    assert!(size_of::<ImportEntry>() == 84);
     assert!(align_of::<ImportEntry>() == 4);
     assert!(offset_of!(ImportEntry, statement_span) == 0);
     assert!(offset_of!(ImportEntry, module_request) == 8);
     assert!(offset_of!(ImportEntry, import_name) == 24);
     assert!(offset_of!(ImportEntry, local_name) == 44);
     assert!(offset_of!(ImportEntry, is_type) == 60);
+    assert!(offset_of!(ImportEntry, attributes) == 64);
+    assert!(offset_of!(ImportEntry, phase) == 80);
 
     assert!(size_of::<ImportImportName>() == 20);
     assert!(align_of::<ImportImportName>() == 4);
@@ -5,9 +5,66 @@
 
 use oxc_allocator::{Allocator, CloneIn};
 
+use crate::module_record::*;
 use crate::number::*;
 use crate::operator::*;
 
+impl<'new_alloc> CloneIn<'new_alloc> for NameSpan<'_> {
+    type Cloned = NameSpan<'new_alloc>;
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        NameSpan {
+            name: CloneIn::clone_in(&self.name, allocator),
+            span: CloneIn::clone_in(&self.span, allocator),
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for ImportAttributeRecord<'_> {
+    type Cloned = ImportAttributeRecord<'new_alloc>;
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        ImportAttributeRecord {
+            key: CloneIn::clone_in(&self.key, allocator),
+            value: CloneIn::clone_in(&self.value, allocator),
+        }
+    }
+}
+
+impl<'alloc> CloneIn<'alloc> for ImportPhase {
+    type Cloned = ImportPhase;
+    fn clone_in(&self, _: &'alloc Allocator) -> Self::Cloned {
+        match self {
+            Self::Source => ImportPhase::Source,
+            Self::Defer => ImportPhase::Defer,
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for ImportEntry<'_> {
+    type Cloned = ImportEntry<'new_alloc>;
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        ImportEntry {
+            statement_span: CloneIn::clone_in(&self.statement_span, allocator),
+            module_request: CloneIn::clone_in(&self.module_request, allocator),
+            import_name: CloneIn::clone_in(&self.import_name, allocator),
+            local_name: CloneIn::clone_in(&self.local_name, allocator),
+            is_type: CloneIn::clone_in(&self.is_type, allocator),
+            attributes: CloneIn::clone_in(&self.attributes, allocator),
+            phase: CloneIn::clone_in(&self.phase, allocator),
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for ImportImportName<'_> {
+    type Cloned = ImportImportName<'new_alloc>;
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        match self {
+            Self::Name(it) => ImportImportName::Name(CloneIn::clone_in(it, allocator)),
+            Self::NamespaceObject => ImportImportName::NamespaceObject,
+            Self::Default(it) => ImportImportName::Default(CloneIn::clone_in(it, allocator)),
+        }
+    }
+}
+
 impl<'alloc> CloneIn<'alloc> for NumberBase {
     type Cloned = NumberBase;
     fn clone_in(&self, _: &'alloc Allocator) -> Self::Cloned {
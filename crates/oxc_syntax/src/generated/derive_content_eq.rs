@@ -5,9 +5,16 @@
 
 use oxc_span::ContentEq;
 
+use crate::module_record::*;
 use crate::number::*;
 use crate::operator::*;
 
+impl ContentEq for ImportPhase {
+    fn content_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
 impl ContentEq for NumberBase {
     fn content_eq(&self, other: &Self) -> bool {
         self == other
@@ -21,6 +21,24 @@ impl ESTree for NameSpan<'_> {
     }
 }
 
+impl ESTree for ImportAttributeRecord<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("key", &self.key);
+        state.serialize_field("value", &self.value);
+        state.end();
+    }
+}
+
+impl ESTree for ImportPhase {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        match self {
+            Self::Source => JsonSafeString("source").serialize(serializer),
+            Self::Defer => JsonSafeString("defer").serialize(serializer),
+        }
+    }
+}
+
 impl ESTree for ImportEntry<'_> {
     fn serialize<S: Serializer>(&self, serializer: S) {
         let mut state = serializer.serialize_struct();
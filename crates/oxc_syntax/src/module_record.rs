@@ -2,14 +2,15 @@
 
 use rustc_hash::FxHashMap;
 
-use oxc_allocator::{Allocator, Vec};
+use oxc_allocator::{Allocator, CloneIn, Vec};
 use oxc_ast_macros::ast;
 use oxc_estree::ESTree;
-use oxc_span::{Atom, Span};
+use oxc_span::{Atom, ContentEq, Span};
 
 /// ESM Module Record
 ///
-/// All data inside this data structure are for ESM, no commonjs data is allowed.
+/// All data inside this data structure are for ESM, no commonjs data is allowed,
+/// except for [`ModuleRecord::require_calls`] which is needed for building module graphs.
 ///
 /// See
 /// * <https://tc39.es/ecma262/#table-additional-fields-of-source-text-module-records>
@@ -29,7 +30,7 @@ pub struct ModuleRecord<'a> {
     ///   export ExportFromClause FromClause
     ///
     /// Keyed by ModuleSpecifier, valued by all node occurrences
-    pub requested_modules: FxHashMap<Atom<'a>, Vec<'a, RequestedModule>>,
+    pub requested_modules: FxHashMap<Atom<'a>, Vec<'a, RequestedModule<'a>>>,
 
     /// `[[ImportEntries]]`
     ///
@@ -64,6 +65,9 @@ pub struct ModuleRecord<'a> {
 
     /// Span position of `import.meta`.
     pub import_metas: Vec<'a, Span>,
+
+    /// CommonJS `require(specifier)` calls with a single argument.
+    pub require_calls: Vec<'a, DynamicImport>,
 }
 
 impl<'a> ModuleRecord<'a> {
@@ -79,6 +83,7 @@ impl<'a> ModuleRecord<'a> {
             exported_bindings: FxHashMap::default(),
             dynamic_imports: Vec::new_in(allocator),
             import_metas: Vec::new_in(allocator),
+            require_calls: Vec::new_in(allocator),
         }
     }
 }
@@ -86,7 +91,7 @@ impl<'a> ModuleRecord<'a> {
 /// Name and Span
 #[ast]
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[generate_derive(ESTree)]
+#[generate_derive(CloneIn, ESTree)]
 #[estree(no_type, no_ts_def)]
 pub struct NameSpan<'a> {
     /// Name
@@ -104,6 +109,57 @@ impl<'a> NameSpan<'a> {
     }
 }
 
+/// [`ImportAttribute Record`](https://tc39.es/ecma262/#importattribute-record)
+///
+/// ## Examples
+///
+/// ```ts
+/// import json from "./foo.json" with { type: "json" };
+/// //                                   ^^^^ key
+/// //                                         ^^^^^^ value
+/// ```
+#[ast]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[generate_derive(CloneIn, ESTree)]
+#[estree(no_type, no_ts_def)]
+pub struct ImportAttributeRecord<'a> {
+    /// Key of the attribute, either an identifier or a string literal.
+    pub key: NameSpan<'a>,
+
+    /// String value of the attribute.
+    pub value: NameSpan<'a>,
+}
+
+/// Import Phase
+///
+/// <https://github.com/tc39/proposal-defer-import-eval>
+/// <https://github.com/tc39/proposal-source-phase-imports>
+/// <https://github.com/estree/estree/blob/2b48e56efc223ea477a45b5e034039934c5791fa/stage3/source-phase-imports.md>
+#[ast]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[generate_derive(CloneIn, ContentEq, ESTree)]
+pub enum ImportPhase {
+    /// `import source x from "mod"`
+    Source = 0,
+    /// `import defer * as ns from "mod"`
+    Defer = 1,
+}
+
+impl ImportPhase {
+    /// Returns the syntax associated with this [`ImportPhase`].
+    ///
+    /// ## Example
+    ///
+    /// - [`Source`][`ImportPhase::Source`] => `"source"`
+    /// - [`Defer`][`ImportPhase::Defer`] => `"defer"`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Source => "source",
+            Self::Defer => "defer",
+        }
+    }
+}
+
 /// [`ImportEntry`](https://tc39.es/ecma262/#importentry-record)
 ///
 /// ## Examples
@@ -120,8 +176,8 @@ impl<'a> NameSpan<'a> {
 /// import * as ns from "mod";
 /// ```
 #[ast]
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[generate_derive(ESTree)]
+#[derive(Debug, PartialEq, Eq)]
+#[generate_derive(CloneIn, ESTree)]
 #[estree(no_type, no_ts_def)]
 pub struct ImportEntry<'a> {
     /// Span of the import statement.
@@ -180,12 +236,34 @@ pub struct ImportEntry<'a> {
     /// import { foo as type } from "mod";
     /// ```
     pub is_type: bool,
+
+    /// Attributes of the import statement.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// import json from "./foo.json" with { type: "json" };
+    /// //                                   ^^^^^^^^^^^^
+    /// ```
+    #[estree(skip)]
+    pub attributes: Vec<'a, ImportAttributeRecord<'a>>,
+
+    /// Phase of the import statement. `None` for evaluation phase imports.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// import source wasm from "./foo.wasm";
+    /// import defer * as ns from "mod";
+    /// ```
+    #[estree(skip)]
+    pub phase: Option<ImportPhase>,
 }
 
 /// `ImportName` For `ImportEntry`
 #[ast]
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[generate_derive(ESTree)]
+#[generate_derive(CloneIn, ESTree)]
 #[estree(no_ts_def)]
 pub enum ImportImportName<'a> {
     /// `import { x } from "mod"`
@@ -397,8 +475,8 @@ impl<'a> ExportLocalName<'a> {
 }
 
 /// RequestedModule
-#[derive(Debug, Clone, Copy)]
-pub struct RequestedModule {
+#[derive(Debug)]
+pub struct RequestedModule<'a> {
     /// Span of the import statement.
     pub statement_span: Span,
 
@@ -417,15 +495,36 @@ pub struct RequestedModule {
 
     /// `true` if the module is requested by an import statement.
     pub is_import: bool,
+
+    /// Attributes of the `with` clause.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// import json from "./foo.json" with { type: "json" };
+    /// export { default } from "./foo.json" with { type: "json" };
+    /// ```
+    pub attributes: Vec<'a, ImportAttributeRecord<'a>>,
+
+    /// Phase of the import statement. Always `None` for export statements.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// import source wasm from "./foo.wasm"; // Some(ImportPhase::Source)
+    /// import defer * as ns from "mod";      // Some(ImportPhase::Defer)
+    /// import foo from "foo";                // None
+    /// ```
+    pub phase: Option<ImportPhase>,
 }
 
-/// Dynamic import expression.
+/// Dynamic import expression, or `require` call.
 #[ast]
 #[derive(Debug, Clone, Copy)]
 #[generate_derive(ESTree)]
 #[estree(no_type, no_ts_def)]
 pub struct DynamicImport {
-    /// Span of the import expression.
+    /// Span of the import expression or call expression.
     pub span: Span,
     /// Span the ModuleSpecifier, which is an expression.
     #[estree(no_flatten)]
@@ -444,11 +543,13 @@ pub trait VisitMutModuleRecord {
         module_record.star_export_entries.iter_mut().for_each(|e| self.visit_export_entry(e));
         module_record.dynamic_imports.iter_mut().for_each(|e| self.visit_dynamic_import(e));
         module_record.import_metas.iter_mut().for_each(|e| self.visit_span(e));
+        module_record.require_calls.iter_mut().for_each(|e| self.visit_dynamic_import(e));
     }
 
     fn visit_requested_module(&mut self, requested_module: &mut RequestedModule) {
         self.visit_span(&mut requested_module.span);
         self.visit_span(&mut requested_module.statement_span);
+        requested_module.attributes.iter_mut().for_each(|e| self.visit_import_attribute(e));
     }
 
     fn visit_import_entry(&mut self, import_entry: &mut ImportEntry) {
@@ -456,6 +557,12 @@ pub trait VisitMutModuleRecord {
         self.visit_name_span(&mut import_entry.module_request);
         self.visit_name_span(&mut import_entry.local_name);
         self.visit_import_import_name(&mut import_entry.import_name);
+        import_entry.attributes.iter_mut().for_each(|e| self.visit_import_attribute(e));
+    }
+
+    fn visit_import_attribute(&mut self, import_attribute: &mut ImportAttributeRecord) {
+        self.visit_name_span(&mut import_attribute.key);
+        self.visit_name_span(&mut import_attribute.value);
     }
 
     fn visit_import_import_name(&mut self, import_import_name: &mut ImportImportName) {
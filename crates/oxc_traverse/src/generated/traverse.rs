@@ -651,6 +651,11 @@ pub trait Traverse<'a> {
     #[inline]
     fn exit_empty_statement(&mut self, node: &mut EmptyStatement, ctx: &mut TraverseCtx<'a>) {}
 
+    #[inline]
+    fn enter_error_statement(&mut self, node: &mut ErrorStatement, ctx: &mut TraverseCtx<'a>) {}
+    #[inline]
+    fn exit_error_statement(&mut self, node: &mut ErrorStatement, ctx: &mut TraverseCtx<'a>) {}
+
     #[inline]
     fn enter_expression_statement(
         &mut self,
@@ -1371,6 +1371,9 @@ unsafe fn walk_statement<'a, Tr: Traverse<'a>>(
         Statement::WithStatement(node) => {
             walk_with_statement(traverser, (&mut **node) as *mut _, ctx)
         }
+        Statement::ErrorStatement(node) => {
+            walk_error_statement(traverser, (&mut **node) as *mut _, ctx)
+        }
         Statement::VariableDeclaration(_)
         | Statement::FunctionDeclaration(_)
         | Statement::ClassDeclaration(_)
@@ -1532,6 +1535,15 @@ unsafe fn walk_empty_statement<'a, Tr: Traverse<'a>>(
     traverser.exit_empty_statement(&mut *node, ctx);
 }
 
+unsafe fn walk_error_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: *mut ErrorStatement,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_error_statement(&mut *node, ctx);
+    traverser.exit_error_statement(&mut *node, ctx);
+}
+
 unsafe fn walk_expression_statement<'a, Tr: Traverse<'a>>(
     traverser: &mut Tr,
     node: *mut ExpressionStatement<'a>,
@@ -7,6 +7,18 @@ export interface CodegenOptions {
    * @default true
    */
   removeWhitespace?: boolean
+  /**
+   * Insert a line break at the next safe position once a line reaches this many bytes.
+   *
+   * Only takes effect when `removeWhitespace` is `true`.
+   */
+  maxLineLen?: number
+  /**
+   * Escape all non-ASCII characters in strings, template literals, identifiers and regular expressions.
+   *
+   * @default false
+   */
+  asciiOnly?: boolean
 }
 
 export interface CompressOptions {
@@ -25,6 +37,12 @@ export interface CompressOptions {
   target?: 'esnext' | 'es2015' | 'es2016' | 'es2017' | 'es2018' | 'es2019' | 'es2020' | 'es2021' | 'es2022' | 'es2023' | 'es2024'
   /** Keep function / class names. */
   keepNames?: CompressOptionsKeepNames
+  /**
+   * Names that are never dropped, e.g. unused function and class expression names.
+   *
+   * Entries written as `/pattern/` are regular expressions, e.g. `/^Vue/`.
+   */
+  reserved?: Array<string>
   /**
    * Pass true to discard calls to `console.*`.
    *
@@ -37,6 +55,13 @@ export interface CompressOptions {
    * @default true
    */
   dropDebugger?: boolean
+  /**
+   * Remove unreachable code, dead stores to local variables and code after calls to
+   * functions that never return, using control flow analysis.
+   *
+   * @default true
+   */
+  controlFlowDeadCode?: boolean
 }
 
 export interface CompressOptionsKeepNames {
@@ -56,6 +81,10 @@ export interface CompressOptionsKeepNames {
    * @default false
    */
   class: boolean
+  /** Keep names of functions matching any of these names or `/regex/` patterns. */
+  functionPatterns?: Array<string>
+  /** Keep names of classes matching any of these names or `/regex/` patterns. */
+  classPatterns?: Array<string>
 }
 
 export interface MangleOptions {
@@ -67,6 +96,41 @@ export interface MangleOptions {
   toplevel?: boolean
   /** Debug mangled names. */
   debug?: boolean
+  /**
+   * Names assigned by previous runs, similar to terser's `nameCache`.
+   *
+   * Top level names found in the cache keep their cached mangled name.
+   * The updated cache is returned in `MinifyResult.nameCache`.
+   * Requires `toplevel: true`.
+   */
+  nameCache?: NameCache
+  /**
+   * Names that are never mangled, similar to terser's `mangle.reserved`.
+   *
+   * Entries written as `/pattern/` are regular expressions, e.g. `/^Vue/`.
+   */
+  reserved?: Array<string>
+  /** Keep function / class names. */
+  keepNames?: MangleOptionsKeepNames
+}
+
+export interface MangleOptionsKeepNames {
+  /**
+   * Do not mangle function names.
+   *
+   * @default false
+   */
+  function: boolean
+  /**
+   * Do not mangle class names.
+   *
+   * @default false
+   */
+  class: boolean
+  /** Do not mangle function names matching any of these names or `/regex/` patterns. */
+  functionPatterns?: Array<string>
+  /** Do not mangle class names matching any of these names or `/regex/` patterns. */
+  classPatterns?: Array<string>
 }
 
 /** Minify synchronously. */
@@ -76,12 +140,77 @@ export interface MinifyOptions {
   compress?: boolean | CompressOptions
   mangle?: boolean | MangleOptions
   codegen?: boolean | CodegenOptions
-  sourcemap?: boolean
+  /**
+   * Enable source map generation.
+   *
+   * When `true` or an object, the `map` field of the result will be populated.
+   *
+   * @default false
+   */
+  sourcemap?: boolean | SourcemapOptions
+  /**
+   * Collect statistics of the compressor, returned in `MinifyResult.stats`.
+   *
+   * This is slow, use it for debugging only.
+   *
+   * @default false
+   */
+  stats?: boolean
+}
+
+export interface MinifyIterationStats {
+  /** Minified output size in bytes before this walk, without mangling. */
+  sizeBefore: number
+  /** Minified output size in bytes after this walk, without mangling. */
+  sizeAfter: number
+  /** Time spent walking the AST in milliseconds. */
+  duration: number
+  /** Number of changes made by each peephole optimization in this walk. */
+  optimizations: Array<MinifyOptimizationStats>
+}
+
+export interface MinifyOptimizationStats {
+  name: string
+  /** Number of times this optimization changed the AST. */
+  changes: number
+}
+
+export interface MinifyPhaseStats {
+  name: string
+  /** Minified output size in bytes before this phase, without mangling. */
+  sizeBefore: number
+  /** Minified output size in bytes after this phase, without mangling. */
+  sizeAfter: number
 }
 
 export interface MinifyResult {
   code: string
   map?: SourceMap
+  /** The updated name cache, if `mangle.nameCache` was provided. */
+  nameCache?: NameCache
+  /** Compressor statistics, if `stats` is enabled. */
+  stats?: MinifyStats
+}
+
+export interface MinifyStats {
+  /** Number of AST walks done by the peephole optimizations until a fixed point is reached. */
+  iterations: number
+  /** Output size of each phase of the compressor, in the order they ran. */
+  phases: Array<MinifyPhaseStats>
+  /**
+   * Number of changes made by each peephole optimization, in the order they first fired.
+   *
+   * Optimizations run interleaved in the same AST walk, so bytes saved are only measured
+   * for each phase and each walk, not for each optimization.
+   */
+  optimizations: Array<MinifyOptimizationStats>
+  /** Statistics of each AST walk of the peephole optimizations, in the order they ran. */
+  iterationStats: Array<MinifyIterationStats>
+}
+
+export interface NameCache {
+  /** Mapping of original top level names to mangled names. */
+  vars: Record<string, string>
 }
 
 export interface SourceMap {
@@ -93,4 +222,24 @@ export interface SourceMap {
   sourcesContent?: Array<string>
   version: number
   x_google_ignoreList?: Array<number>
+  debugId?: string
+}
+
+export interface SourcemapOptions {
+  /**
+   * Include the original source code in `sourcesContent`.
+   *
+   * @default true
+   */
+  sourcesContent?: boolean
+  /** Set `sourceRoot` of the source map. */
+  sourceRoot?: string
+  /**
+   * Add a deterministic `debugId` to the source map and a `//# debugId=` comment to the end of the code.
+   *
+   * @default false
+   */
+  debugId?: boolean
+  /** Add the file to `x_google_ignoreList` if its path contains any of these strings, e.g. `node_modules`. */
+  ignoreList?: Array<string>
 }
@@ -17,7 +17,7 @@ use oxc_minifier::Minifier;
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
-use crate::options::{MinifyOptions, MinifyResult};
+use crate::options::{MinifyOptions, MinifyResult, MinifyStats, NameCache};
 
 /// Minify synchronously.
 #[napi]
@@ -39,7 +39,7 @@ pub fn minify(
 
     let mut program = Parser::new(&allocator, &source_text, source_type).parse().program;
 
-    let scoping = Minifier::new(minifier_options).build(&allocator, &mut program).scoping;
+    let ret = Minifier::new(minifier_options).build(&allocator, &mut program);
 
     let mut codegen_options = match &options.codegen {
         Some(Either::A(false)) => CodegenOptions { minify: false, ..CodegenOptions::default() },
@@ -49,11 +49,27 @@ pub fn minify(
         Some(Either::B(o)) => CodegenOptions::from(o),
     };
 
-    if options.sourcemap == Some(true) {
-        codegen_options.source_map_path = Some(PathBuf::from(filename));
+    match &options.sourcemap {
+        Some(Either::A(true)) => {
+            codegen_options.source_map_path = Some(PathBuf::from(filename));
+        }
+        Some(Either::B(sourcemap_options)) => {
+            codegen_options.source_map_path = Some(PathBuf::from(filename));
+            sourcemap_options.apply(&mut codegen_options);
+        }
+        None | Some(Either::A(false)) => {}
     }
 
-    let ret = Codegen::new().with_options(codegen_options).with_scoping(scoping).build(&program);
+    let name_cache = ret.name_cache.map(NameCache::from);
+    let stats = ret.stats.map(MinifyStats::from);
+
+    let ret =
+        Codegen::new().with_options(codegen_options).with_scoping(ret.scoping).build(&program);
 
-    Ok(MinifyResult { code: ret.code, map: ret.map.map(oxc_sourcemap::napi::SourceMap::from) })
+    Ok(MinifyResult {
+        code: ret.code,
+        map: ret.map.map(oxc_napi::SourceMap::from),
+        name_cache,
+        stats,
+    })
 }
@@ -2,8 +2,11 @@ use std::str::FromStr;
 
 use napi::Either;
 use napi_derive::napi;
+use rustc_hash::FxHashMap;
 
-use oxc_sourcemap::napi::SourceMap;
+use oxc_minifier::NamePattern;
+use oxc_napi::SourceMap;
+pub use oxc_napi::SourcemapOptions;
 use oxc_syntax::es_target::ESTarget;
 
 #[napi(object)]
@@ -26,6 +29,11 @@ pub struct CompressOptions {
     /// Keep function / class names.
     pub keep_names: Option<CompressOptionsKeepNames>,
 
+    /// Names that are never dropped, e.g. unused function and class expression names.
+    ///
+    /// Entries written as `/pattern/` are regular expressions, e.g. `/^Vue/`.
+    pub reserved: Option<Vec<String>>,
+
     /// Pass true to discard calls to `console.*`.
     ///
     /// @default false
@@ -35,11 +43,24 @@ pub struct CompressOptions {
     ///
     /// @default true
     pub drop_debugger: Option<bool>,
+
+    /// Remove unreachable code, dead stores to local variables and code after calls to
+    /// functions that never return, using control flow analysis.
+    ///
+    /// @default true
+    pub control_flow_dead_code: Option<bool>,
 }
 
 impl Default for CompressOptions {
     fn default() -> Self {
-        Self { target: None, keep_names: None, drop_console: None, drop_debugger: Some(true) }
+        Self {
+            target: None,
+            keep_names: None,
+            reserved: None,
+            drop_console: None,
+            drop_debugger: Some(true),
+            control_flow_dead_code: Some(true),
+        }
     }
 }
 
@@ -54,9 +75,18 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
                 .map(|s| ESTarget::from_str(s))
                 .transpose()?
                 .unwrap_or(default.target),
-            keep_names: o.keep_names.as_ref().map(Into::into).unwrap_or_default(),
+            keep_names: o
+                .keep_names
+                .as_ref()
+                .map(oxc_minifier::CompressOptionsKeepNames::try_from)
+                .transpose()?
+                .unwrap_or_default(),
+            reserved: name_patterns(o.reserved.as_deref())?,
             drop_console: o.drop_console.unwrap_or(default.drop_console),
             drop_debugger: o.drop_debugger.unwrap_or(default.drop_debugger),
+            control_flow_dead_code: o
+                .control_flow_dead_code
+                .unwrap_or(default.control_flow_dead_code),
         })
     }
 }
@@ -76,14 +106,38 @@ pub struct CompressOptionsKeepNames {
     ///
     /// @default false
     pub class: bool,
+
+    /// Keep names of functions matching any of these names or `/regex/` patterns.
+    pub function_patterns: Option<Vec<String>>,
+
+    /// Keep names of classes matching any of these names or `/regex/` patterns.
+    pub class_patterns: Option<Vec<String>>,
 }
 
-impl From<&CompressOptionsKeepNames> for oxc_minifier::CompressOptionsKeepNames {
-    fn from(o: &CompressOptionsKeepNames) -> Self {
-        oxc_minifier::CompressOptionsKeepNames { function: o.function, class: o.class }
+impl TryFrom<&CompressOptionsKeepNames> for oxc_minifier::CompressOptionsKeepNames {
+    type Error = String;
+    fn try_from(o: &CompressOptionsKeepNames) -> Result<Self, Self::Error> {
+        Ok(oxc_minifier::CompressOptionsKeepNames {
+            function: o.function,
+            class: o.class,
+            function_patterns: name_patterns(o.function_patterns.as_deref())?,
+            class_patterns: name_patterns(o.class_patterns.as_deref())?,
+        })
     }
 }
 
+/// Parses names, or regular expressions written as `/pattern/`.
+fn name_patterns(patterns: Option<&[String]>) -> Result<Vec<NamePattern>, String> {
+    patterns
+        .unwrap_or_default()
+        .iter()
+        .map(|pattern| match pattern.strip_prefix('/').and_then(|p| p.strip_suffix('/')) {
+            Some(regex) => NamePattern::regex(regex).map_err(|error| error.to_string()),
+            None => Ok(NamePattern::from(pattern.as_str())),
+        })
+        .collect()
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct MangleOptions {
@@ -94,14 +148,92 @@ pub struct MangleOptions {
 
     /// Debug mangled names.
     pub debug: Option<bool>,
+
+    /// Names assigned by previous runs, similar to terser's `nameCache`.
+    ///
+    /// Top level names found in the cache keep their cached mangled name.
+    /// The updated cache is returned in `MinifyResult.nameCache`.
+    /// Requires `toplevel: true`.
+    pub name_cache: Option<NameCache>,
+
+    /// Names that are never mangled, similar to terser's `mangle.reserved`.
+    ///
+    /// Entries written as `/pattern/` are regular expressions, e.g. `/^Vue/`.
+    pub reserved: Option<Vec<String>>,
+
+    /// Keep function / class names.
+    pub keep_names: Option<MangleOptionsKeepNames>,
 }
 
-impl From<&MangleOptions> for oxc_minifier::MangleOptions {
-    fn from(o: &MangleOptions) -> Self {
+impl TryFrom<&MangleOptions> for oxc_minifier::MangleOptions {
+    type Error = String;
+    fn try_from(o: &MangleOptions) -> Result<Self, Self::Error> {
         let default = oxc_minifier::MangleOptions::default();
-        Self {
+        Ok(Self {
             top_level: o.toplevel.unwrap_or(default.top_level),
             debug: o.debug.unwrap_or(default.debug),
+            name_cache: o.name_cache.as_ref().map(Into::into),
+            reserved: name_patterns(o.reserved.as_deref())?,
+            keep_names: o
+                .keep_names
+                .as_ref()
+                .map(oxc_minifier::MangleOptionsKeepNames::try_from)
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    }
+}
+
+#[napi(object)]
+pub struct MangleOptionsKeepNames {
+    /// Do not mangle function names.
+    ///
+    /// @default false
+    pub function: bool,
+
+    /// Do not mangle class names.
+    ///
+    /// @default false
+    pub class: bool,
+
+    /// Do not mangle function names matching any of these names or `/regex/` patterns.
+    pub function_patterns: Option<Vec<String>>,
+
+    /// Do not mangle class names matching any of these names or `/regex/` patterns.
+    pub class_patterns: Option<Vec<String>>,
+}
+
+impl TryFrom<&MangleOptionsKeepNames> for oxc_minifier::MangleOptionsKeepNames {
+    type Error = String;
+    fn try_from(o: &MangleOptionsKeepNames) -> Result<Self, Self::Error> {
+        Ok(oxc_minifier::MangleOptionsKeepNames {
+            function: o.function,
+            class: o.class,
+            function_patterns: name_patterns(o.function_patterns.as_deref())?,
+            class_patterns: name_patterns(o.class_patterns.as_deref())?,
+        })
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct NameCache {
+    /// Mapping of original top level names to mangled names.
+    pub vars: FxHashMap<String, String>,
+}
+
+impl From<&NameCache> for oxc_minifier::NameCache {
+    fn from(o: &NameCache) -> Self {
+        oxc_minifier::NameCache {
+            vars: o.vars.iter().map(|(k, v)| (k.as_str().into(), v.as_str().into())).collect(),
+        }
+    }
+}
+
+impl From<oxc_minifier::NameCache> for NameCache {
+    fn from(o: oxc_minifier::NameCache) -> Self {
+        NameCache {
+            vars: o.vars.into_iter().map(|(k, v)| (k.into_string(), v.into_string())).collect(),
         }
     }
 }
@@ -112,11 +244,21 @@ pub struct CodegenOptions {
     ///
     /// @default true
     pub remove_whitespace: Option<bool>,
+
+    /// Insert a line break at the next safe position once a line reaches this many bytes.
+    ///
+    /// Only takes effect when `removeWhitespace` is `true`.
+    pub max_line_len: Option<u32>,
+
+    /// Escape all non-ASCII characters in strings, template literals, identifiers and regular expressions.
+    ///
+    /// @default false
+    pub ascii_only: Option<bool>,
 }
 
 impl Default for CodegenOptions {
     fn default() -> Self {
-        Self { remove_whitespace: Some(true) }
+        Self { remove_whitespace: Some(true), max_line_len: None, ascii_only: None }
     }
 }
 
@@ -125,6 +267,8 @@ impl From<&CodegenOptions> for oxc_codegen::CodegenOptions {
         let default = oxc_codegen::CodegenOptions::default();
         oxc_codegen::CodegenOptions {
             minify: o.remove_whitespace.unwrap_or(default.minify),
+            max_line_len: o.max_line_len.map(|n| n as usize),
+            ascii_only: o.ascii_only.unwrap_or(default.ascii_only),
             ..default
         }
     }
@@ -139,7 +283,19 @@ pub struct MinifyOptions {
 
     pub codegen: Option<Either<bool, CodegenOptions>>,
 
-    pub sourcemap: Option<bool>,
+    /// Enable source map generation.
+    ///
+    /// When `true` or an object, the `map` field of the result will be populated.
+    ///
+    /// @default false
+    pub sourcemap: Option<Either<bool, SourcemapOptions>>,
+
+    /// Collect statistics of the compressor, returned in `MinifyResult.stats`.
+    ///
+    /// This is slow, use it for debugging only.
+    ///
+    /// @default false
+    pub stats: Option<bool>,
 }
 
 impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
@@ -154,9 +310,9 @@ impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
         let mangle = match &o.mangle {
             Some(Either::A(false)) => None,
             None | Some(Either::A(true)) => Some(oxc_minifier::MangleOptions::default()),
-            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::from(o)),
+            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::try_from(o)?),
         };
-        Ok(oxc_minifier::MinifierOptions { compress, mangle })
+        Ok(oxc_minifier::MinifierOptions { compress, mangle, stats: o.stats.unwrap_or_default() })
     }
 }
 
@@ -165,4 +321,105 @@ pub struct MinifyResult {
     pub code: String,
 
     pub map: Option<SourceMap>,
+
+    /// The updated name cache, if `mangle.nameCache` was provided.
+    pub name_cache: Option<NameCache>,
+
+    /// Compressor statistics, if `stats` is enabled.
+    pub stats: Option<MinifyStats>,
+}
+
+#[napi(object)]
+pub struct MinifyStats {
+    /// Number of AST walks done by the peephole optimizations until a fixed point is reached.
+    pub iterations: u32,
+
+    /// Output size of each phase of the compressor, in the order they ran.
+    pub phases: Vec<MinifyPhaseStats>,
+
+    /// Number of changes made by each peephole optimization, in the order they first fired.
+    ///
+    /// Optimizations run interleaved in the same AST walk, so bytes saved are only measured
+    /// for each phase and each walk, not for each optimization.
+    pub optimizations: Vec<MinifyOptimizationStats>,
+
+    /// Statistics of each AST walk of the peephole optimizations, in the order they ran.
+    pub iteration_stats: Vec<MinifyIterationStats>,
+}
+
+#[napi(object)]
+pub struct MinifyIterationStats {
+    /// Minified output size in bytes before this walk, without mangling.
+    pub size_before: u32,
+
+    /// Minified output size in bytes after this walk, without mangling.
+    pub size_after: u32,
+
+    /// Time spent walking the AST in milliseconds.
+    pub duration: f64,
+
+    /// Number of changes made by each peephole optimization in this walk.
+    pub optimizations: Vec<MinifyOptimizationStats>,
+}
+
+#[napi(object)]
+pub struct MinifyPhaseStats {
+    pub name: String,
+
+    /// Minified output size in bytes before this phase, without mangling.
+    pub size_before: u32,
+
+    /// Minified output size in bytes after this phase, without mangling.
+    pub size_after: u32,
+}
+
+#[napi(object)]
+pub struct MinifyOptimizationStats {
+    pub name: String,
+
+    /// Number of times this optimization changed the AST.
+    pub changes: u32,
+}
+
+impl From<oxc_minifier::CompressorStats> for MinifyStats {
+    #[expect(clippy::cast_possible_truncation)]
+    fn from(stats: oxc_minifier::CompressorStats) -> Self {
+        Self {
+            iterations: stats.iterations,
+            phases: stats
+                .phases
+                .into_iter()
+                .map(|phase| MinifyPhaseStats {
+                    name: phase.name.to_string(),
+                    size_before: phase.size_before as u32,
+                    size_after: phase.size_after as u32,
+                })
+                .collect(),
+            optimizations: stats
+                .optimizations
+                .into_iter()
+                .map(MinifyOptimizationStats::from)
+                .collect(),
+            iteration_stats: stats
+                .iteration_stats
+                .into_iter()
+                .map(|iteration| MinifyIterationStats {
+                    size_before: iteration.size_before as u32,
+                    size_after: iteration.size_after as u32,
+                    duration: iteration.duration.as_secs_f64() * 1000.0,
+                    optimizations: iteration
+                        .optimizations
+                        .into_iter()
+                        .map(MinifyOptimizationStats::from)
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<oxc_minifier::OptimizationStats> for MinifyOptimizationStats {
+    fn from(optimization: oxc_minifier::OptimizationStats) -> Self {
+        Self { name: optimization.name.to_string(), changes: optimization.changes }
+    }
 }
@@ -40,6 +40,53 @@ describe('simple', () => {
   });
 });
 
+describe('name cache', () => {
+  it('reuses top level names across files', () => {
+    const first = minify('a.js', 'var Shell = 1; Shell()', { mangle: { toplevel: true, nameCache: { vars: {} } } });
+    expect(first.nameCache).toStrictEqual({ vars: { Shell: 'e' } });
+    const second = minify('b.js', 'var other = 0, Shell = 1; Shell(other, other)', {
+      mangle: { toplevel: true, nameCache: first.nameCache },
+    });
+    expect(second.code).toBe('var t=0,e=1;e(t,t);');
+    expect(second.nameCache).toStrictEqual({ vars: { Shell: 'e', other: 't' } });
+  });
+});
+
+describe('codegen', () => {
+  it('escapes non-ASCII characters', () => {
+    const ret = minify('test.js', "x('é')", { codegen: { asciiOnly: true } });
+    expect(ret.code).toBe('x(`\\u00E9`);');
+  });
+});
+
+describe('sourcemap', () => {
+  it('uses `sourcemap` options', () => {
+    const ret = minify('node_modules/pkg/test.js', 'foo()', {
+      sourcemap: { sourcesContent: false, debugId: true, ignoreList: ['node_modules'] },
+    });
+    const debugId = ret.map!.debugId!;
+    expect(ret.code).toBe(`foo();\n//# debugId=${debugId}\n`);
+    expect(ret.map!.sourcesContent).toBeUndefined();
+    expect(ret.map!.x_google_ignoreList).toStrictEqual([0]);
+    // The debug ID only depends on the generated code.
+    expect(minify('test.js', 'foo()', { sourcemap: { debugId: true } }).map!.debugId).toBe(debugId);
+  });
+});
+
+describe('reserved names', () => {
+  it('does not mangle reserved names', () => {
+    const code = 'function foo() { var VueApp, bar; VueApp(bar) } foo();';
+    const ret = minify('test.js', code, { compress: false, mangle: { reserved: ['/^Vue/'] } });
+    expect(ret.code).toBe('function foo(){var VueApp,e;VueApp(e)}foo();');
+  });
+
+  it('does not drop reserved names', () => {
+    const code = 'var a = function VueApp() {}, b = function f() {};';
+    const ret = minify('test.js', code, { compress: { reserved: ['/^Vue/'] }, mangle: false });
+    expect(ret.code).toBe('var a=function VueApp(){},b=function(){};');
+  });
+});
+
 describe('worker', () => {
   it('should run', async () => {
     const code = await new Promise((resolve, reject) => {
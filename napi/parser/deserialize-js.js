@@ -499,6 +499,14 @@ function deserializeEmptyStatement(pos) {
   };
 }
 
+function deserializeErrorStatement(pos) {
+  return {
+    type: 'ErrorStatement',
+    start: deserializeU32(pos),
+    end: deserializeU32(pos + 4),
+  };
+}
+
 function deserializeExpressionStatement(pos) {
   return {
     type: 'ExpressionStatement',
@@ -1994,6 +2002,13 @@ function deserializeNameSpan(pos) {
   };
 }
 
+function deserializeImportAttributeRecord(pos) {
+  return {
+    key: deserializeNameSpan(pos),
+    value: deserializeNameSpan(pos + 24),
+  };
+}
+
 function deserializeImportEntry(pos) {
   return {
     importName: deserializeImportImportName(pos + 32),
@@ -2272,6 +2287,8 @@ function deserializeStaticImport(pos) {
     end: deserializeU32(pos + 4),
     moduleRequest: deserializeNameSpan(pos + 8),
     entries: deserializeVecImportEntry(pos + 32),
+    attributes: deserializeVecImportAttributeRecord(pos + 64),
+    phase: deserializeOptionImportPhase(pos + 96),
   };
 }
 
@@ -2858,6 +2875,8 @@ function deserializeStatement(pos) {
       return deserializeBoxWhileStatement(pos + 8);
     case 17:
       return deserializeBoxWithStatement(pos + 8);
+    case 18:
+      return deserializeBoxErrorStatement(pos + 8);
     case 32:
       return deserializeBoxVariableDeclaration(pos + 8);
     case 33:
@@ -3195,17 +3214,6 @@ function deserializeAccessorPropertyType(pos) {
   }
 }
 
-function deserializeImportPhase(pos) {
-  switch (uint8[pos]) {
-    case 0:
-      return 'source';
-    case 1:
-      return 'defer';
-    default:
-      throw new Error(`Unexpected discriminant ${uint8[pos]} for ImportPhase`);
-  }
-}
-
 function deserializeImportDeclarationSpecifier(pos) {
   switch (uint8[pos]) {
     case 0:
@@ -3923,6 +3931,17 @@ function deserializeCommentKind(pos) {
   }
 }
 
+function deserializeImportPhase(pos) {
+  switch (uint8[pos]) {
+    case 0:
+      return 'source';
+    case 1:
+      return 'defer';
+    default:
+      throw new Error(`Unexpected discriminant ${uint8[pos]} for ImportPhase`);
+  }
+}
+
 function deserializeImportImportName(pos) {
   switch (uint8[pos]) {
     case 0:
@@ -4771,6 +4790,10 @@ function deserializeBoxWithStatement(pos) {
   return deserializeWithStatement(uint32[pos >> 2]);
 }
 
+function deserializeBoxErrorStatement(pos) {
+  return deserializeErrorStatement(uint32[pos >> 2]);
+}
+
 function deserializeBoxVariableDeclaration(pos) {
   return deserializeVariableDeclaration(uint32[pos >> 2]);
 }
@@ -5552,6 +5575,18 @@ function deserializeU32(pos) {
   return uint32[pos >> 2];
 }
 
+function deserializeVecImportAttributeRecord(pos) {
+  const arr = [],
+    pos32 = pos >> 2,
+    len = uint32[pos32 + 6];
+  pos = uint32[pos32];
+  for (let i = 0; i < len; i++) {
+    arr.push(deserializeImportAttributeRecord(pos));
+    pos += 48;
+  }
+  return arr;
+}
+
 function deserializeOptionNameSpan(pos) {
   if (uint32[pos >> 2] === 0 && uint32[(pos + 4) >> 2] === 0) return null;
   return deserializeNameSpan(pos);
@@ -5720,7 +5755,7 @@ function deserializeVecStaticImport(pos) {
   pos = uint32[pos32];
   for (let i = 0; i < len; i++) {
     arr.push(deserializeStaticImport(pos));
-    pos += 64;
+    pos += 104;
   }
   return arr;
 }
@@ -5768,7 +5803,7 @@ function deserializeVecImportEntry(pos) {
   pos = uint32[pos32];
   for (let i = 0; i < len; i++) {
     arr.push(deserializeImportEntry(pos));
-    pos += 96;
+    pos += 136;
   }
   return arr;
 }
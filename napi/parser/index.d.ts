@@ -7,6 +7,8 @@ export declare class ParseResult {
   get module(): EcmaScriptModule
   get comments(): Array<Comment>
   get errors(): Array<OxcError>
+  /** Tokens, if `tokens` option is enabled. Otherwise an empty array. */
+  get tokens(): Array<Token>
 }
 
 export interface Comment {
@@ -108,6 +110,23 @@ export declare const enum ExportLocalNameKind {
  */
 export declare function getBufferOffset(buffer: Uint8Array): number
 
+export interface ImportAttribute {
+  /**
+   * ```js
+   * import json from "./foo.json" with { type: "json" };
+   * //                                   ^^^^
+   * ```
+   */
+  key: ValueSpan
+  /**
+   * ```js
+   * import json from "./foo.json" with { type: "json" };
+   * //                                         ^^^^^^
+   * ```
+   */
+  value: ValueSpan
+}
+
 export interface ImportName {
   kind: ImportNameKind
   name?: string
@@ -124,6 +143,13 @@ export declare const enum ImportNameKind {
   Default = 'Default'
 }
 
+export declare const enum ImportPhase {
+  /** `import source x from "mod"` */
+  Source = 'source',
+  /** `import defer * as ns from "mod"` */
+  Defer = 'defer'
+}
+
 export interface OxcError {
   severity: Severity
   message: string
@@ -168,6 +194,14 @@ export interface ParserOptions {
    * @default false
    */
   showSemanticErrors?: boolean
+  /**
+   * Collect tokens into `tokens` of the result, in the same format as ESLint's `espree` parser.
+   *
+   * Comments are not included.
+   *
+   * @default false
+   */
+  tokens?: boolean
 }
 
 /** Parse synchronously. */
@@ -253,6 +287,24 @@ export interface StaticImport {
    * Empty for `import "mod"`.
    */
   entries: Array<StaticImportEntry>
+  /**
+   * Import attributes.
+   *
+   * ```js
+   * import json from "./foo.json" with { type: "json" };
+   * //                                   ^^^^^^^^^^^^
+   * ```
+   */
+  attributes: Array<ImportAttribute>
+  /**
+   * Import phase. `undefined` for evaluation phase imports.
+   *
+   * ```js
+   * import source wasm from "./foo.wasm";
+   * import defer * as ns from "mod";
+   * ```
+   */
+  phase?: ImportPhase
 }
 
 export interface StaticImportEntry {
@@ -289,6 +341,25 @@ export interface StaticImportEntry {
   isType: boolean
 }
 
+/**
+ * Token, in the format of `espree`'s `tokens` option.
+ *
+ * Only used for type definitions. Tokens are passed to JS as JSON.
+ */
+export interface Token {
+  type: 'Boolean' | 'Identifier' | 'Keyword' | 'Null' | 'Numeric' | 'Punctuator' | 'RegularExpression' | 'String' | 'Template' | 'PrivateIdentifier' | 'JSXText'
+  value: string
+  /** Pattern and flags of `RegularExpression` tokens. */
+  regex?: TokenRegex
+  start: number
+  end: number
+}
+
+export interface TokenRegex {
+  pattern: string
+  flags: string
+}
+
 export interface ValueSpan {
   value: string
   start: number
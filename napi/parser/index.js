@@ -8,6 +8,7 @@ module.exports.ExportExportNameKind = bindings.ExportExportNameKind;
 module.exports.ExportImportNameKind = bindings.ExportImportNameKind;
 module.exports.ExportLocalNameKind = bindings.ExportLocalNameKind;
 module.exports.ImportNameKind = bindings.ImportNameKind;
+module.exports.ImportPhase = bindings.ImportPhase;
 module.exports.parseWithoutReturn = bindings.parseWithoutReturn;
 module.exports.Severity = bindings.Severity;
 
@@ -32,6 +33,10 @@ function parseSyncRaw(filename, sourceText, options) {
     );
   }
 
+  if (options.tokens) {
+    throw new Error('`tokens` option is not supported with `experimentalRawTransfer`');
+  }
+
   // Delete `experimentalRawTransfer` option
   let experimentalRawTransfer;
   ({ experimentalRawTransfer, ...options } = options);
@@ -59,6 +59,7 @@ export const ExportImportNameKind = __napiModule.exports.ExportImportNameKind
 export const ExportLocalNameKind = __napiModule.exports.ExportLocalNameKind
 export const getBufferOffset = __napiModule.exports.getBufferOffset
 export const ImportNameKind = __napiModule.exports.ImportNameKind
+export const ImportPhase = __napiModule.exports.ImportPhase
 export const parseAsync = __napiModule.exports.parseAsync
 export const parseSync = __napiModule.exports.parseSync
 export const parseSyncRaw = __napiModule.exports.parseSyncRaw
@@ -4,8 +4,9 @@ use oxc::syntax::module_record::{self, ModuleRecord};
 
 use crate::types::{
     DynamicImport, EcmaScriptModule, ExportExportName, ExportExportNameKind, ExportImportName,
-    ExportImportNameKind, ExportLocalName, ExportLocalNameKind, ImportName, ImportNameKind, Span,
-    StaticExport, StaticExportEntry, StaticImport, StaticImportEntry, ValueSpan,
+    ExportImportNameKind, ExportLocalName, ExportLocalNameKind, ImportAttribute, ImportName,
+    ImportNameKind, ImportPhase, Span, StaticExport, StaticExportEntry, StaticImport,
+    StaticImportEntry, ValueSpan,
 };
 
 impl From<&ModuleRecord<'_>> for EcmaScriptModule {
@@ -31,6 +32,8 @@ impl From<&ModuleRecord<'_>> for EcmaScriptModule {
                                 end: m.span.end,
                             },
                             entries,
+                            attributes: m.attributes.iter().map(ImportAttribute::from).collect(),
+                            phase: m.phase.map(ImportPhase::from),
                         }
                     }
                 })
@@ -106,6 +109,21 @@ impl From<&module_record::ImportEntry<'_>> for StaticImportEntry {
     }
 }
 
+impl From<&module_record::ImportAttributeRecord<'_>> for ImportAttribute {
+    fn from(e: &module_record::ImportAttributeRecord<'_>) -> Self {
+        Self { key: ValueSpan::from(&e.key), value: ValueSpan::from(&e.value) }
+    }
+}
+
+impl From<module_record::ImportPhase> for ImportPhase {
+    fn from(phase: module_record::ImportPhase) -> Self {
+        match phase {
+            module_record::ImportPhase::Source => Self::Source,
+            module_record::ImportPhase::Defer => Self::Defer,
+        }
+    }
+}
+
 impl From<&module_record::ImportImportName<'_>> for ImportName {
     fn from(e: &module_record::ImportImportName<'_>) -> Self {
         let (kind, name, start, end) = match e {
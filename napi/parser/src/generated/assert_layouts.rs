@@ -39,11 +39,13 @@ const _: () = {
     assert!(offset_of!(EcmaScriptModule, dynamic_imports) == 72);
     assert!(offset_of!(EcmaScriptModule, import_metas) == 104);
 
-    assert!(size_of::<StaticImport>() == 64);
+    assert!(size_of::<StaticImport>() == 104);
     assert!(align_of::<StaticImport>() == 8);
     assert!(offset_of!(StaticImport, span) == 0);
     assert!(offset_of!(StaticImport, module_request) == 8);
     assert!(offset_of!(StaticImport, entries) == 32);
+    assert!(offset_of!(StaticImport, attributes) == 64);
+    assert!(offset_of!(StaticImport, phase) == 96);
 
     assert!(size_of::<StaticExport>() == 40);
     assert!(align_of::<StaticExport>() == 8);
@@ -83,11 +85,13 @@ const _: () = {
     assert!(offset_of!(EcmaScriptModule, dynamic_imports) == 36);
     assert!(offset_of!(EcmaScriptModule, import_metas) == 52);
 
-    assert!(size_of::<StaticImport>() == 40);
+    assert!(size_of::<StaticImport>() == 60);
     assert!(align_of::<StaticImport>() == 4);
     assert!(offset_of!(StaticImport, span) == 0);
     assert!(offset_of!(StaticImport, module_request) == 8);
     assert!(offset_of!(StaticImport, entries) == 24);
+    assert!(offset_of!(StaticImport, attributes) == 40);
+    assert!(offset_of!(StaticImport, phase) == 56);
 
     assert!(size_of::<StaticExport>() == 24);
     assert!(align_of::<StaticExport>() == 4);
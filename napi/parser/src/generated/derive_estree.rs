@@ -71,6 +71,8 @@ impl ESTree for StaticImport<'_> {
         state.serialize_field("end", &self.span.end);
         state.serialize_field("moduleRequest", &self.module_request);
         state.serialize_field("entries", &self.entries);
+        state.serialize_field("attributes", &self.attributes);
+        state.serialize_field("phase", &self.phase);
         state.end();
     }
 }
@@ -12,10 +12,12 @@ use napi_derive::napi;
 
 use oxc::{
     allocator::Allocator,
-    parser::{ParseOptions, Parser, ParserReturn},
+    ast_visit::utf8_to_utf16::Utf8ToUtf16,
+    parser::{ParseOptions, Parser, ParserReturn, Token},
     semantic::SemanticBuilder,
     span::SourceType,
 };
+use oxc_estree::{CompactJSSerializer, ESTree};
 use oxc_napi::{OxcError, convert_utf8_to_utf16};
 
 mod convert;
@@ -79,6 +81,7 @@ fn parse<'a>(
     Parser::new(allocator, source_text, source_type)
         .with_options(ParseOptions {
             preserve_parens: options.preserve_parens.unwrap_or(true),
+            tokens: options.tokens.unwrap_or(false),
             ..ParseOptions::default()
         })
         .parse()
@@ -98,6 +101,8 @@ fn parse_with_return(filename: &str, source_text: String, options: &ParserOption
         errors.extend(semantic_ret.errors.into_iter().map(OxcError::from));
     }
 
+    let tokens = serialize_tokens(&source_text, &ret.tokens);
+
     let comments =
         convert_utf8_to_utf16(&source_text, &mut program, &mut module_record, &mut errors);
 
@@ -108,7 +113,28 @@ fn parse_with_return(filename: &str, source_text: String, options: &ParserOption
 
     let module = EcmaScriptModule::from(&module_record);
 
-    ParseResult { program, module, comments, errors }
+    ParseResult { program, module, comments, errors, tokens }
+}
+
+/// Serialize tokens to JSON, with spans converted to UTF-16.
+fn serialize_tokens(source_text: &str, tokens: &[Token]) -> String {
+    let span_converter = Utf8ToUtf16::new(source_text);
+    let mut offset_converter = span_converter.converter();
+    let tokens = tokens
+        .iter()
+        .filter_map(|token| {
+            let mut token = token.to_estree(source_text)?;
+            if let Some(converter) = offset_converter.as_mut() {
+                converter.convert_offset(&mut token.start);
+                converter.convert_offset(&mut token.end);
+            }
+            Some(token)
+        })
+        .collect::<Vec<_>>();
+
+    let mut serializer = CompactJSSerializer::new();
+    tokens.as_slice().serialize(&mut serializer);
+    serializer.into_string()
 }
 
 /// Parse synchronously.
@@ -1,11 +1,14 @@
 use rustc_hash::FxHashMap;
 
 use oxc::{
-    allocator::{Allocator, FromIn, Vec},
+    allocator::{Allocator, CloneIn, FromIn, Vec},
     ast::ast::{Comment, Program},
     diagnostics::{LabeledSpan, OxcDiagnostic, Severity},
     span::{Atom, Span},
-    syntax::module_record::{DynamicImport, ExportEntry, ImportEntry, ModuleRecord, NameSpan},
+    syntax::module_record::{
+        DynamicImport, ExportEntry, ImportAttributeRecord, ImportEntry, ImportPhase, ModuleRecord,
+        NameSpan,
+    },
 };
 use oxc_ast_macros::ast;
 use oxc_estree::ESTree;
@@ -143,6 +146,20 @@ pub struct StaticImport<'a> {
     ///
     /// Empty for `import "mod"`.
     pub entries: Vec<'a, ImportEntry<'a>>,
+    /// Import attributes.
+    ///
+    /// ```js
+    /// import json from "./foo.json" with { type: "json" };
+    /// //                                   ^^^^^^^^^^^^
+    /// ```
+    pub attributes: Vec<'a, ImportAttributeRecord<'a>>,
+    /// Import phase. `null` for evaluation phase imports.
+    ///
+    /// ```js
+    /// import source wasm from "./foo.wasm";
+    /// import defer * as ns from "mod";
+    /// ```
+    pub phase: Option<ImportPhase>,
 }
 
 #[ast]
@@ -162,13 +179,15 @@ impl<'a> FromIn<'a, ModuleRecord<'a>> for EcmaScriptModule<'a> {
                         .import_entries
                         .iter()
                         .filter(|e| e.statement_span == m.statement_span)
-                        .cloned();
+                        .map(|e| e.clone_in(allocator));
                     let entries = Vec::from_iter_in(entries, allocator);
 
                     StaticImport {
                         span: m.statement_span,
                         module_request: NameSpan { name: *name, span: m.span },
                         entries,
+                        attributes: m.attributes.clone_in(allocator),
+                        phase: m.phase,
                     }
                 })
             });
@@ -37,6 +37,13 @@ pub struct ParserOptions {
     ///
     /// @default false
     pub show_semantic_errors: Option<bool>,
+
+    /// Collect tokens into `tokens` of the result, in the same format as ESLint's `espree` parser.
+    ///
+    /// Comments are not included.
+    ///
+    /// @default false
+    pub tokens: Option<bool>,
 }
 
 #[napi]
@@ -45,6 +52,7 @@ pub struct ParseResult {
     pub(crate) module: EcmaScriptModule,
     pub(crate) comments: Vec<Comment>,
     pub(crate) errors: Vec<OxcError>,
+    pub(crate) tokens: String,
 }
 
 #[napi]
@@ -68,6 +76,34 @@ impl ParseResult {
     pub fn errors(&mut self) -> Vec<OxcError> {
         mem::take(&mut self.errors)
     }
+
+    /// Tokens, if `tokens` option is enabled. Otherwise an empty array.
+    #[napi(getter, ts_return_type = "Array<Token>")]
+    pub fn get_tokens(&mut self) -> String {
+        mem::take(&mut self.tokens)
+    }
+}
+
+/// Token, in the format of `espree`'s `tokens` option.
+///
+/// Only used for type definitions. Tokens are passed to JS as JSON.
+#[napi(object)]
+pub struct Token {
+    #[napi(
+        ts_type = "'Boolean' | 'Identifier' | 'Keyword' | 'Null' | 'Numeric' | 'Punctuator' | 'RegularExpression' | 'String' | 'Template' | 'PrivateIdentifier' | 'JSXText'"
+    )]
+    pub r#type: String,
+    pub value: String,
+    /// Pattern and flags of `RegularExpression` tokens.
+    pub regex: Option<TokenRegex>,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[napi(object)]
+pub struct TokenRegex {
+    pub pattern: String,
+    pub flags: String,
 }
 
 #[napi(object)]
@@ -119,6 +155,42 @@ pub struct StaticImport {
     ///
     /// Empty for `import "mod"`.
     pub entries: Vec<StaticImportEntry>,
+    /// Import attributes.
+    ///
+    /// ```js
+    /// import json from "./foo.json" with { type: "json" };
+    /// //                                   ^^^^^^^^^^^^
+    /// ```
+    pub attributes: Vec<ImportAttribute>,
+    /// Import phase. `undefined` for evaluation phase imports.
+    ///
+    /// ```js
+    /// import source wasm from "./foo.wasm";
+    /// import defer * as ns from "mod";
+    /// ```
+    pub phase: Option<ImportPhase>,
+}
+
+#[napi(object)]
+pub struct ImportAttribute {
+    /// ```js
+    /// import json from "./foo.json" with { type: "json" };
+    /// //                                   ^^^^
+    /// ```
+    pub key: ValueSpan,
+    /// ```js
+    /// import json from "./foo.json" with { type: "json" };
+    /// //                                         ^^^^^^
+    /// ```
+    pub value: ValueSpan,
+}
+
+#[napi(string_enum = "lowercase")]
+pub enum ImportPhase {
+    /// `import source x from "mod"`
+    Source,
+    /// `import defer * as ns from "mod"`
+    Defer,
 }
 
 #[napi(object)]
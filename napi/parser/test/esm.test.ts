@@ -64,6 +64,21 @@ export { default as name1 } from "module-name";
   });
 });
 
+describe('staticImports', () => {
+  test('attributes', () => {
+    const ret = parseSync('test.js', "import json from './foo.json' with { type: 'json' }");
+    expect(ret.module.staticImports[0].attributes).toStrictEqual([
+      { key: { value: 'type', start: 37, end: 41 }, value: { value: 'json', start: 43, end: 49 } },
+    ]);
+    expect(ret.module.staticImports[0].phase).toBeUndefined();
+  });
+
+  test('phase', () => {
+    const ret = parseSync('test.js', "import source wasm from './foo.wasm'; import defer * as ns from 'mod';");
+    expect(ret.module.staticImports.map((i) => i.phase)).toEqual(['source', 'defer']);
+  });
+});
+
 describe('hasModuleSyntax', () => {
   test('import.meta', () => {
     const ret = parseSync('test.js', 'import.meta.foo');
@@ -264,6 +264,24 @@ describe('parse', () => {
       expect((ret.program.body[0] as TSTypeAliasDeclaration).typeAnnotation.type).toBe('TSTypeReference');
     });
   });
+
+  describe('tokens', () => {
+    it('are empty by default', () => {
+      const ret = parseSync('test.js', 'let x = 1;');
+      expect(ret.tokens).toEqual([]);
+    });
+
+    it('are collected when enabled', () => {
+      const ret = parseSync('test.js', 'let x = /a/g; // c', { tokens: true });
+      expect(ret.tokens).toEqual([
+        { type: 'Keyword', value: 'let', start: 0, end: 3 },
+        { type: 'Identifier', value: 'x', start: 4, end: 5 },
+        { type: 'Punctuator', value: '=', start: 6, end: 7 },
+        { type: 'RegularExpression', value: '/a/g', regex: { pattern: 'a', flags: 'g' }, start: 8, end: 12 },
+        { type: 'Punctuator', value: ';', start: 12, end: 13 },
+      ]);
+    });
+  });
 });
 
 describe('UTF-16 span', () => {
@@ -342,6 +360,7 @@ describe('UTF-16 span', () => {
         ],
         "staticImports": [
           {
+            "attributes": [],
             "end": 23,
             "entries": [
               {
@@ -370,6 +389,11 @@ describe('UTF-16 span', () => {
     `);
   });
 
+  it('tokens', async () => {
+    const ret = await parseAsync('test.js', `'🤨' + x`, { tokens: true });
+    expect(ret.tokens.map(({ start, end }) => [start, end])).toEqual([[0, 4], [5, 6], [7, 8]]);
+  });
+
   it('error', async () => {
     const ret = await parseAsync('test.js', `"🤨";asdf asdf`);
     expect(ret.errors).toMatchInlineSnapshot(`
@@ -1,5 +1,5 @@
 module.exports.wrap = function wrap(result) {
-  let program, module, comments, errors;
+  let program, module, comments, errors, tokens;
   return {
     get program() {
       if (!program) {
@@ -38,5 +38,9 @@ module.exports.wrap = function wrap(result) {
       if (!errors) errors = result.errors;
       return errors;
     },
+    get tokens() {
+      if (!tokens) tokens = JSON.parse(result.tokens);
+      return tokens;
+    },
   };
 };
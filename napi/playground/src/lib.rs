@@ -116,6 +116,7 @@ impl Oxc {
             allow_v8_intrinsics: parser_options
                 .allow_v8_intrinsics
                 .unwrap_or(default_parser_options.allow_v8_intrinsics),
+            ..default_parser_options
         };
         let ParserReturn { mut program, errors, mut module_record, .. } =
             Parser::new(&allocator, &source_text, source_type)
@@ -216,6 +217,7 @@ impl Oxc {
                 } else {
                     CompressOptions::default()
                 }),
+                stats: false,
             };
             Minifier::new(options).build(&allocator, &mut program).scoping
         } else {
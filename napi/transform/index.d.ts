@@ -297,6 +297,26 @@ export interface SourceMap {
   sourcesContent?: Array<string>
   version: number
   x_google_ignoreList?: Array<number>
+  debugId?: string
+}
+
+export interface SourcemapOptions {
+  /**
+   * Include the original source code in `sourcesContent`.
+   *
+   * @default true
+   */
+  sourcesContent?: boolean
+  /** Set `sourceRoot` of the source map. */
+  sourceRoot?: string
+  /**
+   * Add a deterministic `debugId` to the source map and a `//# debugId=` comment to the end of the code.
+   *
+   * @default false
+   */
+  debugId?: boolean
+  /** Add the file to `x_google_ignoreList` if its path contains any of these strings, e.g. `node_modules`. */
+  ignoreList?: Array<string>
 }
 
 /**
@@ -330,13 +350,14 @@ export interface TransformOptions {
   /**
    * Enable source map generation.
    *
-   * When `true`, the `sourceMap` field of transform result objects will be populated.
+   * When `true` or an object, the `sourceMap` field of transform result objects will be populated.
    *
    * @default false
    *
    * @see {@link SourceMap}
+   * @see {@link SourcemapOptions}
    */
-  sourcemap?: boolean
+  sourcemap?: boolean | SourcemapOptions
   /** Set assumptions in order to produce smaller output. */
   assumptions?: CompilerAssumptions
   /** Configure how TypeScript is transformed. */
@@ -10,7 +10,7 @@ use oxc::{
     span::SourceType,
 };
 use oxc_napi::OxcError;
-use oxc_sourcemap::napi::SourceMap;
+use oxc_napi::SourceMap;
 
 #[napi(object)]
 pub struct IsolatedDeclarationsResult {
@@ -25,7 +25,8 @@ use oxc::{
     },
 };
 use oxc_napi::OxcError;
-use oxc_sourcemap::napi::SourceMap;
+use oxc_napi::SourceMap;
+pub use oxc_napi::SourcemapOptions;
 
 use crate::IsolatedDeclarationsOptions;
 
@@ -96,12 +97,13 @@ pub struct TransformOptions {
 
     /// Enable source map generation.
     ///
-    /// When `true`, the `sourceMap` field of transform result objects will be populated.
+    /// When `true` or an object, the `sourceMap` field of transform result objects will be populated.
     ///
     /// @default false
     ///
     /// @see {@link SourceMap}
-    pub sourcemap: Option<bool>,
+    /// @see {@link SourcemapOptions}
+    pub sourcemap: Option<Either<bool, SourcemapOptions>>,
 
     /// Set assumptions in order to produce smaller output.
     pub assumptions: Option<CompilerAssumptions>,
@@ -520,6 +522,7 @@ struct Compiler {
     isolated_declaration_options: Option<oxc::isolated_declarations::IsolatedDeclarationsOptions>,
 
     sourcemap: bool,
+    sourcemap_options: Option<SourcemapOptions>,
 
     printed: String,
     printed_sourcemap: Option<SourceMap>,
@@ -544,7 +547,12 @@ impl Compiler {
             .and_then(|o| o.declaration)
             .map(oxc::isolated_declarations::IsolatedDeclarationsOptions::from);
 
-        let sourcemap = options.as_ref().and_then(|o| o.sourcemap).unwrap_or_default();
+        let (sourcemap, sourcemap_options) =
+            match options.as_ref().and_then(|o| o.sourcemap.as_ref()) {
+                Some(Either::A(sourcemap)) => (*sourcemap, None),
+                Some(Either::B(sourcemap_options)) => (true, Some(sourcemap_options.clone())),
+                None => (false, None),
+            };
 
         let define = options
             .as_mut()
@@ -591,6 +599,7 @@ impl Compiler {
             transform_options,
             isolated_declaration_options,
             sourcemap,
+            sourcemap_options,
             printed: String::default(),
             printed_sourcemap: None,
             declaration: None,
@@ -612,6 +621,14 @@ impl CompilerInterface for Compiler {
         self.sourcemap
     }
 
+    fn codegen_options(&self) -> Option<CodegenOptions> {
+        let mut options = CodegenOptions::default();
+        if let Some(sourcemap_options) = &self.sourcemap_options {
+            sourcemap_options.apply(&mut options);
+        }
+        Some(options)
+    }
+
     fn transform_options(&self) -> Option<&oxc::transformer::TransformOptions> {
         Some(&self.transform_options)
     }
@@ -48,6 +48,24 @@ describe('simple', () => {
       },
     );
   });
+
+  it('uses `sourcemap` options', () => {
+    const ret = transform('node_modules/pkg/test.ts', code, {
+      sourcemap: { sourcesContent: false, sourceRoot: '/src/', debugId: true, ignoreList: ['node_modules'] },
+    });
+    const debugId = ret.map!.debugId!;
+    expect(debugId).toMatch(/^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/);
+    expect(ret.code).toBe(`export class A {}\n//# debugId=${debugId}\n`);
+    expect(ret.map).toStrictEqual({
+      mappings: 'AAAA,OAAO,MAAM,EAAK,CAAE',
+      names: [],
+      sourceRoot: '/src/',
+      sources: ['node_modules/pkg/test.ts'],
+      version: 3,
+      x_google_ignoreList: [0],
+      debugId,
+    });
+  });
 });
 
 describe('transform', () => {
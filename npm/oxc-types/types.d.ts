@@ -332,6 +332,7 @@ export type Statement =
   | TryStatement
   | WhileStatement
   | WithStatement
+  | ErrorStatement
   | Declaration
   | ModuleDeclaration;
 
@@ -381,6 +382,10 @@ export interface EmptyStatement extends Span {
   type: 'EmptyStatement';
 }
 
+export interface ErrorStatement extends Span {
+  type: 'ErrorStatement';
+}
+
 export interface ExpressionStatement extends Span {
   type: 'ExpressionStatement';
   expression: Expression;
@@ -698,8 +703,6 @@ export interface ImportDeclaration extends Span {
   importKind?: ImportOrExportKind;
 }
 
-export type ImportPhase = 'source' | 'defer';
-
 export type ImportDeclarationSpecifier = ImportSpecifier | ImportDefaultSpecifier | ImportNamespaceSpecifier;
 
 export interface ImportSpecifier extends Span {
@@ -1415,6 +1418,8 @@ export interface JSDocUnknownType extends Span {
   type: 'JSDocUnknownType';
 }
 
+export type ImportPhase = 'source' | 'defer';
+
 export type AssignmentOperator =
   | '='
   | '+='
@@ -1674,6 +1679,7 @@ export type Node =
   | VariableDeclaration
   | VariableDeclarator
   | EmptyStatement
+  | ErrorStatement
   | ExpressionStatement
   | IfStatement
   | DoWhileStatement